@@ -407,6 +407,201 @@ macro_rules! impl_ops_traits {
     };
 }
 
+/// Implements inherent methods on `$name<O>` which mirror those of `$native`.
+///
+/// Each method converts its byteorder-typed operands to `$native`, calls the
+/// corresponding `$native` method, and converts the result (if it is of type
+/// `$native`) back to `$name<O>`.
+macro_rules! impl_native_methods {
+    ($name:ident, $native:ident, $bytes:expr, "unsigned integer") => {
+        impl_native_methods!($name, $native, $bytes, @integer);
+
+        impl<O: ByteOrder> $name<O> {
+            impl_native_methods!(@methods $name, $native, [fn(self) -> bool], is_power_of_two);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> Self], next_power_of_two);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> Option<Self>], checked_next_power_of_two);
+        }
+    };
+    ($name:ident, $native:ident, $bytes:expr, "signed integer") => {
+        impl_native_methods!($name, $native, $bytes, @integer);
+
+        impl<O: ByteOrder> $name<O> {
+            impl_native_methods!(@methods $name, $native, [fn(self) -> bool], is_positive, is_negative);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> Self], abs, signum, wrapping_abs, saturating_abs, saturating_neg);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> Option<Self>], checked_abs);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> (Self, bool)], overflowing_abs);
+        }
+    };
+    ($name:ident, $native:ident, $bytes:expr, "floating point number") => {
+        impl_native_methods!($name, $native, $bytes, @all_types);
+
+        impl<O: ByteOrder> $name<O> {
+            impl_native_methods!(@consts $name, $native, MIN, MAX, MIN_POSITIVE, EPSILON, NAN, INFINITY, NEG_INFINITY);
+
+            impl_native_methods!(@methods $name, $native, [fn(self) -> bool], is_nan, is_infinite, is_finite, is_subnormal, is_normal, is_sign_positive, is_sign_negative);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> core::num::FpCategory], classify);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> Self], recip, to_degrees, to_radians);
+            impl_native_methods!(@methods $name, $native, [fn(self, other: Self) -> Self], max, min);
+            impl_native_methods!(@methods $name, $native, [fn(self, min: Self, max: Self) -> Self], clamp);
+
+            #[doc = concat!("See [`", stringify!($native), "::total_cmp`].")]
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            pub fn total_cmp(&self, other: &Self) -> Ordering {
+                self.get().total_cmp(&other.get())
+            }
+        }
+
+        #[cfg(any(feature = "std", test))]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+        impl<O: ByteOrder> $name<O> {
+            impl_native_methods!(@methods $name, $native, [fn(self) -> Self], abs, signum, floor, ceil, round, trunc, fract, sqrt);
+            impl_native_methods!(@methods $name, $native, [fn(self, rhs: Self) -> Self], copysign, powf, div_euclid, rem_euclid);
+            impl_native_methods!(@methods $name, $native, [fn(self, n: i32) -> Self], powi);
+            impl_native_methods!(@methods $name, $native, [fn(self, a: Self, b: Self) -> Self], mul_add);
+        }
+    };
+    ($name:ident, $native:ident, $bytes:expr, @integer) => {
+        impl_native_methods!($name, $native, $bytes, @all_types);
+
+        impl<O: ByteOrder> $name<O> {
+            impl_native_methods!(@consts $name, $native, MIN, MAX);
+
+            #[doc = concat!("See [`", stringify!($native), "::BITS`].")]
+            pub const BITS: u32 = $native::BITS;
+
+            impl_native_methods!(@methods $name, $native, [fn(self) -> u32], count_ones, count_zeros, leading_zeros, trailing_zeros, leading_ones, trailing_ones);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> Self], swap_bytes, reverse_bits, wrapping_neg);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> Option<Self>], checked_neg);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> (Self, bool)], overflowing_neg);
+
+            impl_native_methods!(@methods $name, $native, [fn(self, n: u32) -> Self], rotate_left, rotate_right, pow, wrapping_pow, saturating_pow, wrapping_shl, wrapping_shr);
+            impl_native_methods!(@methods $name, $native, [fn(self, n: u32) -> Option<Self>], checked_pow, checked_shl, checked_shr);
+            impl_native_methods!(@methods $name, $native, [fn(self, n: u32) -> (Self, bool)], overflowing_pow, overflowing_shl, overflowing_shr);
+
+            impl_native_methods!(@methods $name, $native, [fn(self, rhs: Self) -> Self],
+                div_euclid, rem_euclid,
+                wrapping_add, wrapping_sub, wrapping_mul, wrapping_div, wrapping_rem, wrapping_div_euclid, wrapping_rem_euclid,
+                saturating_add, saturating_sub, saturating_mul, saturating_div
+            );
+            impl_native_methods!(@methods $name, $native, [fn(self, rhs: Self) -> Option<Self>],
+                checked_add, checked_sub, checked_mul, checked_div, checked_rem, checked_div_euclid, checked_rem_euclid
+            );
+            impl_native_methods!(@methods $name, $native, [fn(self, rhs: Self) -> (Self, bool)],
+                overflowing_add, overflowing_sub, overflowing_mul, overflowing_div, overflowing_rem, overflowing_div_euclid, overflowing_rem_euclid
+            );
+        }
+    };
+    ($name:ident, $native:ident, $bytes:expr, @all_types) => {
+        impl<O: ByteOrder> IntoNative<$native> for $name<O> {
+            #[inline(always)]
+            fn into_native(self) -> $native {
+                self.get()
+            }
+        }
+
+        impl<O: ByteOrder> FromNative<$native> for $name<O> {
+            #[inline(always)]
+            fn from_native(n: $native) -> $name<O> {
+                $name::new(n)
+            }
+        }
+
+        impl<O: ByteOrder> $name<O> {
+            impl_native_methods!(@from_bytes $name, $native, $bytes, from_be_bytes, from_le_bytes, from_ne_bytes);
+            impl_native_methods!(@methods $name, $native, [fn(self) -> [u8; $bytes]], to_be_bytes, to_le_bytes, to_ne_bytes);
+        }
+    };
+    (@consts $name:ident, $native:ident, $($konst:ident),*) => {
+        $(
+            #[doc = concat!("See [`", stringify!($native), "::", stringify!($konst), "`].")]
+            pub const $konst: $name<O> = $name::new($native::$konst);
+        )*
+    };
+    (@from_bytes $name:ident, $native:ident, $bytes:expr, $($method:ident),*) => {
+        $(
+            #[doc = concat!("See [`", stringify!($native), "::", stringify!($method), "`].")]
+            #[must_use = "has no side effects"]
+            #[inline(always)]
+            // Clippy: This can't be a `const fn` because the float
+            // `from_*_bytes` methods are not const-stable on our MSRV.
+            #[allow(clippy::missing_const_for_fn)]
+            pub fn $method(bytes: [u8; $bytes]) -> $name<O> {
+                $name::new($native::$method(bytes))
+            }
+        )*
+    };
+    (@methods $name:ident, $native:ident, $sig:tt, $($method:ident),*) => {
+        $(impl_native_methods!(@method $name, $native, $sig, $method);)*
+    };
+    (@method $name:ident, $native:ident, [fn(self $(, $arg:ident: $arg_ty:ty)*) -> $ret:ty], $method:ident) => {
+        #[doc = concat!("See [`", stringify!($native), "::", stringify!($method), "`].")]
+        #[must_use = "has no side effects"]
+        #[inline(always)]
+        pub fn $method(self $(, $arg: $arg_ty)*) -> $ret {
+            FromNative::from_native(self.get().$method($(IntoNative::into_native($arg)),*))
+        }
+    };
+}
+
+/// Converts a byteorder type or a value containing byteorder types to the
+/// corresponding native type.
+///
+/// This allows `impl_native_methods!` to handle arguments of any type
+/// uniformly.
+trait IntoNative<N> {
+    fn into_native(self) -> N;
+}
+
+/// The inverse of [`IntoNative`], which converts a method's native return
+/// value to the corresponding byteorder type.
+trait FromNative<N> {
+    fn from_native(n: N) -> Self;
+}
+
+macro_rules! impl_native_conversions {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoNative<$ty> for $ty {
+                #[inline(always)]
+                fn into_native(self) -> $ty {
+                    self
+                }
+            }
+
+            impl FromNative<$ty> for $ty {
+                #[inline(always)]
+                fn from_native(n: $ty) -> $ty {
+                    n
+                }
+            }
+        )*
+    };
+}
+
+impl_native_conversions!(bool, i32, u32, core::num::FpCategory);
+
+impl<const N: usize> FromNative<[u8; N]> for [u8; N] {
+    #[inline(always)]
+    fn from_native(n: [u8; N]) -> [u8; N] {
+        n
+    }
+}
+
+impl<N, T: FromNative<N>> FromNative<Option<N>> for Option<T> {
+    #[inline(always)]
+    fn from_native(n: Option<N>) -> Option<T> {
+        n.map(T::from_native)
+    }
+}
+
+impl<N, T: FromNative<N>> FromNative<(N, bool)> for (T, bool) {
+    #[inline(always)]
+    fn from_native((n, b): (N, bool)) -> (T, bool) {
+        (T::from_native(n), b)
+    }
+}
+
 macro_rules! doc_comment {
     ($x:expr, $($tt:tt)*) => {
         #[doc = $x]
@@ -418,9 +613,9 @@ macro_rules! define_max_value_constant {
     ($name:ident, $bytes:expr, "unsigned integer") => {
         /// The maximum value.
         ///
-        /// This constant should be preferred to constructing a new value using
-        /// `new`, as `new` may perform an endianness swap depending on the
-        /// endianness `O` and the endianness of the platform.
+        /// Deprecated: use [`MAX`](Self::MAX), which is also provided for
+        /// signed integers and floats, instead.
+        #[deprecated(since = "0.9.0", note = "use `MAX` instead")]
         pub const MAX_VALUE: $name<O> = $name([0xFFu8; $bytes], PhantomData);
     };
     // We don't provide maximum and minimum value constants for signed values
@@ -470,6 +665,11 @@ order to uphold the invariants that a) the layout of `", stringify!($name), "`
 has endianness `O` and that, b) the layout of `", stringify!($native), "` has
 the platform's native endianness.
 
+`", stringify!($name), "` also provides most of the inherent methods of `",
+stringify!($native), "` - such as `to_be_bytes` or, for integers, `checked_add` -
+which operate on `", stringify!($name), "` values directly, rather than
+requiring a round trip through `get` and `set`.
+
 `", stringify!($name), "` implements [`FromBytes`], [`IntoBytes`], and [`Unaligned`],
 making it useful for parsing and serialization. See the module documentation for an
 example of how it can be used for parsing UDP packets.
@@ -682,6 +882,7 @@ example of how it can be used for parsing UDP packets.
 
         impl_fmt_traits!($name, $native, $number_kind);
         impl_ops_traits!($name, $native, $number_kind);
+        impl_native_methods!($name, $native, $bytes, $number_kind);
 
         impl<O: ByteOrder> Debug for $name<O> {
             #[inline]
@@ -690,6 +891,24 @@ example of how it can be used for parsing UDP packets.
                 f.debug_tuple(stringify!($name)).field(&self.get()).finish()
             }
         }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+        impl<O: ByteOrder> serde::Serialize for $name<O> {
+            #[inline]
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&self.get(), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+        impl<'de, O: ByteOrder> serde::Deserialize<'de> for $name<O> {
+            #[inline]
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<$name<O>, D::Error> {
+                <$native as serde::Deserialize<'de>>::deserialize(deserializer).map($name::new)
+            }
+        }
     };
 }
 
@@ -931,6 +1150,105 @@ define_type!(
     []
 );
 
+/// Serializes values as their raw bytes.
+///
+/// With the `serde` feature, the types in this module serialize as their native
+/// numeric value - e.g., `U16::<BigEndian>::new(258)` serializes as `258`. This
+/// is usually what human-readable formats such as JSON want. Binary formats,
+/// on the other hand, often want to preserve the exact wire representation. To
+/// serialize a field as its bytes instead, annotate it with `#[serde(with =
+/// "zerocopy::byteorder::byte_array")]`. This serializes the field as an array
+/// of `size_of::<T>()` bytes (in the same way as `[u8; N]`), in the byte order
+/// in which they are stored in memory.
+///
+/// `byte_array` is not specific to the types in this module: it supports any
+/// field whose type is [`IntoBytes`] and [`Immutable`] (for serialization) or
+/// [`FromBytes`] and [`IntoBytes`] (for deserialization).
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use serde::{Deserialize, Serialize};
+/// use zerocopy::byteorder::big_endian::{U16, U32};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Header {
+///     len: U16,
+///     #[serde(with = "zerocopy::byteorder::byte_array")]
+///     checksum: U32,
+/// }
+///
+/// let header = Header { len: U16::new(258), checksum: U32::new(0xC0FFEE) };
+/// let json = serde_json::to_string(&header).unwrap();
+/// assert_eq!(json, r#"{"len":258,"checksum":[0,192,255,238]}"#);
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+pub mod byte_array {
+    use core::{fmt, marker::PhantomData, mem};
+
+    use serde::{
+        de::{Error, SeqAccess, Visitor},
+        ser::SerializeTuple,
+        Deserializer, Serializer,
+    };
+
+    use crate::{FromBytes, Immutable, IntoBytes};
+
+    /// Serializes `value` as an array of its bytes.
+    ///
+    /// See the [module documentation](self) for more information.
+    #[inline]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: IntoBytes + Immutable,
+        S: Serializer,
+    {
+        let bytes = value.as_bytes();
+        let mut tuple = serializer.serialize_tuple(bytes.len())?;
+        for byte in bytes {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+
+    /// Deserializes a value from an array of its bytes.
+    ///
+    /// See the [module documentation](self) for more information.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromBytes + IntoBytes,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(mem::size_of::<T>(), ByteArrayVisitor(PhantomData))
+    }
+
+    struct ByteArrayVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: FromBytes + IntoBytes> Visitor<'de> for ByteArrayVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "an array of {} bytes", mem::size_of::<T>())
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+            let mut value = T::new_zeroed();
+            for (i, byte) in value.as_mut_bytes().iter_mut().enumerate() {
+                *byte = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+            Ok(value)
+        }
+
+        fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<T, E> {
+            T::read_from_bytes(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+        }
+    }
+}
+
 macro_rules! module {
     ($name:ident, $trait:ident, $endianness_str:expr) => {
         /// Numeric primitives stored in
@@ -1014,6 +1332,9 @@ mod tests {
     }
 
     use compatibility::*;
+    // Disambiguate from `crate::Arbitrary`, which is in scope via `super::*`
+    // when the `arbitrary` or `proptest` feature is enabled.
+    use compatibility::Arbitrary;
 
     // A native integer type (u16, i32, etc).
     trait Native: Arbitrary + FromBytes + IntoBytes + Immutable + Copy + PartialEq + Debug {
@@ -1114,6 +1435,7 @@ mod tests {
     macro_rules! impl_byte_order_type_unsigned {
         ($name:ident, unsigned) => {
             impl<O: ByteOrder> ByteOrderTypeUnsigned for $name<O> {
+                #[allow(deprecated)]
                 const MAX_VALUE: $name<O> = $name::MAX_VALUE;
             }
         };
@@ -1516,6 +1838,203 @@ mod tests {
         test!(@unary Neg, neg, call_for_signed_types, call_for_float_types);
     }
 
+    #[test]
+    fn test_native_methods() {
+        // Check that each method agrees with the native method of the same
+        // name for both byte orders, and for a mix of edge-case and random
+        // values.
+        macro_rules! test_integer {
+            ($name:ident, $native:ident, $byteorder:ident, |$a:ident, $x:ident| $extra:block) => {{
+                type T = $name<$byteorder>;
+
+                assert_eq!(T::MIN.get(), $native::MIN);
+                assert_eq!(T::MAX.get(), $native::MAX);
+                assert_eq!(T::BITS, $native::BITS);
+
+                let mut r = SmallRng::seed_from_u64(RNG_SEED);
+                let mut values = vec![0, 1, 2, $native::MIN, $native::MAX, $native::MAX / 2];
+                values.extend((0..RAND_ITERS.min(16)).map(|_| r.gen::<$native>()));
+
+                for &$a in &values {
+                    let $x = T::new($a);
+
+                    assert_eq!($x.count_ones(), $a.count_ones());
+                    assert_eq!($x.count_zeros(), $a.count_zeros());
+                    assert_eq!($x.leading_zeros(), $a.leading_zeros());
+                    assert_eq!($x.trailing_zeros(), $a.trailing_zeros());
+                    assert_eq!($x.leading_ones(), $a.leading_ones());
+                    assert_eq!($x.trailing_ones(), $a.trailing_ones());
+                    assert_eq!($x.swap_bytes().get(), $a.swap_bytes());
+                    assert_eq!($x.reverse_bits().get(), $a.reverse_bits());
+                    assert_eq!($x.wrapping_neg().get(), $a.wrapping_neg());
+                    assert_eq!($x.checked_neg().map(T::get), $a.checked_neg());
+                    assert_eq!($x.overflowing_neg().0.get(), $a.overflowing_neg().0);
+                    assert_eq!($x.overflowing_neg().1, $a.overflowing_neg().1);
+
+                    assert_eq!($x.to_be_bytes(), $a.to_be_bytes());
+                    assert_eq!($x.to_le_bytes(), $a.to_le_bytes());
+                    assert_eq!($x.to_ne_bytes(), $a.to_ne_bytes());
+                    assert_eq!(T::from_be_bytes($a.to_be_bytes()), $x);
+                    assert_eq!(T::from_le_bytes($a.to_le_bytes()), $x);
+                    assert_eq!(T::from_ne_bytes($a.to_ne_bytes()), $x);
+
+                    for n in [0, 1, 7, $native::BITS - 1, $native::BITS, $native::BITS + 1] {
+                        assert_eq!($x.rotate_left(n).get(), $a.rotate_left(n));
+                        assert_eq!($x.rotate_right(n).get(), $a.rotate_right(n));
+                        assert_eq!($x.wrapping_pow(n).get(), $a.wrapping_pow(n));
+                        assert_eq!($x.saturating_pow(n).get(), $a.saturating_pow(n));
+                        assert_eq!($x.wrapping_shl(n).get(), $a.wrapping_shl(n));
+                        assert_eq!($x.wrapping_shr(n).get(), $a.wrapping_shr(n));
+                        assert_eq!($x.checked_pow(n).map(T::get), $a.checked_pow(n));
+                        assert_eq!($x.checked_shl(n).map(T::get), $a.checked_shl(n));
+                        assert_eq!($x.checked_shr(n).map(T::get), $a.checked_shr(n));
+                        assert_eq!($x.overflowing_pow(n).0.get(), $a.overflowing_pow(n).0);
+                        assert_eq!($x.overflowing_shl(n).0.get(), $a.overflowing_shl(n).0);
+                        assert_eq!($x.overflowing_shr(n).1, $a.overflowing_shr(n).1);
+                        if let Some(p) = $a.checked_pow(n) {
+                            assert_eq!($x.pow(n).get(), p);
+                        }
+                    }
+
+                    for &b in &values {
+                        let y = T::new(b);
+
+                        assert_eq!($x.wrapping_add(y).get(), $a.wrapping_add(b));
+                        assert_eq!($x.wrapping_sub(y).get(), $a.wrapping_sub(b));
+                        assert_eq!($x.wrapping_mul(y).get(), $a.wrapping_mul(b));
+                        assert_eq!($x.saturating_add(y).get(), $a.saturating_add(b));
+                        assert_eq!($x.saturating_sub(y).get(), $a.saturating_sub(b));
+                        assert_eq!($x.saturating_mul(y).get(), $a.saturating_mul(b));
+                        assert_eq!($x.checked_add(y).map(T::get), $a.checked_add(b));
+                        assert_eq!($x.checked_sub(y).map(T::get), $a.checked_sub(b));
+                        assert_eq!($x.checked_mul(y).map(T::get), $a.checked_mul(b));
+                        assert_eq!($x.checked_div(y).map(T::get), $a.checked_div(b));
+                        assert_eq!($x.checked_rem(y).map(T::get), $a.checked_rem(b));
+                        assert_eq!($x.checked_div_euclid(y).map(T::get), $a.checked_div_euclid(b));
+                        assert_eq!($x.checked_rem_euclid(y).map(T::get), $a.checked_rem_euclid(b));
+                        assert_eq!($x.overflowing_add(y).0.get(), $a.overflowing_add(b).0);
+                        assert_eq!($x.overflowing_sub(y).1, $a.overflowing_sub(b).1);
+                        assert_eq!($x.overflowing_mul(y).0.get(), $a.overflowing_mul(b).0);
+                        assert_eq!($x.overflowing_mul(y).1, $a.overflowing_mul(b).1);
+
+                        // The remaining methods panic on division by zero.
+                        if b != 0 {
+                            assert_eq!($x.wrapping_div(y).get(), $a.wrapping_div(b));
+                            assert_eq!($x.wrapping_rem(y).get(), $a.wrapping_rem(b));
+                            assert_eq!($x.wrapping_div_euclid(y).get(), $a.wrapping_div_euclid(b));
+                            assert_eq!($x.wrapping_rem_euclid(y).get(), $a.wrapping_rem_euclid(b));
+                            assert_eq!($x.saturating_div(y).get(), $a.saturating_div(b));
+                            assert_eq!($x.overflowing_div(y).1, $a.overflowing_div(b).1);
+                            assert_eq!($x.overflowing_rem(y).0.get(), $a.overflowing_rem(b).0);
+                            assert_eq!(
+                                $x.overflowing_div_euclid(y).0.get(),
+                                $a.overflowing_div_euclid(b).0
+                            );
+                            assert_eq!(
+                                $x.overflowing_rem_euclid(y).1,
+                                $a.overflowing_rem_euclid(b).1
+                            );
+                        }
+                        if let Some(q) = $a.checked_div_euclid(b) {
+                            assert_eq!($x.div_euclid(y).get(), q);
+                            assert_eq!($x.rem_euclid(y).get(), $a.rem_euclid(b));
+                        }
+                    }
+
+                    $extra
+                }
+            }};
+        }
+
+        macro_rules! test_unsigned {
+            ($($name:ident, $native:ident);*) => {$(
+                test_integer!($name, $native, BigEndian, |a, x| {
+                    assert_eq!(x.is_power_of_two(), a.is_power_of_two());
+                    assert_eq!(x.checked_next_power_of_two().map($name::get), a.checked_next_power_of_two());
+                    if let Some(p) = a.checked_next_power_of_two() {
+                        assert_eq!(x.next_power_of_two().get(), p);
+                    }
+                });
+                test_integer!($name, $native, LittleEndian, |_a, _x| {});
+            )*};
+        }
+
+        macro_rules! test_signed {
+            ($($name:ident, $native:ident);*) => {$(
+                test_integer!($name, $native, BigEndian, |a, x| {
+                    assert_eq!(x.is_positive(), a.is_positive());
+                    assert_eq!(x.is_negative(), a.is_negative());
+                    assert_eq!(x.signum().get(), a.signum());
+                    assert_eq!(x.wrapping_abs().get(), a.wrapping_abs());
+                    assert_eq!(x.saturating_abs().get(), a.saturating_abs());
+                    assert_eq!(x.saturating_neg().get(), a.saturating_neg());
+                    assert_eq!(x.checked_abs().map($name::get), a.checked_abs());
+                    assert_eq!(x.overflowing_abs().1, a.overflowing_abs().1);
+                    if let Some(abs) = a.checked_abs() {
+                        assert_eq!(x.abs().get(), abs);
+                    }
+                });
+                test_integer!($name, $native, LittleEndian, |_a, _x| {});
+            )*};
+        }
+
+        test_unsigned!(U16, u16; U32, u32; U64, u64; U128, u128; Usize, usize);
+        test_signed!(I16, i16; I32, i32; I64, i64; I128, i128; Isize, isize);
+
+        macro_rules! test_float {
+            ($($name:ident, $native:ident);*) => {$(
+                type T = $name<LittleEndian>;
+
+                assert_eq!(T::MIN.get(), $native::MIN);
+                assert_eq!(T::MAX.get(), $native::MAX);
+                assert_eq!(T::MIN_POSITIVE.get(), $native::MIN_POSITIVE);
+                assert_eq!(T::EPSILON.get(), $native::EPSILON);
+                assert_eq!(T::INFINITY.get(), $native::INFINITY);
+                assert_eq!(T::NEG_INFINITY.get(), $native::NEG_INFINITY);
+                assert!(T::NAN.is_nan());
+
+                let values = [0.0, -0.0, 1.5, -2.25, 1e-40, $native::MAX, $native::INFINITY, $native::NAN];
+                for &a in &values {
+                    let x = T::new(a);
+
+                    assert_eq!(x.is_nan(), a.is_nan());
+                    assert_eq!(x.is_infinite(), a.is_infinite());
+                    assert_eq!(x.is_finite(), a.is_finite());
+                    assert_eq!(x.is_subnormal(), a.is_subnormal());
+                    assert_eq!(x.is_normal(), a.is_normal());
+                    assert_eq!(x.is_sign_positive(), a.is_sign_positive());
+                    assert_eq!(x.is_sign_negative(), a.is_sign_negative());
+                    assert_eq!(x.classify(), a.classify());
+                    assert_eq!(x.to_be_bytes(), a.to_be_bytes());
+                    assert_eq!(T::from_le_bytes(a.to_le_bytes()).get().to_bits(), a.to_bits());
+
+                    x.recip().get().assert_eq_or_nan(a.recip());
+                    x.to_degrees().get().assert_eq_or_nan(a.to_degrees());
+                    x.floor().get().assert_eq_or_nan(a.floor());
+                    x.sqrt().get().assert_eq_or_nan(a.sqrt());
+                    x.powi(3).get().assert_eq_or_nan(a.powi(3));
+                    x.clamp(T::new(-1.0), T::new(1.0)).get().assert_eq_or_nan(a.clamp(-1.0, 1.0));
+
+                    for &b in &values {
+                        let y = T::new(b);
+                        assert_eq!(x.total_cmp(&y), a.total_cmp(&b));
+                        x.max(y).get().assert_eq_or_nan(a.max(b));
+                        x.min(y).get().assert_eq_or_nan(a.min(b));
+                        x.copysign(y).get().assert_eq_or_nan(a.copysign(b));
+                        x.mul_add(y, x).get().assert_eq_or_nan(a.mul_add(b, a));
+                    }
+                }
+            )*};
+        }
+
+        {
+            test_float!(F32, f32);
+        }
+        {
+            test_float!(F64, f64);
+        }
+    }
+
     #[test]
     fn test_debug_impl() {
         // Ensure that Debug applies format options to the inner value.
@@ -1524,4 +2043,61 @@ mod tests {
         assert_eq!(format!("{:03?}", val), "U16(010)");
         assert_eq!(format!("{:x?}", val), "U16(a)");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde::{de::value::BytesDeserializer, Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Header {
+            a: U16<BE>,
+            b: I32<LE>,
+            c: U64<BE>,
+            d: I128<LE>,
+            e: Usize<BE>,
+            f: F32<BE>,
+            g: F64<LE>,
+            #[serde(with = "byte_array")]
+            h: U32<BE>,
+            #[serde(with = "byte_array")]
+            i: I16<LE>,
+        }
+
+        let header = Header {
+            a: U16::new(258),
+            b: I32::new(-7),
+            c: U64::new(1 << 40),
+            d: I128::new(-1),
+            e: Usize::new(42),
+            f: F32::new(1.5),
+            g: F64::new(-0.25),
+            h: U32::new(0x01020304),
+            i: I16::new(-2),
+        };
+
+        // Human-readable formats see native values, except for `byte_array`
+        // fields.
+        let json = serde_json::to_string(&header).unwrap();
+        assert_eq!(
+            json,
+            r#"{"a":258,"b":-7,"c":1099511627776,"d":-1,"e":42,"f":1.5,"g":-0.25,"h":[1,2,3,4],"i":[254,255]}"#
+        );
+        assert_eq!(serde_json::from_str::<Header>(&json).unwrap(), header);
+        serde_json::from_str::<U16<BE>>("65536").unwrap_err();
+        serde_json::from_str::<Header>(&json.replace("[1,2,3,4]", "[1,2,3]")).unwrap_err();
+
+        // Binary formats see `byte_array` fields' bytes in their stored byte
+        // order, and without a length prefix.
+        let bin = bincode::serialize(&header).unwrap();
+        assert_eq!(bin[bin.len() - 6..], [1, 2, 3, 4, 254, 255]);
+        assert_eq!(bin[..2], 258u16.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Header>(&bin).unwrap(), header);
+
+        // Formats which produce byte strings are also supported.
+        let de = BytesDeserializer::<serde::de::value::Error>::new(&[1, 2, 3, 4]);
+        assert_eq!(byte_array::deserialize::<U32<BE>, _>(de).unwrap(), U32::new(0x01020304));
+        let de = BytesDeserializer::<serde::de::value::Error>::new(&[1, 2, 3]);
+        byte_array::deserialize::<U32<BE>, _>(de).unwrap_err();
+    }
 }
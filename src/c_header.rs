@@ -0,0 +1,431 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! Generation of C struct definitions from [`TypeLayout`]s.
+
+use super::*;
+
+/// A C header which defines structs with the same layouts as Rust types.
+///
+/// A `CHeader` is built from the [`TypeLayout`]s of `repr(C)` structs which
+/// implement [`DescribeLayout`]. Its `Display` implementation produces a C11
+/// header which contains a definition of each of those structs (and of every
+/// struct they contain), each followed by `_Static_assert`s which check that
+/// the C compiler lays the struct out exactly as Rust does: its size, its
+/// alignment, and the offset of each of its fields. If the Rust and C
+/// definitions of a struct drift apart, the C build fails.
+///
+/// Fields are mapped to C types as follows:
+/// - Primitive integer and floating-point types are mapped to the
+///   corresponding fixed-width C types (`u32` to `uint32_t`, `f64` to
+///   `double`, etc.). `bool` is mapped to `bool`, and `char` to `uint32_t`.
+/// - The types in [`byteorder`] are mapped in the same way, annotated with a
+///   comment naming their byte order. Since they have no alignment
+///   requirement, they are declared `ZEROCOPY_UNALIGNED`, which the header
+///   defines as `__attribute__((packed))` unless it is already defined.
+/// - Structs are mapped to `struct` types of the same name, and arrays to
+///   arrays.
+/// - A trailing slice is mapped to a flexible array member.
+/// - Zero-sized fields, which C does not support, are omitted.
+/// - Other types are mapped to byte arrays of the same size.
+///
+/// `repr(packed(N))` is reproduced using `#pragma pack(N)`, and `repr(align(N))`
+/// using `_Alignas(N)`.
+///
+/// `CHeader` is intended to be used from tests or build scripts which write
+/// the header to a file.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::{byteorder::{BigEndian, U32}, CHeader};
+/// # use zerocopy_derive::*;
+/// #[derive(KnownLayout, DescribeLayout)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     id: U32<BigEndian>,
+/// }
+///
+/// #[derive(KnownLayout, DescribeLayout)]
+/// #[repr(C)]
+/// struct Packet {
+///     header: Header,
+///     len: u16,
+///     body: [u8],
+/// }
+///
+/// let header = CHeader::new().add::<Packet>().to_string();
+/// assert!(header.ends_with(
+///     "struct Header {
+///     uint8_t kind;
+///     uint32_t id ZEROCOPY_UNALIGNED; /* big-endian */
+/// };
+/// _Static_assert(sizeof(struct Header) == 5, \"size of Header\");
+/// _Static_assert(_Alignof(struct Header) == 1, \"alignment of Header\");
+/// _Static_assert(offsetof(struct Header, kind) == 0, \"offset of Header.kind\");
+/// _Static_assert(offsetof(struct Header, id) == 1, \"offset of Header.id\");
+///
+/// struct Packet {
+///     struct Header header;
+///     uint16_t len;
+///     uint8_t body[];
+/// };
+/// _Static_assert(sizeof(struct Packet) == 8, \"size of Packet\");
+/// _Static_assert(_Alignof(struct Packet) == 2, \"alignment of Packet\");
+/// _Static_assert(offsetof(struct Packet, header) == 0, \"offset of Packet.header\");
+/// _Static_assert(offsetof(struct Packet, len) == 6, \"offset of Packet.len\");
+/// _Static_assert(offsetof(struct Packet, body) == 8, \"offset of Packet.body\");
+/// "
+/// ));
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+pub struct CHeader {
+    structs: Vec<&'static TypeLayout>,
+}
+
+impl CHeader {
+    /// Constructs an empty `CHeader`.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> CHeader {
+        CHeader { structs: Vec::new() }
+    }
+
+    /// Adds a definition of `T`, and of every struct it contains, to the
+    /// header.
+    ///
+    /// If `T` is not a struct, only the structs it contains are defined.
+    #[inline]
+    pub fn add<T: ?Sized + DescribeLayout>(&mut self) -> &mut CHeader {
+        self.add_layout(&T::TYPE_LAYOUT)
+    }
+
+    /// Adds a definition of the type described by `layout`, and of every
+    /// struct it contains, to the header.
+    ///
+    /// Each struct is defined after the structs it contains, and is defined
+    /// only once. If two different structs have the same name, the header
+    /// contains an `#error` directive.
+    #[inline]
+    pub fn add_layout(&mut self, layout: &'static TypeLayout) -> &mut CHeader {
+        match layout.kind() {
+            TypeKind::Primitive { .. } => {}
+            TypeKind::Array { elem, .. } | TypeKind::Slice { elem } => {
+                self.add_layout(elem);
+            }
+            TypeKind::Struct { fields, .. } => {
+                for field in *fields {
+                    self.add_layout(field.layout());
+                }
+                if !self.structs.contains(&layout) {
+                    self.structs.push(layout);
+                }
+            }
+        }
+        self
+    }
+}
+
+/// The prelude of every [`CHeader`].
+const PRELUDE: &str = "/* Generated by zerocopy. Do not edit. */
+
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifndef ZEROCOPY_UNALIGNED
+#define ZEROCOPY_UNALIGNED __attribute__((packed))
+#endif
+";
+
+impl Display for CHeader {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(PRELUDE)?;
+        for (i, layout) in self.structs.iter().enumerate() {
+            f.write_str("\n")?;
+            let name = struct_name(layout);
+            let redefined =
+                self.structs.get(..i).unwrap_or(&[]).iter().any(|prev| struct_name(prev) == name);
+            if redefined {
+                writeln!(f, "#error \"conflicting definitions of struct {}\"", name)?;
+            } else {
+                fmt_struct(layout, f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The name of the struct described by `layout`.
+const fn struct_name(layout: &TypeLayout) -> &'static str {
+    match layout.kind() {
+        TypeKind::Struct { name, .. } => name,
+        _ => "",
+    }
+}
+
+/// Whether a field of the given type can be declared in C.
+fn is_declared(field: &FieldLayout) -> bool {
+    field.layout().size() > 0 || field.layout().trailing_slice_elem().is_some()
+}
+
+/// Writes the definition of a struct and the assertions which check its
+/// layout.
+fn fmt_struct(layout: &TypeLayout, f: &mut Formatter<'_>) -> fmt::Result {
+    let name = struct_name(layout);
+    let fields = layout.fields();
+    if !fields.iter().any(is_declared) {
+        return writeln!(f, "/* struct {} is zero-sized, and so cannot be defined in C. */", name);
+    }
+
+    // A struct whose alignment is lower than that of one of its fields is
+    // `repr(packed)`; one whose alignment is higher than that of all of its
+    // fields is `repr(align)`.
+    let natural_align = fields.iter().map(|field| field.layout().align()).max().unwrap_or(1);
+    let packed = layout.align() < natural_align;
+    let mut over_aligned = layout.align() > natural_align;
+
+    if packed {
+        writeln!(f, "#pragma pack(push, {})", layout.align())?;
+    }
+    writeln!(f, "struct {} {{", name)?;
+    for field in fields {
+        if !is_declared(field) {
+            writeln!(f, "    /* {}: {} is zero-sized */", field.name(), field.layout())?;
+            continue;
+        }
+        f.write_str("    ")?;
+        if over_aligned {
+            write!(f, "_Alignas({}) ", layout.align())?;
+            over_aligned = false;
+        }
+        fmt_field(field, f)?;
+    }
+    writeln!(f, "}};")?;
+    if packed {
+        writeln!(f, "#pragma pack(pop)")?;
+    }
+
+    writeln!(
+        f,
+        "_Static_assert(sizeof(struct {0}) == {1}, \"size of {0}\");",
+        name,
+        layout.size()
+    )?;
+    writeln!(
+        f,
+        "_Static_assert(_Alignof(struct {0}) == {1}, \"alignment of {0}\");",
+        name,
+        layout.align()
+    )?;
+    for field in fields.iter().filter(|field| is_declared(field)) {
+        writeln!(
+            f,
+            "_Static_assert(offsetof(struct {0}, {1}) == {2}, \"offset of {0}.{1}\");",
+            name,
+            CIdent(field.name()),
+            field.offset()
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes the declaration of a field.
+fn fmt_field(field: &FieldLayout, f: &mut Formatter<'_>) -> fmt::Result {
+    // Peel off arrays and slices to find the element type.
+    let mut elem = field.layout();
+    while let TypeKind::Array { elem: inner, .. } | TypeKind::Slice { elem: inner } = elem.kind() {
+        elem = inner;
+    }
+
+    let primitive = match elem.kind() {
+        TypeKind::Primitive { name } => Some((*name, c_primitive(name))),
+        _ => None,
+    };
+    match primitive {
+        None => write!(f, "struct {} {}", struct_name(elem), CIdent(field.name()))?,
+        Some((_, Some(ty))) => write!(f, "{} {}", ty, CIdent(field.name()))?,
+        Some((_, None)) => write!(f, "uint8_t {}", CIdent(field.name()))?,
+    }
+
+    let mut layout = field.layout();
+    loop {
+        match layout.kind() {
+            TypeKind::Array { elem, len } => {
+                write!(f, "[{}]", len)?;
+                layout = elem;
+            }
+            TypeKind::Slice { elem } => {
+                f.write_str("[]")?;
+                layout = elem;
+            }
+            TypeKind::Primitive { .. } | TypeKind::Struct { .. } => break,
+        }
+    }
+
+    match primitive {
+        Some((name, None)) => write!(f, "[{}]; /* {} */", elem.size(), name)?,
+        Some((name, Some(_))) => {
+            // Byte-order-aware types have no alignment requirement, but their
+            // C counterparts do. This cannot be inferred from the alignment of
+            // the type, since some native types (e.g., `u64` on i686) are
+            // also aligned to less than their size.
+            let byte_order_aware = elem.is_byte_order_aware();
+            if byte_order_aware {
+                f.write_str(" ZEROCOPY_UNALIGNED")?;
+            }
+            f.write_str(";")?;
+            match (elem.big_endian(), byte_order_aware) {
+                (Some(true), true) => f.write_str(" /* big-endian */")?,
+                (Some(false), true) => f.write_str(" /* little-endian */")?,
+                _ if name == "char" => f.write_str(" /* char */")?,
+                _ => {}
+            }
+        }
+        None => f.write_str(";")?,
+    }
+    f.write_str("\n")
+}
+
+/// The C type corresponding to the primitive type with the given name, if
+/// any.
+fn c_primitive(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "bool" => "bool",
+        "u8" => "uint8_t",
+        "u16" | "U16" => "uint16_t",
+        "u32" | "U32" | "char" => "uint32_t",
+        "u64" | "U64" => "uint64_t",
+        "u128" | "U128" => "unsigned __int128",
+        "usize" | "Usize" => "uintptr_t",
+        "i8" => "int8_t",
+        "i16" | "I16" => "int16_t",
+        "i32" | "I32" => "int32_t",
+        "i64" | "I64" => "int64_t",
+        "i128" | "I128" => "__int128",
+        "isize" | "Isize" => "intptr_t",
+        "f32" | "F32" => "float",
+        "f64" | "F64" => "double",
+        _ => return None,
+    })
+}
+
+/// A field name, formatted as a C identifier.
+///
+/// The fields of tuple structs, whose names are indices, are prefixed with an
+/// underscore.
+struct CIdent(&'static str);
+
+impl Display for CIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.starts_with(|c: char| c.is_ascii_digit()) {
+            f.write_str("_")?;
+        }
+        f.write_str(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(KnownLayout, DescribeLayout)]
+    #[repr(C, packed(2))]
+    struct Packed(u8, u32, [I16<LittleEndian>; 2]);
+
+    #[derive(KnownLayout, DescribeLayout)]
+    #[repr(C, align(8))]
+    struct Aligned {
+        a: (),
+        b: [Packed; 2],
+        c: char,
+        d: [[u16; 2]],
+    }
+
+    #[test]
+    fn test_c_header() {
+        let header = CHeader::new().add::<[Packed; 3]>().add::<Aligned>().to_string();
+        assert!(header.starts_with(PRELUDE));
+        assert_eq!(
+            &header[PRELUDE.len()..],
+            "
+#pragma pack(push, 2)
+struct Packed {
+    uint8_t _0;
+    uint32_t _1;
+    int16_t _2[2] ZEROCOPY_UNALIGNED; /* little-endian */
+};
+#pragma pack(pop)
+_Static_assert(sizeof(struct Packed) == 10, \"size of Packed\");
+_Static_assert(_Alignof(struct Packed) == 2, \"alignment of Packed\");
+_Static_assert(offsetof(struct Packed, _0) == 0, \"offset of Packed._0\");
+_Static_assert(offsetof(struct Packed, _1) == 2, \"offset of Packed._1\");
+_Static_assert(offsetof(struct Packed, _2) == 6, \"offset of Packed._2\");
+
+struct Aligned {
+    /* a: () is zero-sized */
+    _Alignas(8) struct Packed b[2];
+    uint32_t c; /* char */
+    uint16_t d[][2];
+};
+_Static_assert(sizeof(struct Aligned) == 24, \"size of Aligned\");
+_Static_assert(_Alignof(struct Aligned) == 8, \"alignment of Aligned\");
+_Static_assert(offsetof(struct Aligned, b) == 0, \"offset of Aligned.b\");
+_Static_assert(offsetof(struct Aligned, c) == 20, \"offset of Aligned.c\");
+_Static_assert(offsetof(struct Aligned, d) == 24, \"offset of Aligned.d\");
+"
+        );
+
+        #[derive(KnownLayout, DescribeLayout)]
+        #[repr(C)]
+        struct Empty {
+            a: [u8; 0],
+        }
+
+        #[derive(KnownLayout, DescribeLayout)]
+        #[repr(C)]
+        struct Conflicting {
+            a: Empty,
+        }
+
+        let header = CHeader::new().add::<Conflicting>().add_layout(&CONFLICTING).to_string();
+        assert_eq!(
+            &header[PRELUDE.len()..],
+            "
+/* struct Empty is zero-sized, and so cannot be defined in C. */
+
+/* struct Conflicting is zero-sized, and so cannot be defined in C. */
+
+#error \"conflicting definitions of struct Conflicting\"
+"
+        );
+
+        // Native types are never declared unaligned, even on targets on which
+        // they are aligned to less than their size.
+        #[derive(KnownLayout, DescribeLayout)]
+        #[repr(C)]
+        struct Native {
+            a: u64,
+            b: f64,
+        }
+
+        let header = CHeader::new().add::<Native>().to_string();
+        assert!(header.contains("    uint64_t a;\n    double b;\n"));
+        assert!(!header[PRELUDE.len()..].contains("ZEROCOPY_UNALIGNED"));
+
+        static CONFLICTING: TypeLayout = util::macro_util::struct_type_layout::<u8>(
+            "Conflicting",
+            &[util::macro_util::field_layout("a", 0, &u8::TYPE_LAYOUT)],
+        );
+    }
+}
@@ -58,6 +58,11 @@
 //! When `debug_assertions` are disabled (as is default for `release` builds),
 //! such potentially sensitive information is excluded.
 //!
+//! When the destination type implements [`DescribeLayout`],
+//! [`CastError::with_hex_dump`] and [`TryCastError::with_hex_dump`] extend
+//! this verbose message with a [`HexDump`] of the source bytes, which shows
+//! the offending bytes alongside the destination type's fields.
+//!
 //! In the future, we may support manually configuring this behavior. If you are
 //! interested in this feature, [let us know on GitHub][issue-1457] so we know
 //! to prioritize it.
@@ -126,7 +131,9 @@ use core::error::Error;
 #[cfg(all(not(zerocopy_core_error), any(feature = "std", test)))]
 use std::error::Error;
 
-use crate::{util::SendSyncPhantomData, KnownLayout, TryFromBytes, Unaligned};
+use crate::{
+    util::SendSyncPhantomData, DescribeLayout, HexDump, KnownLayout, TryFromBytes, Unaligned,
+};
 #[cfg(doc)]
 use crate::{FromBytes, Ref};
 
@@ -556,11 +563,58 @@ pub struct ValidityError<Src, Dst: ?Sized + TryFromBytes> {
     pub(crate) src: Src,
     /// The inner destination type inolved in the conversion.
     dst: SendSyncPhantomData<Dst>,
+    /// The name of the field which did not hold its required constant, if
+    /// known.
+    mismatched_constant: Option<&'static str>,
 }
 
 impl<Src, Dst: ?Sized + TryFromBytes> ValidityError<Src, Dst> {
     pub(crate) fn new(src: Src) -> Self {
-        Self { src, dst: SendSyncPhantomData::default() }
+        Self { src, dst: SendSyncPhantomData::default(), mismatched_constant: None }
+    }
+
+    /// Records the name of the field which did not hold its required
+    /// constant.
+    pub(crate) fn with_mismatched_constant(
+        self,
+        mismatched_constant: Option<&'static str>,
+    ) -> Self {
+        Self { mismatched_constant, ..self }
+    }
+
+    /// The name of the field which did not hold the constant required by its
+    /// `#[zerocopy(eq = ...)]` attribute, if that is why the conversion
+    /// failed.
+    ///
+    /// This is reported by conversions of references and by
+    /// [`TryFromBytes::try_read_from_bytes`] and its variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::*;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(TryFromBytes, KnownLayout, Immutable)]
+    /// #[repr(C)]
+    /// struct Header {
+    ///     #[zerocopy(eq = b"\x7fELF")]
+    ///     magic: [u8; 4],
+    ///     #[zerocopy(eq = 1)]
+    ///     class: u8,
+    /// }
+    ///
+    /// match Header::try_ref_from_bytes(&b"\x7fELF\x02"[..]) {
+    ///     Err(ConvertError::Validity(err)) => {
+    ///         assert_eq!(err.mismatched_constant(), Some("class"));
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn mismatched_constant(&self) -> Option<&'static str> {
+        self.mismatched_constant
     }
 
     /// Produces the source underlying the failed conversion.
@@ -591,7 +645,11 @@ impl<Src, Dst: ?Sized + TryFromBytes> ValidityError<Src, Dst> {
     /// ```
     #[inline]
     pub fn map_src<NewSrc>(self, f: impl Fn(Src) -> NewSrc) -> ValidityError<NewSrc, Dst> {
-        ValidityError { src: f(self.src), dst: SendSyncPhantomData::default() }
+        ValidityError {
+            src: f(self.src),
+            dst: SendSyncPhantomData::default(),
+            mismatched_constant: self.mismatched_constant,
+        }
     }
 
     /// Converts the error into a general [`ConvertError`].
@@ -609,6 +667,10 @@ impl<Src, Dst: ?Sized + TryFromBytes> ValidityError<Src, Dst> {
     {
         f.write_str("Destination type: ")?;
         f.write_str(core::any::type_name::<Dst>())?;
+        if let Some(field) = self.mismatched_constant {
+            f.write_str("\nMismatched constant field: ")?;
+            f.write_str(field)?;
+        }
         Ok(())
     }
 }
@@ -782,6 +844,44 @@ impl<Src, Dst: ?Sized + Unaligned> From<CastError<Src, Dst>> for SizeError<Src,
     }
 }
 
+impl<Src, Dst> CastError<Src, Dst>
+where
+    Src: Deref<Target = [u8]>,
+    Dst: ?Sized + DescribeLayout,
+{
+    /// Pairs this error with an annotated hex dump of its source bytes.
+    ///
+    /// When `debug_assertions` are enabled, the `Display` implementation of
+    /// the returned value produces this error's verbose message followed by a
+    /// [`HexDump`] of the source bytes, annotated according to the layout of
+    /// `Dst`. Otherwise, it produces this error's message alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::*;
+    ///
+    /// let source = [0u8, 1, 2];
+    ///
+    /// // Try to read a `[u16; 2]` from `source`. This will fail because there
+    /// // are insufficient bytes in `source`.
+    /// let err = <[u16; 2]>::ref_from_bytes(&source[..]).unwrap_err();
+    /// let msg = err.with_hex_dump().to_string();
+    /// # #[cfg(debug_assertions)]
+    /// assert!(msg.ends_with("\n\nSource bytes:\n[u16; 2] (3 bytes)\n0000  00 01 02  [u16; 2] <missing 1 byte>"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_hex_dump(&self) -> WithHexDump<'_, Self> {
+        let src = match self {
+            Self::Alignment(e) => &e.src,
+            Self::Size(e) => &e.src,
+            Self::Validity(i) => match *i {},
+        };
+        WithHexDump { err: self, dump: HexDump::from_bytes(src, &Dst::TYPE_LAYOUT) }
+    }
+}
+
 /// The error type of fallible reference conversions.
 ///
 /// Fallible reference conversions, like [`TryFromBytes::try_ref_from_bytes`]
@@ -851,6 +951,90 @@ impl<Src, Dst: ?Sized + TryFromBytes> From<CastError<Src, Dst>> for TryCastError
     }
 }
 
+impl<Src, Dst> TryCastError<Src, Dst>
+where
+    Src: Deref<Target = [u8]>,
+    Dst: ?Sized + TryFromBytes + DescribeLayout,
+{
+    /// Pairs this error with an annotated hex dump of its source bytes.
+    ///
+    /// When `debug_assertions` are enabled, the `Display` implementation of
+    /// the returned value produces this error's verbose message followed by a
+    /// [`HexDump`] of the source bytes, annotated according to the layout of
+    /// `Dst`. Otherwise, it produces this error's message alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::*;
+    ///
+    /// let source = [1u8, 2];
+    ///
+    /// // Try to read a `[bool; 2]` from `source`. This will fail because `2`
+    /// // is not a valid `bool`.
+    /// let err = <[bool; 2]>::try_ref_from_bytes(&source[..]).unwrap_err();
+    /// let msg = err.with_hex_dump().to_string();
+    /// # #[cfg(debug_assertions)]
+    /// assert!(msg.ends_with("\n\nSource bytes:\n[bool; 2] (2 bytes)\n0000  01 02  [bool; 2]"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_hex_dump(&self) -> WithHexDump<'_, Self> {
+        let src = match self {
+            Self::Alignment(e) => &e.src,
+            Self::Size(e) => &e.src,
+            Self::Validity(e) => &e.src,
+        };
+        WithHexDump { err: self, dump: HexDump::from_bytes(src, &Dst::TYPE_LAYOUT) }
+    }
+}
+
+/// An error paired with an annotated hex dump of its source bytes.
+///
+/// This type is produced by [`CastError::with_hex_dump`] and
+/// [`TryCastError::with_hex_dump`]. Its `Display` implementation produces the
+/// error's message and, when `debug_assertions` are enabled, a [`HexDump`] of
+/// the source bytes. Like the error's own verbose message, the hex dump may
+/// include potentially sensitive information.
+#[derive(Debug)]
+pub struct WithHexDump<'a, E> {
+    err: &'a E,
+    dump: HexDump<'a>,
+}
+
+impl<'a, E> WithHexDump<'a, E> {
+    /// The error.
+    #[must_use]
+    #[inline]
+    pub const fn error(&self) -> &'a E {
+        self.err
+    }
+
+    /// The hex dump of the error's source bytes.
+    #[must_use]
+    #[inline]
+    pub const fn hex_dump(&self) -> HexDump<'a> {
+        self.dump
+    }
+}
+
+/// Produces a human-readable error message.
+///
+/// The message differs between debug and release builds. When
+/// `debug_assertions` are enabled, this message is verbose and includes
+/// potentially sensitive information, including the contents of the source.
+impl<E: fmt::Display> fmt::Display for WithHexDump<'_, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.err.fmt(f)?;
+        if cfg!(debug_assertions) {
+            f.write_str("\n\nSource bytes:\n")?;
+            fmt::Display::fmt(&self.dump, f)?;
+        }
+        Ok(())
+    }
+}
+
 /// The error type of fallible read-conversions.
 ///
 /// Fallible read-conversions, like [`TryFromBytes::try_read_from_bytes`] may emit
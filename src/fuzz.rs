@@ -0,0 +1,491 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! Integration with the [`arbitrary`] and [`proptest`] crates.
+//!
+//! [`arbitrary`]: https://docs.rs/arbitrary
+//! [`proptest`]: https://docs.rs/proptest
+
+use core::{fmt::Debug, ptr::NonNull};
+
+use alloc::{
+    alloc::{alloc_zeroed, dealloc},
+    boxed::Box,
+    vec,
+};
+
+use super::*;
+
+/// The maximum number of byte patterns which [`Arbitrary`] and
+/// [`ArbitraryBox`] draw from an [`arbitrary::Unstructured`] before giving up.
+///
+/// [`arbitrary::Unstructured`]: https://docs.rs/arbitrary/1/arbitrary/struct.Unstructured.html
+#[cfg(feature = "arbitrary")]
+const MAX_ATTEMPTS: usize = 64;
+
+/// A `T` generated from fuzzer- or property-test-provided bytes.
+///
+/// `Arbitrary<T>` implements [`arbitrary::Arbitrary`] (with the `arbitrary`
+/// feature) and [`proptest::arbitrary::Arbitrary`] (with the `proptest`
+/// feature) for any [`TryFromBytes`] type `T`, so that wire types need no
+/// hand-written impls of those traits: the generated bytes are interpreted
+/// directly as a `T`.
+///
+/// If `T: FromBytes`, every byte pattern is valid, and so every input produces
+/// a value. Otherwise, byte patterns which are not valid instances of `T` are
+/// discarded:
+/// - With `arbitrary`, `Arbitrary<T>` draws a fresh byte pattern from the
+///   input until one is valid. If none of the first several are, or if the
+///   input is exhausted, it fails with [`arbitrary::Error::IncorrectFormat`].
+///   Since each of `T`'s validity checks (e.g., each of an enum's
+///   discriminants) is a distinct branch, coverage-guided fuzzers quickly learn
+///   to produce valid byte patterns.
+/// - With `proptest`, invalid byte patterns are rejected like any other
+///   filtered value, and count towards proptest's local rejection limit. Since
+///   proptest generates bytes uniformly, this is only practical for types
+///   whose valid byte patterns are not too sparse; for others (e.g., large
+///   arrays of `bool`), prefer a hand-written strategy.
+///
+/// For slice DSTs, see [`ArbitraryBox`].
+///
+/// [`arbitrary::Arbitrary`]: https://docs.rs/arbitrary/1/arbitrary/trait.Arbitrary.html
+/// [`arbitrary::Error::IncorrectFormat`]: https://docs.rs/arbitrary/1/arbitrary/enum.Error.html#variant.IncorrectFormat
+/// [`proptest::arbitrary::Arbitrary`]: https://docs.rs/proptest/1/proptest/arbitrary/trait.Arbitrary.html
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "arbitrary")] {
+/// use arbitrary::{Arbitrary as _, Unstructured};
+/// use zerocopy::{Arbitrary, TryFromBytes};
+/// # use zerocopy_derive::*;
+///
+/// #[derive(TryFromBytes, Debug)]
+/// #[repr(u8)]
+/// enum Opcode {
+///     Read = 1,
+///     Write = 2,
+/// }
+///
+/// #[derive(TryFromBytes, Debug)]
+/// #[repr(C)]
+/// struct Header {
+///     opcode: Opcode,
+///     flags: u8,
+///     len: [u8; 2],
+/// }
+///
+/// fn parse(header: &Header) {
+///     // ...
+/// }
+///
+/// // In a fuzz target, the bytes come from the fuzzer.
+/// let mut u = Unstructured::new(&[9, 0, 0, 0, 2, 0xFF, 0x10, 0x00]);
+/// let Arbitrary(header) = Arbitrary::<Header>::arbitrary(&mut u).unwrap();
+/// assert!(matches!(header.opcode, Opcode::Write));
+/// parse(&header);
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "arbitrary", feature = "proptest"))))]
+#[repr(transparent)]
+pub struct Arbitrary<T>(pub T);
+
+impl<T> Arbitrary<T> {
+    /// Consumes `self`, returning the generated value.
+    #[must_use]
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Arbitrary<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Arbitrary<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// A boxed slice DST generated from fuzzer- or property-test-provided bytes.
+///
+/// `ArbitraryBox<T>` is the counterpart of [`Arbitrary`] for [`KnownLayout`]
+/// types with a trailing slice, such as `[T]` or a `#[repr(C)]` struct whose
+/// last field is a slice. It first chooses the number of trailing slice
+/// elements, and then generates a `T` with that many elements in the same way
+/// as `Arbitrary` does for sized types:
+/// - With `arbitrary`, the number of elements is bounded by the remaining
+///   input.
+/// - With `proptest`, the number of elements is drawn from the
+///   [`SizeRange`] parameter, which defaults to `0..100`.
+///
+/// [`SizeRange`]: https://docs.rs/proptest/1/proptest/collection/struct.SizeRange.html
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "arbitrary")] {
+/// use arbitrary::{Arbitrary as _, Unstructured};
+/// use zerocopy::{ArbitraryBox, KnownLayout, TryFromBytes};
+/// # use zerocopy_derive::*;
+///
+/// #[derive(TryFromBytes, KnownLayout, Debug)]
+/// #[repr(C)]
+/// struct Packet {
+///     tag: u8,
+///     body: [bool],
+/// }
+///
+/// let mut u = Unstructured::new(&[0xAB, 0, 1, 1, 0, 0, 0, 0, 0]);
+/// let ArbitraryBox(packet) = ArbitraryBox::<Packet>::arbitrary(&mut u).unwrap();
+/// assert!(packet.body.len() <= 8);
+/// # }
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(doc_cfg, doc(cfg(any(feature = "arbitrary", feature = "proptest"))))]
+pub struct ArbitraryBox<T: ?Sized>(pub Box<T>);
+
+impl<T: ?Sized> ArbitraryBox<T> {
+    /// Consumes `self`, returning the generated value.
+    #[must_use]
+    #[inline(always)]
+    pub fn into_inner(self) -> Box<T> {
+        self.0
+    }
+}
+
+impl<T: ?Sized> Deref for ArbitraryBox<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for ArbitraryBox<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Generates a `T` by repeatedly calling `fill` on a `T`-sized buffer until it
+/// contains a bit-valid `T` or `fill` returns `false`.
+fn try_new<T: TryFromBytes>(mut fill: impl FnMut(&mut [u8]) -> bool) -> Option<T> {
+    let mut bytes = vec![0u8; mem::size_of::<T>()];
+    while fill(&mut bytes) {
+        if let Ok(t) = T::try_read_from_bytes(&bytes) {
+            return Some(t);
+        }
+    }
+    None
+}
+
+/// Generates a `Box<T>` with `elems` trailing slice elements by repeatedly
+/// calling `fill` on its (zero-initialized) allocation until it contains a
+/// bit-valid `T` or `fill` returns `false`.
+fn try_new_box<T>(elems: usize, mut fill: impl FnMut(&mut [u8]) -> bool) -> Option<Box<T>>
+where
+    T: ?Sized + TryFromBytes + KnownLayout<PointerMetadata = usize>,
+{
+    let size = elems.size_for_metadata(T::LAYOUT)?;
+    let layout = Layout::from_size_align(size, T::LAYOUT.align.get()).ok()?;
+
+    let ptr = if size != 0 {
+        // SAFETY: `layout` has non-zero size.
+        NonNull::new(unsafe { alloc_zeroed(layout) })?
+    } else {
+        // `Box` does not allocate for zero-sized values, but it does require
+        // a non-null, aligned dangling pointer.
+        //
+        // SAFETY: `layout.align()` is guaranteed to be non-zero.
+        unsafe { NonNull::new_unchecked(ptr::null_mut::<u8>().wrapping_add(layout.align())) }
+    };
+
+    // SAFETY: If `size != 0`, `ptr` was returned by `alloc_zeroed` for a
+    // layout of `size` bytes, and so is valid for reads and writes of `size`
+    // initialized bytes. Otherwise, `ptr` is non-null and aligned, which is
+    // all that is required of an empty slice. In both cases, nothing else
+    // references these bytes.
+    let bytes = unsafe { slice::from_raw_parts_mut(ptr.as_ptr(), size) };
+    while fill(bytes) {
+        if let Ok(t) = T::try_mut_from_bytes_with_elems(bytes, elems) {
+            let t: *mut T = t;
+            // SAFETY: `t` addresses all `size` bytes of the allocation (or of
+            // the dangling, zero-sized region), as `try_mut_from_bytes_with_elems`
+            // succeeds only if `bytes` is exactly the size of a `T` with
+            // `elems` trailing elements. That allocation was made by the global
+            // allocator with `layout`, whose size is `T::size_for_metadata`
+            // and whose alignment is `T`'s alignment; this is the layout `Box`
+            // uses for such a `T`. Per [1], when `size == 0`, `Box` requires
+            // only that the pointer be non-null and aligned. Finally,
+            // `try_mut_from_bytes_with_elems` guarantees that `t` references a
+            // bit-valid `T`.
+            //
+            // [1] Per https://doc.rust-lang.org/nightly/std/boxed/index.html#memory-layout:
+            //
+            //   For zero-sized values, the `Box` pointer has to be non-null
+            //   and sufficiently aligned.
+            return Some(unsafe { Box::from_raw(t) });
+        }
+    }
+
+    if size != 0 {
+        // SAFETY: `ptr` was allocated by the global allocator with `layout`,
+        // and `bytes` - the only reference to it - is no longer used.
+        unsafe { dealloc(ptr.as_ptr(), layout) };
+    }
+    None
+}
+
+/// Fills `bytes` from `u`, returning `false` once `attempts` reaches
+/// [`MAX_ATTEMPTS`].
+///
+/// Once `u` is exhausted, [`arbitrary::Unstructured::fill_buffer`] only
+/// produces zeroes, and so further attempts are pointless.
+///
+/// [`arbitrary::Unstructured::fill_buffer`]: https://docs.rs/arbitrary/1/arbitrary/struct.Unstructured.html#method.fill_buffer
+#[cfg(feature = "arbitrary")]
+fn fill_from(
+    u: &mut ::arbitrary::Unstructured<'_>,
+    attempts: &mut usize,
+    bytes: &mut [u8],
+) -> bool {
+    if *attempts == MAX_ATTEMPTS || (*attempts > 0 && u.is_empty()) {
+        return false;
+    }
+    // `*attempts < MAX_ATTEMPTS`, so this cannot overflow.
+    #[allow(clippy::arithmetic_side_effects)]
+    {
+        *attempts += 1;
+    }
+    u.fill_buffer(bytes).is_ok()
+}
+
+/// Returns a `fill` callback for `try_new` and `try_new_box` which
+/// produces `src` exactly once.
+#[cfg(feature = "proptest")]
+fn fill_once(src: &[u8]) -> impl FnMut(&mut [u8]) -> bool + '_ {
+    let mut src = Some(src);
+    move |bytes| match src.take() {
+        Some(src) if src.len() == bytes.len() => {
+            bytes.copy_from_slice(src);
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "arbitrary")))]
+impl<'a, T: TryFromBytes> ::arbitrary::Arbitrary<'a> for Arbitrary<T> {
+    #[inline]
+    fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        let mut attempts = 0;
+        try_new(|bytes| fill_from(u, &mut attempts, bytes))
+            .map(Arbitrary)
+            .ok_or(::arbitrary::Error::IncorrectFormat)
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        // Invalid byte patterns are retried, so there is no upper bound.
+        (mem::size_of::<T>(), None)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "arbitrary")))]
+impl<'a, T> ::arbitrary::Arbitrary<'a> for ArbitraryBox<T>
+where
+    T: ?Sized + TryFromBytes + KnownLayout<PointerMetadata = usize>,
+{
+    #[inline]
+    fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        let (offset, elem_size) = match T::LAYOUT.size_info {
+            SizeInfo::SliceDst(TrailingSliceLayout { offset, elem_size }) => (offset, elem_size),
+            // `T: KnownLayout<PointerMetadata = usize>` implies that `T` is a
+            // slice DST.
+            SizeInfo::Sized { .. } => unreachable!(),
+        };
+        // Bound the number of elements by the remaining input so that a
+        // short input cannot request an arbitrarily large allocation.
+        let max_elems = u.len().saturating_sub(offset).checked_div(elem_size).unwrap_or(u.len());
+        let elems = u.int_in_range(0..=max_elems)?;
+        let mut attempts = 0;
+        try_new_box(elems, |bytes| fill_from(u, &mut attempts, bytes))
+            .map(ArbitraryBox)
+            .ok_or(::arbitrary::Error::IncorrectFormat)
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+#[cfg(feature = "proptest")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "proptest")))]
+impl<T: TryFromBytes + Debug> ::proptest::arbitrary::Arbitrary for Arbitrary<T> {
+    type Parameters = ();
+    type Strategy = ::proptest::strategy::FilterMap<
+        ::proptest::collection::VecStrategy<::proptest::num::u8::Any>,
+        fn(alloc::vec::Vec<u8>) -> Option<Self>,
+    >;
+
+    #[inline]
+    fn arbitrary_with((): ()) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+
+        ::proptest::collection::vec(::proptest::num::u8::ANY, mem::size_of::<T>())
+            .prop_filter_map("bytes are not a valid instance of `T`", |bytes| {
+                try_new(fill_once(&bytes)).map(Arbitrary)
+            })
+    }
+}
+
+#[cfg(feature = "proptest")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "proptest")))]
+impl<T> ::proptest::arbitrary::Arbitrary for ArbitraryBox<T>
+where
+    T: ?Sized + TryFromBytes + KnownLayout<PointerMetadata = usize> + Debug + 'static,
+{
+    type Parameters = ::proptest::collection::SizeRange;
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+    #[inline]
+    fn arbitrary_with(elems: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy as _;
+
+        (elems.start()..=elems.end_incl())
+            .prop_flat_map(|elems| {
+                // If the size overflows, generate no bytes; `try_new_box` will
+                // fail and the case will be rejected below.
+                let size = elems.size_for_metadata(T::LAYOUT).unwrap_or(0);
+                (
+                    ::proptest::strategy::Just(elems),
+                    ::proptest::collection::vec(::proptest::num::u8::ANY, size),
+                )
+            })
+            .prop_filter_map("bytes are not a valid instance of `T`", |(elems, bytes)| {
+                try_new_box(elems, fill_once(&bytes)).map(ArbitraryBox)
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_new() {
+        let mut calls = 0;
+        let b = try_new::<bool>(|bytes| {
+            calls += 1;
+            bytes[0] = if calls < 3 { 2 } else { 1 };
+            true
+        });
+        assert_eq!(b, Some(true));
+        assert_eq!(calls, 3);
+
+        assert_eq!(try_new::<bool>(|_| false), None);
+        assert_eq!(try_new::<()>(|_| true), Some(()));
+    }
+
+    #[test]
+    fn test_try_new_box() {
+        let mut calls = 0;
+        let b = try_new_box::<[bool]>(3, |bytes| {
+            calls += 1;
+            bytes.copy_from_slice(if calls < 2 { &[1, 2, 0] } else { &[1, 1, 0] });
+            true
+        });
+        assert_eq!(b.as_deref(), Some(&[true, true, false][..]));
+        assert_eq!(calls, 2);
+
+        assert_eq!(try_new_box::<[bool]>(3, |_| false), None);
+        assert_eq!(try_new_box::<[bool]>(0, |_| true).as_deref(), Some(&[][..]));
+        assert_eq!(try_new_box::<[()]>(5, |_| true).as_deref(), Some(&[(); 5][..]));
+        assert_eq!(try_new_box::<[u64]>(usize::MAX, |_| true), None);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use ::arbitrary::{Arbitrary as _, Error, Unstructured};
+
+        let mut u = Unstructured::new(&[0x34, 0x12, 0xFF]);
+        assert_eq!(Arbitrary::<u16>::arbitrary(&mut u).unwrap().into_inner(), 0x1234u16.to_le());
+        assert_eq!(u.len(), 1);
+
+        // Invalid byte patterns are skipped.
+        let mut u = Unstructured::new(&[2, 3, 1]);
+        assert_eq!(Arbitrary::<bool>::arbitrary(&mut u).unwrap(), Arbitrary(true));
+        assert!(u.is_empty());
+
+        // Once the input is exhausted, generation fails.
+        let mut u = Unstructured::new(&[2, 3]);
+        assert!(matches!(Arbitrary::<bool>::arbitrary(&mut u), Err(Error::IncorrectFormat)));
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(Arbitrary::<bool>::arbitrary(&mut u).unwrap(), Arbitrary(false));
+
+        // Give up after `MAX_ATTEMPTS` invalid byte patterns.
+        let mut input = vec![2u8; MAX_ATTEMPTS];
+        input.push(1);
+        let mut u = Unstructured::new(&input);
+        assert!(matches!(Arbitrary::<bool>::arbitrary(&mut u), Err(Error::IncorrectFormat)));
+        assert_eq!(u.len(), 1);
+
+        let mut u = Unstructured::new(&[3, 1, 0, 1, 0xFF]);
+        let b = ArbitraryBox::<[bool]>::arbitrary(&mut u).unwrap();
+        assert_eq!(*b, [true, false, true][..]);
+
+        let mut u = Unstructured::new(&[]);
+        assert!(ArbitraryBox::<[u32]>::arbitrary(&mut u).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "proptest")]
+    mod proptest {
+        use ::proptest::{
+            arbitrary::{any, any_with},
+            prop_assert, prop_assert_ne, proptest,
+        };
+
+        use super::*;
+
+        proptest! {
+            #[test]
+            fn test_arbitrary(b in any::<Arbitrary<bool>>(), n in any::<Arbitrary<NonZeroU32>>()) {
+                prop_assert!(b.as_bytes()[0] < 2);
+                prop_assert_ne!(n.get(), 0);
+            }
+
+            #[test]
+            fn test_arbitrary_box(
+                s in any::<ArbitraryBox<[NonZeroU16]>>(),
+                b in any_with::<ArbitraryBox<[bool]>>((0..=1).into()),
+            ) {
+                prop_assert!(s.len() < 100);
+                prop_assert!(s.iter().all(|n| n.get() != 0));
+                prop_assert!(b.len() <= 1);
+                prop_assert!(b.as_bytes().iter().all(|b| *b < 2));
+            }
+        }
+    }
+}
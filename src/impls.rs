@@ -920,9 +920,20 @@ mod simd {
             powerpc64, powerpc64, vector_bool_long, vector_double, vector_signed_long, vector_unsigned_long
         );
         simd_arch_mod!(
-            // NOTE(https://github.com/rust-lang/stdarch/issues/1484): NEON intrinsics are currently
-            // broken on big-endian platforms.
-            #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+            // NOTE(https://github.com/rust-lang/stdarch/issues/1484): NEON
+            // *intrinsics* are currently broken on big-endian platforms: some
+            // of them number lanes in the opposite order from the one
+            // documented by Arm. That is a property of the intrinsics, not of
+            // the types. These impls only make claims about the in-memory
+            // representation of each vector type, which - per the module
+            // comment above - is that of the equivalent array (or, for the
+            // `xN` tuple types, of a struct of such vectors) on every target,
+            // regardless of endianness. Reading a vector out of a byte buffer
+            // and writing it back is thus a faithful round-trip on both
+            // little- and big-endian AArch64; only the mapping from byte
+            // offset to intrinsic lane index is endian-dependent, exactly as
+            // it is for `[u32; 4]` and `u32::from_ne_bytes`.
+            #[cfg(target_arch = "aarch64")]
             aarch64, aarch64, float32x2_t, float32x4_t, float64x1_t, float64x2_t, int8x8_t, int8x8x2_t,
             int8x8x3_t, int8x8x4_t, int8x16_t, int8x16x2_t, int8x16x3_t, int8x16x4_t, int16x4_t,
             int16x8_t, int32x2_t, int32x4_t, int64x1_t, int64x2_t, poly8x8_t, poly8x8x2_t, poly8x8x3_t,
@@ -935,7 +946,205 @@ mod simd {
             #[cfg(all(feature = "simd-nightly", target_arch = "arm"))]
             arm, arm, int8x4_t, uint8x4_t
         );
+        simd_arch_mod!(
+            #[cfg(all(feature = "simd-nightly", target_arch = "loongarch64"))]
+            loongarch64, loongarch64, v16i8, v8i16, v4i32, v2i64, v16u8, v8u16, v4u32, v2u64, v4f32,
+            v2f64, v32i8, v16i16, v8i32, v4i64, v32u8, v16u16, v8u32, v4u64, v8f32, v4f64
+        );
+        // NOTE: As of this writing, `core::arch::riscv32` and
+        // `core::arch::riscv64` do not expose any vector types (the "V"
+        // extension intrinsics operate on scalars). Once they do, they should
+        // be added here.
     };
+
+    // Per the documentation of `core::simd::Simd` [1]:
+    //
+    //   `Simd<T, N>` has a layout similar to `[T; N]` (identical "shapes"),
+    //   with a greater alignment. `[T; N]` is aligned to `T`, but `Simd<T, N>`
+    //   will have an alignment based on both `T` and `N`. Thus it is sound to
+    //   transmute `Simd<T, N>` to `[T; N]`...
+    //
+    // `Simd<T, N>` thus has the same size and bit validity as `[T; N]`, and
+    // contains `UnsafeCell`s at the same byte ranges. Since its alignment may
+    // be greater than `T`'s, it is never `Unaligned`.
+    //
+    // `core::simd::Mask` is supported separately below.
+    //
+    // [1] https://doc.rust-lang.org/nightly/core/simd/struct.Simd.html#layout
+    #[cfg(feature = "simd-nightly")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "simd-nightly")))]
+    mod portable {
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+        use core::simd::{Mask, MaskElement};
+        use core::{
+            ptr::NonNull,
+            simd::{LaneCount, Simd, SimdElement, SupportedLaneCount},
+        };
+
+        use crate::*;
+
+        // NOTE: `unsafe_impl!` and `impl_known_layout!` do not support `where`
+        // clauses, which are required to express the `SupportedLaneCount`
+        // bound, so these impls are written out by hand.
+
+        // SAFETY: Delegates safety to `DstLayout::for_type`.
+        unsafe impl<T: SimdElement, const N: usize> KnownLayout for Simd<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            #[allow(clippy::missing_inline_in_public_items)]
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn only_derive_is_allowed_to_implement_this_trait() {}
+
+            type PointerMetadata = ();
+
+            const LAYOUT: DstLayout = DstLayout::for_type::<Simd<T, N>>();
+
+            // SAFETY: `.cast` preserves address and provenance.
+            #[inline(always)]
+            fn raw_from_ptr_len(bytes: NonNull<u8>, _meta: ()) -> NonNull<Self> {
+                bytes.cast::<Self>()
+            }
+
+            #[inline(always)]
+            fn pointer_to_metadata(_ptr: *mut Self) {}
+        }
+
+        // SAFETY: See comment above module definition for justification.
+        unsafe impl<T: SimdElement + Immutable, const N: usize> Immutable for Simd<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method Immutable);
+        }
+
+        // SAFETY: See comment above module definition for justification.
+        unsafe impl<T: SimdElement + TryFromBytes, const N: usize> TryFromBytes for Simd<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method TryFromBytes; |c: Maybe<[T; N]>| {
+                <[T; N] as TryFromBytes>::is_bit_valid(c)
+            });
+        }
+
+        // SAFETY: See comment above module definition for justification.
+        unsafe impl<T: SimdElement + FromZeros, const N: usize> FromZeros for Simd<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method FromZeros);
+        }
+
+        // SAFETY: See comment above module definition for justification.
+        unsafe impl<T: SimdElement + FromBytes, const N: usize> FromBytes for Simd<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method FromBytes);
+        }
+
+        // SAFETY: See comment above module definition for justification.
+        unsafe impl<T: SimdElement + IntoBytes, const N: usize> IntoBytes for Simd<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method IntoBytes);
+        }
+
+        // The documentation of `core::simd::Mask` states that its layout "is
+        // unspecified, and may change between platforms and/or Rust versions,
+        // and code should not assume that it is equivalent to `[T; N]`" [1]. On
+        // x86_64 with AVX-512, it is represented as a bitmask. On every other
+        // target, it is a `repr(transparent)` wrapper around a `Simd<T, N>`,
+        // each of whose lanes is either all zeros (`false`) or all ones
+        // (`true`) [2], and so it has the same size as `[T; N]`, contains no
+        // `UnsafeCell`s, and has no padding. These impls rely on that
+        // representation, and so they are not emitted when the bitmask
+        // representation is used.
+        //
+        // TODO: Remove these impls if `Mask`'s representation changes, or
+        // document them as guaranteed if it is made part of `Mask`'s API.
+        //
+        // [1] https://doc.rust-lang.org/nightly/core/simd/struct.Mask.html#layout
+        // [2] https://github.com/rust-lang/portable-simd/blob/master/crates/core_simd/src/masks/full_masks.rs
+
+        // SAFETY: Delegates safety to `DstLayout::for_type`.
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+        unsafe impl<T: MaskElement, const N: usize> KnownLayout for Mask<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            #[allow(clippy::missing_inline_in_public_items)]
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            fn only_derive_is_allowed_to_implement_this_trait() {}
+
+            type PointerMetadata = ();
+
+            const LAYOUT: DstLayout = DstLayout::for_type::<Mask<T, N>>();
+
+            // SAFETY: `.cast` preserves address and provenance.
+            #[inline(always)]
+            fn raw_from_ptr_len(bytes: NonNull<u8>, _meta: ()) -> NonNull<Self> {
+                bytes.cast::<Self>()
+            }
+
+            #[inline(always)]
+            fn pointer_to_metadata(_ptr: *mut Self) {}
+        }
+
+        // SAFETY: See comment above `Mask`'s `KnownLayout` impl for
+        // justification.
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+        unsafe impl<T: MaskElement, const N: usize> Immutable for Mask<T, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method Immutable);
+        }
+
+        // SAFETY: See comment above `Mask`'s `KnownLayout` impl for
+        // justification. `Mask<T, N>` has the same size as `[T; N]`, and `T` is
+        // an integer type, so every initialized `[T; N]` can be read. A mask
+        // is valid if and only if each of its lanes is all zeros or all ones.
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+        unsafe impl<T, const N: usize> TryFromBytes for Mask<T, N>
+        where
+            T: MaskElement + FromBytes + IntoBytes + Immutable,
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method TryFromBytes; |c: MaybeAligned<[T; N]>| {
+                c.read_unaligned::<BecauseImmutable>().iter().all(|lane| {
+                    let bytes = lane.as_bytes();
+                    bytes.iter().all(|b| *b == 0) || bytes.iter().all(|b| *b == 0xFF)
+                })
+            });
+        }
+
+        // SAFETY: See comment above `Mask`'s `KnownLayout` impl for
+        // justification. The all-zeros mask is the mask whose lanes are all
+        // `false`.
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+        unsafe impl<T, const N: usize> FromZeros for Mask<T, N>
+        where
+            T: MaskElement + FromBytes + IntoBytes + Immutable,
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method FromZeros);
+        }
+
+        // SAFETY: See comment above `Mask`'s `KnownLayout` impl for
+        // justification. Each lane of a mask is an initialized integer, and a
+        // mask has no padding.
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+        unsafe impl<T, const N: usize> IntoBytes for Mask<T, N>
+        where
+            T: MaskElement + IntoBytes,
+            LaneCount<N>: SupportedLaneCount,
+        {
+            unsafe_impl!(@method IntoBytes);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1107,6 +1316,19 @@ mod tests {
                    @failure [0x01; mem::size_of::<*mut NotZerocopy>()];
         );
 
+        #[cfg(all(
+            feature = "simd-nightly",
+            not(all(target_arch = "x86_64", target_feature = "avx512f"))
+        ))]
+        impl_try_from_bytes_testable!(
+            core::simd::Mask<i8, 4>
+                => @success Self::from_array([false; 4]), Self::from_array([true, false, true, true]),
+                   @failure [1u8, 0, 0, 0], [0u8, 0, 0, 0x7F];
+            core::simd::Mask<i32, 2>
+                => @success Self::from_array([false, true]), Self::from_array([true, true]),
+                   @failure [1u32, 0], [0u32, 0xFFFF_FF00];
+        );
+
         // Use the trick described in [1] to allow us to call methods
         // conditional on certain trait bounds.
         //
@@ -1876,6 +2098,32 @@ mod tests {
             #[cfg(all(feature = "simd-nightly", target_arch = "arm"))]
             #[rustfmt::skip]
             test_simd_arch_mod!(arm, int8x4_t, uint8x4_t);
+
+            #[cfg(all(feature = "simd-nightly", target_arch = "loongarch64"))]
+            #[rustfmt::skip]
+            test_simd_arch_mod!(
+                loongarch64, v16i8, v8i16, v4i32, v2i64, v16u8, v8u16, v4u32, v2u64, v4f32, v2f64,
+                v32i8, v16i16, v8i32, v4i64, v32u8, v16u16, v8u32, v4u64, v8f32, v4f64
+            );
+
+            #[cfg(feature = "simd-nightly")]
+            {
+                use core::simd::Simd;
+                assert_impls!(Simd<u8, 16>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, !Unaligned);
+                assert_impls!(Simd<i32, 4>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, !Unaligned);
+                assert_impls!(Simd<f64, 8>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, !Unaligned);
+                assert_impls!(Simd<usize, 64>: KnownLayout, Immutable, TryFromBytes, FromZeros, FromBytes, IntoBytes, !Unaligned);
+            }
+
+            #[cfg(all(
+                feature = "simd-nightly",
+                not(all(target_arch = "x86_64", target_feature = "avx512f"))
+            ))]
+            {
+                use core::simd::Mask;
+                assert_impls!(Mask<i8, 4>: KnownLayout, Immutable, TryFromBytes, FromZeros, IntoBytes, !FromBytes, !Unaligned);
+                assert_impls!(Mask<i32, 2>: KnownLayout, Immutable, TryFromBytes, FromZeros, IntoBytes, !FromBytes, !Unaligned);
+            }
         }
     }
 }
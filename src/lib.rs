@@ -66,7 +66,7 @@
 //!
 //! ##### Conversion Macros
 //!
-//! Zerocopy provides six macros for safe casting between types:
+//! Zerocopy provides ten macros for safe casting between types:
 //!
 //! - ([`try_`][try_transmute])[`transmute`] (conditionally) converts a value of
 //!   one type to a value of another type of the same size
@@ -76,6 +76,13 @@
 //! - ([`try_`][try_transmute_ref])[`transmute_ref`] (conditionally) converts a
 //!   mutable or immutable reference of one type to an immutable reference of
 //!   another type of the same size
+//! - ([`try_`][try_transmute_slice_mut])[`transmute_slice_mut`] (conditionally)
+//!   converts a mutable reference to a slice of one type to a mutable reference
+//!   to a slice of another type whose size evenly divides it
+//! - ([`try_`][try_transmute_slice])[`transmute_slice`] (conditionally)
+//!   converts a mutable or immutable reference to a slice of one type to an
+//!   immutable reference to a slice of another type whose size evenly divides
+//!   it
 //!
 //! These macros perform *compile-time* size and alignment checks, meaning that
 //! unconditional casts have zero cost at runtime. Conditional casts do not need
@@ -134,9 +141,32 @@
 //!
 //! - **`simd-nightly`**
 //!   Enables the `simd` feature and adds support for SIMD types which are only
-//!   available on nightly. Since these types are unstable, support for any type
-//!   may be removed at any point in the future.
+//!   available on nightly, including the portable `core::simd::Simd`. Since
+//!   these types are unstable, support for any type may be removed at any point
+//!   in the future. `core::simd::Mask` supports every trait but `FromBytes`
+//!   and `Unaligned`, except on x86_64 with AVX-512 enabled, where it is
+//!   represented as a bitmask.
 //!
+//! - **`testing`**
+//!   Adds the `testing` module, which provides utilities for checking
+//!   `TryFromBytes` and `IntoBytes` impls from unit tests. `testing` implies
+//!   `alloc`.
+//!
+//! - **`arbitrary`**, **`proptest`**
+//!   Implement the [`arbitrary`][arbitrary] and [`proptest`][proptest] crates'
+//!   `Arbitrary` traits for the `Arbitrary` and `ArbitraryBox` wrappers, which
+//!   generate values of any `TryFromBytes` type directly from fuzzer- or
+//!   property-test-provided bytes. Both features imply `alloc`.
+//!
+//! - **`serde`**
+//!   Implements [`serde`][serde]'s `Serialize` and `Deserialize` traits for the
+//!   byte order-aware numeric types (as their native numeric value) and for
+//!   `Unalign<T>` (as `T`). The `byteorder::byte_array` module allows fields to
+//!   be serialized as their raw bytes instead.
+//!
+//! [arbitrary]: https://docs.rs/arbitrary
+//! [proptest]: https://docs.rs/proptest
+//! [serde]: https://docs.rs/serde
 //! [duplicate-import-errors]: https://github.com/google/zerocopy/issues/1587
 //! [simd-layout]: https://rust-lang.github.io/unsafe-code-guidelines/layout/packed-simd-vectors.html
 //!
@@ -296,6 +326,7 @@
     all(feature = "simd-nightly", any(target_arch = "x86", target_arch = "x86_64")),
     feature(stdarch_x86_avx512)
 )]
+#![cfg_attr(feature = "simd-nightly", feature(portable_simd))]
 #![cfg_attr(
     all(feature = "simd-nightly", target_arch = "arm"),
     feature(stdarch_arm_dsp, stdarch_arm_neon_intrinsics)
@@ -304,6 +335,7 @@
     all(feature = "simd-nightly", any(target_arch = "powerpc", target_arch = "powerpc64")),
     feature(stdarch_powerpc)
 )]
+#![cfg_attr(all(feature = "simd-nightly", target_arch = "loongarch64"), feature(stdarch_loongarch))]
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
 #![cfg_attr(
     __ZEROCOPY_INTERNAL_USE_ONLY_NIGHTLY_FEATURES_IN_TESTS,
@@ -322,30 +354,43 @@ pub mod util;
 
 pub mod byte_slice;
 pub mod byteorder;
+#[cfg(any(feature = "alloc", test))]
+mod c_header;
 // This module is `pub` so that zerocopy's error types and error handling
 // documentation is grouped together in a cohesive module. In practice, we
 // expect most users to use the re-export of `error`'s items to avoid identifier
 // stuttering.
 pub mod error;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod fuzz;
 mod impls;
 #[doc(hidden)]
 pub mod layout;
 mod macros;
-#[doc(hidden)]
 pub mod pointer;
 mod r#ref;
+#[cfg(feature = "testing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "testing")))]
+pub mod testing;
+mod type_layout;
 // TODO(#252): If we make this pub, come up with a better name.
 mod wrappers;
 
 pub use crate::byte_slice::*;
 pub use crate::byteorder::*;
+#[cfg(any(feature = "alloc", test))]
+pub use crate::c_header::*;
 pub use crate::error::*;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub use crate::fuzz::*;
 pub use crate::r#ref::*;
+pub use crate::type_layout::*;
 pub use crate::wrappers::*;
 
 use core::{
     cell::UnsafeCell,
     cmp::Ordering,
+    convert::Infallible,
     fmt::{self, Debug, Display, Formatter},
     hash::Hasher,
     marker::PhantomData,
@@ -363,6 +408,10 @@ use crate::pointer::invariant::{self, BecauseExclusive};
 
 #[cfg(any(feature = "alloc", test))]
 extern crate alloc;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
 #[cfg(any(feature = "alloc", test))]
 use alloc::{boxed::Box, vec::Vec};
 
@@ -371,7 +420,9 @@ use core::alloc::Layout;
 
 // Used by `TryFromBytes::is_bit_valid`.
 #[doc(hidden)]
-pub use crate::pointer::{invariant::BecauseImmutable, Maybe, MaybeAligned, Ptr};
+pub use crate::pointer::{invariant::BecauseImmutable, MaybeAligned};
+// Used by hand-written `TryFromBytes` impls; see `unsafe_impl_items!`.
+pub use crate::pointer::{Maybe, Ptr};
 // Used by `KnownLayout`.
 #[doc(hidden)]
 pub use crate::layout::*;
@@ -426,6 +477,39 @@ const _: () = {
 /// }
 /// ```
 ///
+/// # Length-Linked Trailing Slices
+///
+/// Many formats store the number of elements in a trailing array in a header
+/// field. Annotating the trailing slice field of a struct with `#[zerocopy(len
+/// = ...)]` links it to such a field, which may be named by a path of fields
+/// (e.g., `header.count`) and must be an unsigned integer, either native or
+/// [byte-order-aware](byteorder). The derive then generates
+/// `ref_from_prefix_linked` and `try_ref_from_prefix_linked` methods, which
+/// read the count from the prefix of their source and use it as the element
+/// count of the trailing slice. A count which does not fit in a `usize` is
+/// reported as a size error. These methods can only be called if the struct
+/// implements [`FromBytes`] (or [`TryFromBytes`]) and [`Immutable`]. Deriving
+/// other traits on a struct with a `#[zerocopy(len = ...)]` attribute without
+/// also deriving `KnownLayout` is an error.
+///
+/// ```
+/// # use zerocopy::byteorder::{BigEndian, U16};
+/// # use zerocopy_derive::*;
+/// #[derive(FromBytes, KnownLayout, Immutable, Unaligned)]
+/// #[repr(C)]
+/// struct Packet {
+///     kind: u8,
+///     count: U16<BigEndian>,
+///     #[zerocopy(len = count)]
+///     body: [u8],
+/// }
+///
+/// let bytes = &[7, 0, 2, 0xAA, 0xBB, 0xCC][..];
+/// let (packet, rest) = Packet::ref_from_prefix_linked(bytes).unwrap();
+/// assert_eq!(&packet.body, &[0xAA, 0xBB][..]);
+/// assert_eq!(rest, &[0xCC][..]);
+/// ```
+///
 /// # Limitations
 ///
 /// This derive cannot currently be applied to unsized structs without an
@@ -497,6 +581,57 @@ const _: () = {
 #[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
 pub use zerocopy_derive::KnownLayout;
 
+/// Generates constants describing the offset and size of each field of a
+/// `repr(C)` struct.
+///
+/// For each field, this derive generates an associated `<FIELD>_OFFSET`
+/// constant holding the field's offset in bytes, and an associated
+/// `<FIELD>_SIZE` constant holding its size in bytes, where `<FIELD>` is the
+/// field's name in upper case (or, for tuple structs, its index prefixed by an
+/// underscore). These constants have the same visibility as their fields. It
+/// also generates a `FIELD_OFFSETS` constant which holds the `(name, offset,
+/// size)` of every field in declaration order:
+///
+/// ```
+/// # use zerocopy_derive::*;
+/// #[derive(FieldOffsets)]
+/// #[repr(C)]
+/// struct UdpHeader {
+///     src_port: [u8; 2],
+///     dst_port: [u8; 2],
+///     length: [u8; 2],
+///     checksum: [u8; 2],
+/// }
+///
+/// assert_eq!(UdpHeader::CHECKSUM_OFFSET, 6);
+/// assert_eq!(UdpHeader::CHECKSUM_SIZE, 2);
+/// assert_eq!(
+///     UdpHeader::FIELD_OFFSETS,
+///     [("src_port", 0, 2), ("dst_port", 2, 2), ("length", 4, 2), ("checksum", 6, 2)],
+/// );
+/// ```
+///
+/// Unlike `core::mem::offset_of!`, these constants can be used in generic
+/// code and in structs whose trailing field is dynamically sized. The size of
+/// a dynamically-sized trailing field is its size when its trailing slice is
+/// empty.
+///
+/// Offsets are computed from the struct's `repr(C)` layout, taking
+/// `repr(align(N))` and `repr(packed(N))` into account, in the same way as
+/// [`KnownLayout`]. The struct's trailing field must implement `KnownLayout`.
+#[cfg(any(feature = "derive", test))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use zerocopy_derive::FieldOffsets;
+
+/// Implements [`DescribeLayout`] for a `repr(C)` struct.
+///
+/// Each field's type must implement `DescribeLayout`, and the struct must
+/// implement [`KnownLayout`]. Field offsets are computed in the same way as
+/// for [`FieldOffsets`]. See [`DescribeLayout`] for an example.
+#[cfg(any(feature = "derive", test))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use zerocopy_derive::DescribeLayout;
+
 /// Indicates that zerocopy can reason about certain aspects of a type's layout.
 ///
 /// This trait is required by many of zerocopy's APIs. It supports sized types,
@@ -533,7 +668,9 @@ pub use zerocopy_derive::KnownLayout;
 /// ```
 ///
 /// This derive performs a sophisticated analysis to deduce the layout
-/// characteristics of types. You **must** implement this trait via the derive.
+/// characteristics of types. You **must** implement this trait via the derive;
+/// unlike zerocopy's other traits, it cannot be implemented by hand using
+/// [`unsafe_impl_items!`].
 ///
 /// # Dynamically-sized types
 ///
@@ -1107,6 +1244,10 @@ pub use zerocopy_derive::Immutable;
 /// This derive performs a sophisticated, compile-time safety analysis to
 /// determine whether a type is `Immutable`.
 ///
+/// Where the derive cannot be used, `Immutable` may instead be implemented by
+/// hand using [`unsafe_impl_items!`]. Such an implementation must uphold the
+/// requirements described in the "Safety" section below.
+///
 /// # Safety
 ///
 /// Unsafe code outside of this crate must not make any assumptions about `T`
@@ -1115,6 +1256,14 @@ pub use zerocopy_derive::Immutable;
 /// assumptions based on `T: Immutable`, future relaxations may cause that code
 /// to become unsound.
 ///
+/// A type may only implement `Immutable` if, given `t: &T`, `t` does not
+/// contain any [`UnsafeCell`]s at any byte location within the byte range
+/// addressed by `t`. This includes ranges of length 0 (e.g., `UnsafeCell<()>`
+/// and `[UnsafeCell<u8>; 0]`). If a type implements `Immutable` which violates
+/// this requirement, it may cause undefined behavior.
+///
+/// [`UnsafeCell`]: core::cell::UnsafeCell
+///
 // # Safety (Internal)
 //
 // If `T: Immutable`, unsafe code *inside of this crate* may assume that, given
@@ -1141,9 +1290,14 @@ pub use zerocopy_derive::Immutable;
     diagnostic::on_unimplemented(note = "Consider adding `#[derive(Immutable)]` to `{Self}`")
 )]
 pub unsafe trait Immutable {
+    /// Marks an implementation of this trait as upholding its safety
+    /// requirements.
+    ///
+    /// This method is provided by zerocopy's derives and by
+    /// [`unsafe_impl_items!`]; it should not otherwise be implemented, and is
+    /// never called.
     // The `Self: Sized` bound makes it so that `Immutable` is still object
     // safe.
-    #[doc(hidden)]
     fn only_derive_is_allowed_to_implement_this_trait()
     where
         Self: Sized;
@@ -1182,6 +1336,83 @@ pub unsafe trait Immutable {
 /// }
 /// ```
 ///
+/// # Byte-Order-Aware Discriminants
+///
+/// A field-less enum's discriminant is stored as a native-endian integer. To
+/// parse an enum whose discriminant is stored with a particular byte order, add
+/// a `#[zerocopy(discriminant = ...)]` attribute naming a [byte-order-aware
+/// integer type](byteorder) which corresponds to the enum's `repr`. The derive
+/// then also generates an unaligned "wire" type, named by appending `Wire` to
+/// the name of the enum, which holds the discriminant in that byte order:
+///
+/// ```
+/// # use zerocopy::{byteorder::{BigEndian, U16}, IntoBytes, TryFromBytes};
+/// # use zerocopy_derive::*;
+/// #[derive(TryFromBytes, KnownLayout, Immutable, Debug, PartialEq)]
+/// #[zerocopy(discriminant = U16<BigEndian>)]
+/// #[repr(u16)]
+/// enum MessageType {
+///     Hello = 0x0102,
+///     Goodbye = 0x0304,
+/// }
+///
+/// let wire = MessageTypeWire::try_read_from_bytes(&[0x01, 0x02][..]).unwrap();
+/// assert_eq!(wire.get(), MessageType::Hello);
+///
+/// // Values other than the enum's discriminants are rejected.
+/// assert!(MessageTypeWire::try_read_from_bytes(&[0x02, 0x01][..]).is_err());
+///
+/// let wire = MessageTypeWire::new(MessageType::Goodbye);
+/// assert_eq!(wire.as_bytes(), [0x03, 0x04]);
+/// ```
+///
+/// The wire type implements [`KnownLayout`], [`Immutable`], `TryFromBytes`,
+/// [`IntoBytes`], and [`Unaligned`], and can be converted to and from the enum
+/// via its `new` and `get` methods or via `From`. A wire value can only be
+/// obtained from one of the enum's discriminants, even by code in the enum's
+/// module, so `get` never fails.
+///
+/// Deriving any other trait on an enum with a `#[zerocopy(discriminant = ...)]`
+/// attribute without also deriving `TryFromBytes` is an error.
+///
+/// # Constant Fields
+///
+/// Formats often require that some fields hold particular values, such as a
+/// magic number or a version. A struct field annotated with `#[zerocopy(eq =
+/// ...)]` is only valid if it holds the given constant, which may be a value of
+/// the field's type, a byte string literal (for byte array fields), or a native
+/// integer or float (for fields of [byte-order-aware types](byteorder)). Such a
+/// field's type must implement `Copy`, [`Immutable`], and `PartialEq`.
+///
+/// If a conversion fails because a field does not hold its constant,
+/// [`ValidityError::mismatched_constant`] names that field. The derive also
+/// generates a `new_zeroed_with_constants` constructor, which sets each such
+/// field to its constant and every other field to zero. It can only be called
+/// if the other fields' types implement [`FromZeros`] and the struct is
+/// [`Sized`], and it is no more visible than the struct or any of its fields.
+///
+/// ```
+/// # use zerocopy::{byteorder::{LittleEndian, U16}, IntoBytes, TryFromBytes};
+/// # use zerocopy_derive::*;
+/// #[derive(TryFromBytes, KnownLayout, Immutable, IntoBytes)]
+/// #[repr(C)]
+/// struct FileHeader {
+///     #[zerocopy(eq = b"\x89PNG")]
+///     magic: [u8; 4],
+///     #[zerocopy(eq = 2)]
+///     version: U16<LittleEndian>,
+///     flags: U16<LittleEndian>,
+/// }
+///
+/// let header = FileHeader::new_zeroed_with_constants();
+/// assert_eq!(header.as_bytes(), b"\x89PNG\x02\x00\x00\x00");
+/// assert!(FileHeader::try_read_from_bytes(header.as_bytes()).is_ok());
+/// assert!(FileHeader::try_read_from_bytes(&b"\x89PNG\x03\x00\x00\x00"[..]).is_err());
+/// ```
+///
+/// Constant fields are not supported when deriving [`FromZeros`] or
+/// [`FromBytes`], nor in enums or unions.
+///
 /// [safety conditions]: trait@TryFromBytes#safety
 #[cfg(any(feature = "derive", test))]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
@@ -1229,7 +1460,9 @@ pub use zerocopy_derive::TryFromBytes;
 /// ```
 ///
 /// This derive ensures that the runtime check of whether bytes correspond to a
-/// valid instance is sound. You **must** implement this trait via the derive.
+/// valid instance is sound. You **must** implement this trait via the derive,
+/// unless the derive cannot be used, in which case it may be implemented by
+/// hand using [`unsafe_impl_items!`] and [`project!`].
 ///
 /// # What is a "valid instance"?
 ///
@@ -1272,6 +1505,11 @@ pub use zerocopy_derive::TryFromBytes;
 /// or representation of `T`. It merely provides the ability to perform a
 /// validity check at runtime via methods like [`try_ref_from_bytes`].
 ///
+/// A hand-written implementation of `TryFromBytes` (see [`unsafe_impl_items!`])
+/// must ensure that [`is_bit_valid`] only returns `true` if its argument
+/// references a bit-valid `Self`. It must not assume that its argument is
+/// aligned.
+///
 /// You must not rely on the `#[doc(hidden)]` internals of `TryFromBytes`.
 /// Future releases of zerocopy may make backwards-breaking changes to these
 /// items, including changes that only affect soundness, which may cause code
@@ -1280,6 +1518,7 @@ pub use zerocopy_derive::TryFromBytes;
 /// [undefined behavior]: https://raphlinus.github.io/programming/rust/2018/08/17/undefined-behavior.html
 /// [github-repo]: https://github.com/google/zerocopy
 /// [`try_ref_from_bytes`]: TryFromBytes::try_ref_from_bytes
+/// [`is_bit_valid`]: TryFromBytes::is_bit_valid
 /// [*valid instance*]: #what-is-a-valid-instance
 #[cfg_attr(feature = "derive", doc = "[derive]: zerocopy_derive::TryFromBytes")]
 #[cfg_attr(
@@ -1291,9 +1530,14 @@ pub use zerocopy_derive::TryFromBytes;
     diagnostic::on_unimplemented(note = "Consider adding `#[derive(TryFromBytes)]` to `{Self}`")
 )]
 pub unsafe trait TryFromBytes {
+    /// Marks an implementation of this trait as upholding its safety
+    /// requirements.
+    ///
+    /// This method is provided by zerocopy's derives and by
+    /// [`unsafe_impl_items!`]; it should not otherwise be implemented, and is
+    /// never called.
     // The `Self: Sized` bound makes it so that `TryFromBytes` is still object
     // safe.
-    #[doc(hidden)]
     fn only_derive_is_allowed_to_implement_this_trait()
     where
         Self: Sized;
@@ -1303,7 +1547,8 @@ pub unsafe trait TryFromBytes {
     /// # Safety
     ///
     /// Unsafe code may assume that, if `is_bit_valid(candidate)` returns true,
-    /// `*candidate` contains a valid `Self`.
+    /// `*candidate` contains a valid `Self`. Implementations must uphold this;
+    /// hand-written implementations are provided via [`unsafe_impl_items!`].
     ///
     /// # Panics
     ///
@@ -1319,9 +1564,24 @@ pub unsafe trait TryFromBytes {
     ///
     /// [`UnsafeCell`]: core::cell::UnsafeCell
     /// [`Shared`]: invariant::Shared
-    #[doc(hidden)]
     fn is_bit_valid<A: invariant::Reference>(candidate: Maybe<'_, Self, A>) -> bool;
 
+    /// If `candidate` is not a valid `Self` because one of its fields does not
+    /// hold the constant required by `#[zerocopy(eq = ...)]`, returns the name
+    /// of that field.
+    ///
+    /// This is only called after `is_bit_valid(candidate)` has returned
+    /// `false`, and is only used to produce [`ValidityError`]s.
+    #[doc(hidden)]
+    #[must_use]
+    #[inline(always)]
+    fn mismatched_constant<A: invariant::Reference>(
+        candidate: Maybe<'_, Self, A>,
+    ) -> Option<&'static str> {
+        let _ = candidate;
+        None
+    }
+
     /// Attempts to interpret the given `source` as a `&Self`.
     ///
     /// If the bytes of `source` are a valid instance of `Self`, this method
@@ -2527,7 +2787,7 @@ pub unsafe trait TryFromBytes {
         };
         // SAFETY: `candidate` was copied from from `source: &[u8]`, so all of
         // its bytes are initialized.
-        unsafe { try_read_from(source, candidate) }
+        unsafe { try_read_from(source, candidate).map_err(Into::into) }
     }
 
     /// Attempts to read a `Self` from the prefix of the given `source`.
@@ -2588,7 +2848,7 @@ pub unsafe trait TryFromBytes {
         };
         // SAFETY: `candidate` was copied from from `source: &[u8]`, so all of
         // its bytes are initialized.
-        unsafe { try_read_from(source, candidate).map(|slf| (slf, suffix)) }
+        unsafe { try_read_from(source, candidate).map(|slf| (slf, suffix)).map_err(Into::into) }
     }
 
     /// Attempts to read a `Self` from the suffix of the given `source`.
@@ -2650,7 +2910,266 @@ pub unsafe trait TryFromBytes {
         };
         // SAFETY: `candidate` was copied from from `source: &[u8]`, so all of
         // its bytes are initialized.
-        unsafe { try_read_from(source, candidate).map(|slf| (prefix, slf)) }
+        unsafe { try_read_from(source, candidate).map(|slf| (prefix, slf)).map_err(Into::into) }
+    }
+
+    /// Attempts to interpret the given `source` as a `&Self`.
+    ///
+    /// Unlike [`try_ref_from_bytes`], this method checks at compile time that
+    /// `N` is equal to `size_of::<Self>()`. If `source` is not appropriately
+    /// aligned, or if the bytes are not a valid instance of `Self`, this
+    /// returns `Err`. If [`Self: Unaligned`][self-unaligned], you can
+    /// [infallibly discard the alignment error][ConvertError::from].
+    ///
+    /// [`try_ref_from_bytes`]: TryFromBytes::try_ref_from_bytes
+    /// [self-unaligned]: Unaligned
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be called with an array whose length is not equal to
+    /// `size_of::<Self>()`. Attempting to do so results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::TryFromBytes;
+    ///
+    /// let _ = bool::try_ref_from_array(&[0u8; 2]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::TryFromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// // The only valid value of this type is the byte `0xC0`
+    /// #[derive(TryFromBytes, Immutable)]
+    /// #[repr(u8)]
+    /// enum C0 { xC0 = 0xC0 }
+    ///
+    /// // The only valid value of this type is the bytes `0xC0C0`.
+    /// #[derive(TryFromBytes, Immutable)]
+    /// #[repr(C)]
+    /// struct C0C0(C0, C0);
+    ///
+    /// #[derive(TryFromBytes, Immutable)]
+    /// #[repr(C)]
+    /// struct Packet {
+    ///     magic_number: C0C0,
+    ///     mug_size: u8,
+    ///     temperature: u8,
+    /// }
+    ///
+    /// let bytes = &[0xC0, 0xC0, 240, 77];
+    ///
+    /// let packet = Packet::try_ref_from_array(bytes).unwrap();
+    ///
+    /// assert_eq!(packet.mug_size, 240);
+    /// assert_eq!(packet.temperature, 77);
+    ///
+    /// // These bytes are not valid instance of `Packet`.
+    /// let bytes = &[0x10, 0xC0, 240, 77];
+    /// assert!(Packet::try_ref_from_array(bytes).is_err());
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn try_ref_from_array<const N: usize>(
+        source: &[u8; N],
+    ) -> Result<
+        &Self,
+        ConvertError<AlignmentError<&[u8; N], Self>, Infallible, ValidityError<&[u8; N], Self>>,
+    >
+    where
+        Self: Sized + Immutable,
+    {
+        static_assert!(Self, const N: usize => N == mem::size_of::<Self>());
+        if let Err(err) = util::validate_aligned_to::<_, Self>(source) {
+            return Err(err.with_src(source).into());
+        }
+
+        // This call may panic. If that happens, it doesn't cause any soundness
+        // issues, as we have not generated any invalid state which we need to
+        // fix before returning.
+        match util::macro_util::try_cast_or_pme::<_, Self, _, BecauseImmutable>(Ptr::from_ref(
+            source,
+        )) {
+            Ok(ptr) => {
+                // SAFETY: We just validated that `source` is aligned to
+                // `align_of::<Self>()`, and `ptr` addresses the same bytes as
+                // `source`.
+                let ptr = unsafe { ptr.assume_alignment::<invariant::Aligned>() };
+                Ok(ptr.as_ref())
+            }
+            Err(err) => Err(err.map_src(Ptr::as_ref).into()),
+        }
+    }
+
+    /// Attempts to interpret the given `source` as a `&mut Self`.
+    ///
+    /// Unlike [`try_mut_from_bytes`], this method checks at compile time that
+    /// `N` is equal to `size_of::<Self>()`. If `source` is not appropriately
+    /// aligned, or if the bytes are not a valid instance of `Self`, this
+    /// returns `Err`. If [`Self: Unaligned`][self-unaligned], you can
+    /// [infallibly discard the alignment error][ConvertError::from].
+    ///
+    /// [`try_mut_from_bytes`]: TryFromBytes::try_mut_from_bytes
+    /// [self-unaligned]: Unaligned
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be called with an array whose length is not equal to
+    /// `size_of::<Self>()`. Attempting to do so results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::TryFromBytes;
+    ///
+    /// let _ = bool::try_mut_from_array(&mut [0u8; 2]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::TryFromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// // The only valid value of this type is the byte `0xC0`
+    /// #[derive(TryFromBytes, IntoBytes)]
+    /// #[repr(u8)]
+    /// enum C0 { xC0 = 0xC0 }
+    ///
+    /// // The only valid value of this type is the bytes `0xC0C0`.
+    /// #[derive(TryFromBytes, IntoBytes)]
+    /// #[repr(C)]
+    /// struct C0C0(C0, C0);
+    ///
+    /// #[derive(TryFromBytes, IntoBytes)]
+    /// #[repr(C)]
+    /// struct Packet {
+    ///     magic_number: C0C0,
+    ///     mug_size: u8,
+    ///     temperature: u8,
+    /// }
+    ///
+    /// let bytes = &mut [0xC0, 0xC0, 240, 77];
+    ///
+    /// let packet = Packet::try_mut_from_array(bytes).unwrap();
+    ///
+    /// assert_eq!(packet.mug_size, 240);
+    /// assert_eq!(packet.temperature, 77);
+    ///
+    /// packet.temperature = 111;
+    ///
+    /// assert_eq!(bytes, &[0xC0, 0xC0, 240, 111]);
+    ///
+    /// // These bytes are not valid instance of `Packet`.
+    /// let bytes = &mut [0x10, 0xC0, 240, 77];
+    /// assert!(Packet::try_mut_from_array(bytes).is_err());
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn try_mut_from_array<const N: usize>(
+        source: &mut [u8; N],
+    ) -> Result<
+        &mut Self,
+        ConvertError<
+            AlignmentError<&mut [u8; N], Self>,
+            Infallible,
+            ValidityError<&mut [u8; N], Self>,
+        >,
+    >
+    where
+        Self: Sized + IntoBytes,
+    {
+        static_assert!(Self, const N: usize => N == mem::size_of::<Self>());
+        if let Err(err) = util::validate_aligned_to::<_, Self>(&*source) {
+            return Err(err.with_src(source).into());
+        }
+
+        // This call may panic. If that happens, it doesn't cause any soundness
+        // issues, as we have not generated any invalid state which we need to
+        // fix before returning.
+        match util::macro_util::try_cast_or_pme::<_, Self, _, BecauseExclusive>(Ptr::from_mut(
+            source,
+        )) {
+            Ok(ptr) => {
+                // SAFETY: We just validated that `source` is aligned to
+                // `align_of::<Self>()`, and `ptr` addresses the same bytes as
+                // `source`.
+                let ptr = unsafe { ptr.assume_alignment::<invariant::Aligned>() };
+                Ok(ptr.as_mut())
+            }
+            Err(err) => Err(err.map_src(Ptr::as_mut).into()),
+        }
+    }
+
+    /// Attempts to read the given `source` as a `Self`.
+    ///
+    /// Unlike [`try_read_from_bytes`], this method checks at compile time that
+    /// `N` is equal to `size_of::<Self>()`. If the bytes are not a valid
+    /// instance of `Self`, this returns `Err`.
+    ///
+    /// [`try_read_from_bytes`]: TryFromBytes::try_read_from_bytes
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be called with an array whose length is not equal to
+    /// `size_of::<Self>()`. Attempting to do so results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::TryFromBytes;
+    ///
+    /// let _ = bool::try_read_from_array(&[0u8; 2]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::TryFromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// // The only valid value of this type is the byte `0xC0`
+    /// #[derive(TryFromBytes)]
+    /// #[repr(u8)]
+    /// enum C0 { xC0 = 0xC0 }
+    ///
+    /// // The only valid value of this type is the bytes `0xC0C0`.
+    /// #[derive(TryFromBytes)]
+    /// #[repr(C)]
+    /// struct C0C0(C0, C0);
+    ///
+    /// #[derive(TryFromBytes)]
+    /// #[repr(C)]
+    /// struct Packet {
+    ///     magic_number: C0C0,
+    ///     mug_size: u8,
+    ///     temperature: u8,
+    /// }
+    ///
+    /// let bytes = &[0xC0, 0xC0, 240, 77];
+    ///
+    /// let packet = Packet::try_read_from_array(bytes).unwrap();
+    ///
+    /// assert_eq!(packet.mug_size, 240);
+    /// assert_eq!(packet.temperature, 77);
+    ///
+    /// // These bytes are not valid instance of `Packet`.
+    /// let bytes = &[0x10, 0xC0, 240, 77];
+    /// assert!(Packet::try_read_from_array(bytes).is_err());
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn try_read_from_array<const N: usize>(
+        source: &[u8; N],
+    ) -> Result<Self, ValidityError<&[u8; N], Self>>
+    where
+        Self: Sized,
+    {
+        let candidate = MaybeUninit::<Self>::read_from_array(source);
+        // SAFETY: `candidate` was copied from from `source: &[u8; N]`, so all
+        // of its bytes are initialized.
+        unsafe { try_read_from(source, candidate) }
     }
 }
 
@@ -2716,7 +3235,7 @@ fn swap<T, U>((t, u): (T, U)) -> (U, T) {
 unsafe fn try_read_from<S, T: TryFromBytes>(
     source: S,
     mut candidate: MaybeUninit<T>,
-) -> Result<T, TryReadError<S, T>> {
+) -> Result<T, ValidityError<S, T>> {
     // We use `from_mut` despite not mutating via `c_ptr` so that we don't need
     // to add a `T: Immutable` bound.
     let c_ptr = Ptr::from_mut(&mut candidate);
@@ -2733,8 +3252,10 @@ unsafe fn try_read_from<S, T: TryFromBytes>(
     // calling `try_into_valid` (and thus `is_bit_valid`) with a shared
     // pointer when `Self: !Immutable`. Since `Self: Immutable`, this panic
     // condition will not happen.
-    if !T::is_bit_valid(c_ptr.forget_aligned()) {
-        return Err(ValidityError::new(source).into());
+    let mut c_ptr = c_ptr.forget_aligned();
+    if !T::is_bit_valid(c_ptr.reborrow()) {
+        let mismatched_constant = T::mismatched_constant(c_ptr);
+        return Err(ValidityError::new(source).with_mismatched_constant(mismatched_constant));
     }
 
     // SAFETY: We just validated that `candidate` contains a valid `T`.
@@ -2793,6 +3314,10 @@ unsafe fn try_read_from<S, T: TryFromBytes>(
 /// This derive performs a sophisticated, compile-time safety analysis to
 /// determine whether a type is `FromZeros`.
 ///
+/// Where the derive cannot be used, `FromZeros` may instead be implemented by
+/// hand using [`unsafe_impl_items!`]. Such an implementation must uphold the
+/// requirements described in the "Safety" section below.
+///
 /// # Safety
 ///
 /// *This section describes what is required in order for `T: FromZeros`, and
@@ -2822,9 +3347,14 @@ unsafe fn try_read_from<S, T: TryFromBytes>(
     diagnostic::on_unimplemented(note = "Consider adding `#[derive(FromZeros)]` to `{Self}`")
 )]
 pub unsafe trait FromZeros: TryFromBytes {
+    /// Marks an implementation of this trait as upholding its safety
+    /// requirements.
+    ///
+    /// This method is provided by zerocopy's derives and by
+    /// [`unsafe_impl_items!`]; it should not otherwise be implemented, and is
+    /// never called.
     // The `Self: Sized` bound makes it so that `FromZeros` is still object
     // safe.
-    #[doc(hidden)]
     fn only_derive_is_allowed_to_implement_this_trait()
     where
         Self: Sized;
@@ -3063,38 +3593,166 @@ pub unsafe trait FromZeros: TryFromBytes {
         Ok(unsafe { Box::from_raw(ptr.as_ptr()) })
     }
 
-    /// Creates a `Vec<Self>` from zeroed bytes.
-    ///
-    /// This function is useful for allocating large values of `Vec`s and
-    /// zero-initializing them, without ever creating a temporary instance of
-    /// `[Self; _]` (or many temporary instances of `Self`) on the stack. For
-    /// example, `u8::new_vec_zeroed(1048576)` will allocate directly on the
-    /// heap; it does not require storing intermediate values on the stack.
+    /// Creates an `Rc<Self>` from zeroed bytes.
     ///
-    /// On systems that use a heap implementation that supports allocating from
-    /// pre-zeroed memory, using `new_vec_zeroed` may have performance benefits.
+    /// Like [`new_box_zeroed`], this zero-initializes `Self` on the heap,
+    /// without ever creating a temporary instance of `Self` on the stack.
     ///
-    /// If `Self` is a zero-sized type, then this function will return a
-    /// `Vec<Self>` that has the correct `len`. Such a `Vec` cannot contain any
-    /// actual information, but its `len()` property will report the correct
-    /// value.
+    /// `Rc` does not support fallible allocation or allocating zeroed memory
+    /// on all of our supported toolchains, so this is implemented by calling
+    /// [`new_box_zeroed`] and then converting the resulting `Box` into an
+    /// `Rc`. This conversion moves the referent into a second allocation.
     ///
     /// # Errors
     ///
-    /// Returns an error on allocation failure. Allocation failure is
-    /// guaranteed never to cause a panic or an abort.
+    /// Returns an error if allocating the `Box` fails, or if an `Rc` of this
+    /// size cannot be allocated without overflowing `isize`. Failure of the
+    /// second allocation, performed by the `Rc`, is handled by calling
+    /// [`handle_alloc_error`].
+    ///
+    /// [`new_box_zeroed`]: FromZeros::new_box_zeroed
+    /// [`handle_alloc_error`]: https://doc.rust-lang.org/alloc/alloc/fn.handle_alloc_error.html
     #[must_use = "has no side effects (other than allocation)"]
     #[cfg(feature = "alloc")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
-    #[inline(always)]
-    fn new_vec_zeroed(len: usize) -> Result<Vec<Self>, AllocError>
+    #[inline]
+    fn new_rc_zeroed() -> Result<Rc<Self>, AllocError>
     where
         Self: Sized,
     {
-        <[Self]>::new_box_zeroed_with_elems(len).map(Into::into)
+        check_rc_layout(Layout::new::<Self>())?;
+        Self::new_box_zeroed().map(Rc::from)
     }
 
-    /// Extends a `Vec<Self>` by pushing `additional` new items onto the end of
+    /// Creates an `Rc<Self>` from zeroed bytes, where `Self` is a slice DST.
+    ///
+    /// Like [`new_box_zeroed_with_elems`], this zero-initializes a `Self` with
+    /// `count` trailing slice elements on the heap, without ever creating a
+    /// temporary instance of `Self` on the stack.
+    ///
+    /// `Rc` does not support fallible allocation or allocating zeroed memory
+    /// on all of our supported toolchains, so this is implemented by calling
+    /// [`new_box_zeroed_with_elems`] and then converting the resulting `Box`
+    /// into an `Rc`. This conversion moves the referent into a second
+    /// allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if allocating the `Box` fails, or if an `Rc` of this
+    /// size cannot be allocated without overflowing `isize`. Failure of the
+    /// second allocation, performed by the `Rc`, is handled by calling
+    /// [`handle_alloc_error`].
+    ///
+    /// [`new_box_zeroed_with_elems`]: FromZeros::new_box_zeroed_with_elems
+    /// [`handle_alloc_error`]: https://doc.rust-lang.org/alloc/alloc/fn.handle_alloc_error.html
+    #[must_use = "has no side effects (other than allocation)"]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn new_rc_zeroed_with_elems(count: usize) -> Result<Rc<Self>, AllocError>
+    where
+        Self: KnownLayout<PointerMetadata = usize>,
+    {
+        check_rc_layout(layout_for_elems::<Self>(count)?)?;
+        Self::new_box_zeroed_with_elems(count).map(Rc::from)
+    }
+
+    /// Creates an `Arc<Self>` from zeroed bytes.
+    ///
+    /// Like [`new_box_zeroed`], this zero-initializes `Self` on the heap,
+    /// without ever creating a temporary instance of `Self` on the stack.
+    ///
+    /// `Arc` does not support fallible allocation or allocating zeroed memory
+    /// on all of our supported toolchains, so this is implemented by calling
+    /// [`new_box_zeroed`] and then converting the resulting `Box` into an
+    /// `Arc`. This conversion moves the referent into a second allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if allocating the `Box` fails, or if an `Arc` of this
+    /// size cannot be allocated without overflowing `isize`. Failure of the
+    /// second allocation, performed by the `Arc`, is handled by calling
+    /// [`handle_alloc_error`].
+    ///
+    /// [`new_box_zeroed`]: FromZeros::new_box_zeroed
+    /// [`handle_alloc_error`]: https://doc.rust-lang.org/alloc/alloc/fn.handle_alloc_error.html
+    #[must_use = "has no side effects (other than allocation)"]
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn new_arc_zeroed() -> Result<Arc<Self>, AllocError>
+    where
+        Self: Sized,
+    {
+        check_rc_layout(Layout::new::<Self>())?;
+        Self::new_box_zeroed().map(Arc::from)
+    }
+
+    /// Creates an `Arc<Self>` from zeroed bytes, where `Self` is a slice DST.
+    ///
+    /// Like [`new_box_zeroed_with_elems`], this zero-initializes a `Self` with
+    /// `count` trailing slice elements on the heap, without ever creating a
+    /// temporary instance of `Self` on the stack.
+    ///
+    /// `Arc` does not support fallible allocation or allocating zeroed memory
+    /// on all of our supported toolchains, so this is implemented by calling
+    /// [`new_box_zeroed_with_elems`] and then converting the resulting `Box`
+    /// into an `Arc`. This conversion moves the referent into a second
+    /// allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if allocating the `Box` fails, or if an `Arc` of this
+    /// size cannot be allocated without overflowing `isize`. Failure of the
+    /// second allocation, performed by the `Arc`, is handled by calling
+    /// [`handle_alloc_error`].
+    ///
+    /// [`new_box_zeroed_with_elems`]: FromZeros::new_box_zeroed_with_elems
+    /// [`handle_alloc_error`]: https://doc.rust-lang.org/alloc/alloc/fn.handle_alloc_error.html
+    #[must_use = "has no side effects (other than allocation)"]
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn new_arc_zeroed_with_elems(count: usize) -> Result<Arc<Self>, AllocError>
+    where
+        Self: KnownLayout<PointerMetadata = usize>,
+    {
+        check_rc_layout(layout_for_elems::<Self>(count)?)?;
+        Self::new_box_zeroed_with_elems(count).map(Arc::from)
+    }
+
+    /// Creates a `Vec<Self>` from zeroed bytes.
+    ///
+    /// This function is useful for allocating large values of `Vec`s and
+    /// zero-initializing them, without ever creating a temporary instance of
+    /// `[Self; _]` (or many temporary instances of `Self`) on the stack. For
+    /// example, `u8::new_vec_zeroed(1048576)` will allocate directly on the
+    /// heap; it does not require storing intermediate values on the stack.
+    ///
+    /// On systems that use a heap implementation that supports allocating from
+    /// pre-zeroed memory, using `new_vec_zeroed` may have performance benefits.
+    ///
+    /// If `Self` is a zero-sized type, then this function will return a
+    /// `Vec<Self>` that has the correct `len`. Such a `Vec` cannot contain any
+    /// actual information, but its `len()` property will report the correct
+    /// value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on allocation failure. Allocation failure is
+    /// guaranteed never to cause a panic or an abort.
+    #[must_use = "has no side effects (other than allocation)"]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+    #[inline(always)]
+    fn new_vec_zeroed(len: usize) -> Result<Vec<Self>, AllocError>
+    where
+        Self: Sized,
+    {
+        <[Self]>::new_box_zeroed_with_elems(len).map(Into::into)
+    }
+
+    /// Extends a `Vec<Self>` by pushing `additional` new items onto the end of
     /// the vector. The new items are initialized with zeros.
     #[cfg(feature = "alloc")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
@@ -3151,6 +3809,33 @@ pub unsafe trait FromZeros: TryFromBytes {
     }
 }
 
+/// Computes the layout of a `T` with `count` trailing slice elements.
+///
+/// Returns an error if the size of `T` would overflow `isize`.
+#[cfg(feature = "alloc")]
+#[inline]
+fn layout_for_elems<T>(count: usize) -> Result<Layout, AllocError>
+where
+    T: ?Sized + KnownLayout<PointerMetadata = usize>,
+{
+    let size = count.size_for_metadata(T::LAYOUT).ok_or(AllocError)?;
+    Layout::from_size_align(size, T::LAYOUT.align.get()).or(Err(AllocError))
+}
+
+/// Checks that a referent with the given layout can be allocated inside of an
+/// `Rc` or `Arc`.
+///
+/// Returns an error if the allocation, including the reference counts which
+/// precede the referent, would overflow `isize`. `Rc` and `Arc` panic rather
+/// than returning an error in this case.
+#[cfg(feature = "alloc")]
+#[inline]
+fn check_rc_layout(layout: Layout) -> Result<(), AllocError> {
+    // Both `Rc` and `Arc` store their strong and weak reference counts as two
+    // `usize`-sized fields preceding the referent.
+    Layout::new::<[usize; 2]>().extend(layout).map(|_| ()).or(Err(AllocError))
+}
+
 /// Analyzes whether a type is [`FromBytes`].
 ///
 /// This derive analyzes, at compile time, whether the annotated type satisfies
@@ -3347,6 +4032,10 @@ pub use zerocopy_derive::FromBytes;
 /// This derive performs a sophisticated, compile-time safety analysis to
 /// determine whether a type is `FromBytes`.
 ///
+/// Where the derive cannot be used, `FromBytes` may instead be implemented by
+/// hand using [`unsafe_impl_items!`]. Such an implementation must uphold the
+/// requirements described in the "Safety" section below.
+///
 /// # Safety
 ///
 /// *This section describes what is required in order for `T: FromBytes`, and
@@ -3377,9 +4066,14 @@ pub use zerocopy_derive::FromBytes;
     diagnostic::on_unimplemented(note = "Consider adding `#[derive(FromBytes)]` to `{Self}`")
 )]
 pub unsafe trait FromBytes: FromZeros {
+    /// Marks an implementation of this trait as upholding its safety
+    /// requirements.
+    ///
+    /// This method is provided by zerocopy's derives and by
+    /// [`unsafe_impl_items!`]; it should not otherwise be implemented, and is
+    /// never called.
     // The `Self: Sized` bound makes it so that `FromBytes` is still object
     // safe.
-    #[doc(hidden)]
     fn only_derive_is_allowed_to_implement_this_trait()
     where
         Self: Sized;
@@ -4474,6 +5168,222 @@ pub unsafe trait FromBytes: FromZeros {
             Err(CastError::Validity(i)) => match i {},
         }
     }
+
+    /// Interprets the given `source` as a `&Self`.
+    ///
+    /// Unlike [`ref_from_bytes`], this method checks at compile time that `N`
+    /// is equal to `size_of::<Self>()`, and so the only way it can fail is if
+    /// `source` is not appropriately aligned. If [`Self:
+    /// Unaligned`][self-unaligned], the returned [`AlignmentError`] can be
+    /// infallibly converted into [`Infallible`][core::convert::Infallible].
+    ///
+    /// [`ref_from_bytes`]: FromBytes::ref_from_bytes
+    /// [self-unaligned]: Unaligned
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be called with an array whose length is not equal to
+    /// `size_of::<Self>()`. Attempting to do so results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::FromBytes;
+    ///
+    /// let _ = u32::ref_from_array(&[0u8; 8]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, KnownLayout, Immutable)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     src_port: [u8; 2],
+    ///     dst_port: [u8; 2],
+    ///     length: [u8; 2],
+    ///     checksum: [u8; 2],
+    /// }
+    ///
+    /// let bytes = &[0, 1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let header = PacketHeader::ref_from_array(bytes).unwrap();
+    ///
+    /// assert_eq!(header.src_port, [0, 1]);
+    /// assert_eq!(header.dst_port, [2, 3]);
+    /// assert_eq!(header.length, [4, 5]);
+    /// assert_eq!(header.checksum, [6, 7]);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn ref_from_array<const N: usize>(
+        source: &[u8; N],
+    ) -> Result<&Self, AlignmentError<&[u8; N], Self>>
+    where
+        Self: Sized + Immutable,
+    {
+        static_assert!(Self, const N: usize => N == mem::size_of::<Self>());
+        if let Err(err) = util::validate_aligned_to::<_, Self>(source) {
+            return Err(err.with_src(source));
+        }
+
+        let ptr: *const [u8; N] = source;
+        // SAFETY:
+        // - `ptr.cast::<Self>()` is non-null and validly aligned for `Self`,
+        //   since `ptr` is derived from a reference and we just validated its
+        //   alignment.
+        // - The referent of `ptr` is valid for reads of `size_of::<Self>()`
+        //   bytes, since `N == size_of::<Self>()` (asserted above), and all of
+        //   these bytes are initialized. Since `Self: FromBytes`, they are a
+        //   valid `Self`.
+        // - The returned reference has the lifetime of `source`, and neither
+        //   `[u8; N]` nor `Self` (which is `Immutable`) permit interior
+        //   mutation, so no mutation can happen through either reference while
+        //   the other is live.
+        Ok(unsafe { &*ptr.cast::<Self>() })
+    }
+
+    /// Interprets the given `source` as a `&mut Self`.
+    ///
+    /// Unlike [`mut_from_bytes`], this method checks at compile time that `N`
+    /// is equal to `size_of::<Self>()`, and so the only way it can fail is if
+    /// `source` is not appropriately aligned. If [`Self:
+    /// Unaligned`][self-unaligned], the returned [`AlignmentError`] can be
+    /// infallibly converted into [`Infallible`][core::convert::Infallible].
+    ///
+    /// [`mut_from_bytes`]: FromBytes::mut_from_bytes
+    /// [self-unaligned]: Unaligned
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be called with an array whose length is not equal to
+    /// `size_of::<Self>()`. Attempting to do so results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::FromBytes;
+    ///
+    /// let _ = u32::mut_from_array(&mut [0u8; 8]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes, IntoBytes, KnownLayout)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     src_port: [u8; 2],
+    ///     dst_port: [u8; 2],
+    ///     length: [u8; 2],
+    ///     checksum: [u8; 2],
+    /// }
+    ///
+    /// let bytes = &mut [0, 1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let header = PacketHeader::mut_from_array(bytes).unwrap();
+    ///
+    /// assert_eq!(header.src_port, [0, 1]);
+    /// assert_eq!(header.dst_port, [2, 3]);
+    /// assert_eq!(header.length, [4, 5]);
+    /// assert_eq!(header.checksum, [6, 7]);
+    ///
+    /// header.checksum = [0, 0];
+    ///
+    /// assert_eq!(bytes, &[0, 1, 2, 3, 4, 5, 0, 0]);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn mut_from_array<const N: usize>(
+        source: &mut [u8; N],
+    ) -> Result<&mut Self, AlignmentError<&mut [u8; N], Self>>
+    where
+        Self: Sized + IntoBytes,
+    {
+        static_assert!(Self, const N: usize => N == mem::size_of::<Self>());
+        if let Err(err) = util::validate_aligned_to::<_, Self>(&*source) {
+            return Err(err.with_src(source));
+        }
+
+        let ptr: *mut [u8; N] = source;
+        // SAFETY:
+        // - `ptr.cast::<Self>()` is non-null and validly aligned for `Self`,
+        //   since `ptr` is derived from a reference and we just validated its
+        //   alignment.
+        // - The referent of `ptr` is valid for reads and writes of
+        //   `size_of::<Self>()` bytes, since `N == size_of::<Self>()` (asserted
+        //   above), and all of these bytes are initialized. Since `Self:
+        //   FromBytes`, they are a valid `Self`. Since `Self: IntoBytes`, any
+        //   `Self` written through the returned reference leaves the referent a
+        //   valid `[u8; N]`.
+        // - The returned reference has the lifetime of `source`, which is
+        //   exclusively borrowed for that lifetime.
+        Ok(unsafe { &mut *ptr.cast::<Self>() })
+    }
+
+    /// Reads a copy of `Self` from the given `source`.
+    ///
+    /// Unlike [`read_from_bytes`], this method checks at compile time that `N`
+    /// is equal to `size_of::<Self>()`, and so it cannot fail.
+    ///
+    /// [`read_from_bytes`]: FromBytes::read_from_bytes
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be called with an array whose length is not equal to
+    /// `size_of::<Self>()`. Attempting to do so results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::FromBytes;
+    ///
+    /// let _ = u32::read_from_array(&[0u8; 8]); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::FromBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(FromBytes)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     src_port: [u8; 2],
+    ///     dst_port: [u8; 2],
+    ///     length: [u8; 2],
+    ///     checksum: [u8; 2],
+    /// }
+    ///
+    /// let bytes = &[0, 1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let header = PacketHeader::read_from_array(bytes);
+    ///
+    /// assert_eq!(header.src_port, [0, 1]);
+    /// assert_eq!(header.dst_port, [2, 3]);
+    /// assert_eq!(header.length, [4, 5]);
+    /// assert_eq!(header.checksum, [6, 7]);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline]
+    fn read_from_array<const N: usize>(source: &[u8; N]) -> Self
+    where
+        Self: Sized,
+    {
+        static_assert!(Self, const N: usize => N == mem::size_of::<Self>());
+        let ptr: *const [u8; N] = source;
+        // SAFETY:
+        // - `ptr.cast::<Self>()` is valid for reads of `size_of::<Self>()`
+        //   bytes, since `N == size_of::<Self>()` (asserted above).
+        //   `read_unaligned` does not require that it be aligned.
+        // - All of the bytes of `source` are initialized. Since `Self:
+        //   FromBytes`, they are a valid `Self`.
+        unsafe { ptr::read_unaligned(ptr.cast::<Self>()) }
+    }
 }
 
 /// Interprets the given affix of the given bytes as a `&Self`.
@@ -4666,6 +5576,10 @@ pub use zerocopy_derive::IntoBytes;
 /// documentation][derive] for guidance on how to interpret error messages
 /// produced by the derive's analysis.
 ///
+/// Where the derive cannot be used, `IntoBytes` may instead be implemented by
+/// hand using [`unsafe_impl_items!`]. Such an implementation must uphold the
+/// requirements described in the "Safety" section below.
+///
 /// # Safety
 ///
 /// *This section describes what is required in order for `T: IntoBytes`, and
@@ -4696,13 +5610,18 @@ pub use zerocopy_derive::IntoBytes;
     diagnostic::on_unimplemented(note = "Consider adding `#[derive(IntoBytes)]` to `{Self}`")
 )]
 pub unsafe trait IntoBytes {
+    /// Marks an implementation of this trait as upholding its safety
+    /// requirements.
+    ///
+    /// This method is provided by zerocopy's derives and by
+    /// [`unsafe_impl_items!`]; it should not otherwise be implemented, and is
+    /// never called.
     // The `Self: Sized` bound makes it so that this function doesn't prevent
     // `IntoBytes` from being object safe. Note that other `IntoBytes` methods
     // prevent object safety, but those provide a benefit in exchange for object
     // safety. If at some point we remove those methods, change their type
     // signatures, or move them out of this trait so that `IntoBytes` is object
     // safe again, it's important that this function not prevent object safety.
-    #[doc(hidden)]
     fn only_derive_is_allowed_to_implement_this_trait()
     where
         Self: Sized;
@@ -4843,6 +5762,152 @@ pub unsafe trait IntoBytes {
         unsafe { slice::from_raw_parts_mut(slf.cast::<u8>(), len) }
     }
 
+    /// Gets the bytes of this value as an array.
+    ///
+    /// Unlike [`as_bytes`], this method returns an array whose length `N` is
+    /// checked at compile time to be equal to `size_of::<Self>()`.
+    ///
+    /// [`as_bytes`]: IntoBytes::as_bytes
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be called with an `N` which is not equal to
+    /// `size_of::<Self>()`. Attempting to do so results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::IntoBytes;
+    ///
+    /// let _: &[u8; 8] = 0u32.as_array(); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::IntoBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// #[derive(IntoBytes, Immutable)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     src_port: [u8; 2],
+    ///     dst_port: [u8; 2],
+    ///     length: [u8; 2],
+    ///     checksum: [u8; 2],
+    /// }
+    ///
+    /// let header = PacketHeader {
+    ///     src_port: [0, 1],
+    ///     dst_port: [2, 3],
+    ///     length: [4, 5],
+    ///     checksum: [6, 7],
+    /// };
+    ///
+    /// let bytes: &[u8; 8] = header.as_array();
+    ///
+    /// assert_eq!(bytes, &[0, 1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    fn as_array<const N: usize>(&self) -> &[u8; N]
+    where
+        Self: Sized + Immutable,
+    {
+        static_assert!(Self, const N: usize => N == mem::size_of::<Self>());
+        let slf: *const Self = self;
+
+        // SAFETY:
+        // - `slf.cast::<[u8; N]>()` is valid for reads of `N` bytes because
+        //   `slf` is the same pointer as `self`, which points to an object of
+        //   size `size_of::<Self>() == N` (asserted above).
+        // - `slf` is trivially aligned to `align_of::<[u8; N]>() == 1`.
+        // - `Self: IntoBytes` ensures that all of the bytes of `slf` are
+        //   initialized.
+        // - Since `slf` is derived from `self`, and `self` is an immutable
+        //   reference, the only other references to this memory region that
+        //   could exist are other immutable references, and those don't allow
+        //   mutation. `Self: Immutable` prohibits types which contain
+        //   `UnsafeCell`s, which are the only types for which this rule
+        //   wouldn't be sufficient.
+        unsafe { &*slf.cast::<[u8; N]>() }
+    }
+
+    /// Gets the bytes of this value mutably as an array.
+    ///
+    /// Unlike [`as_mut_bytes`], this method returns an array whose length `N`
+    /// is checked at compile time to be equal to `size_of::<Self>()`.
+    ///
+    /// [`as_mut_bytes`]: IntoBytes::as_mut_bytes
+    ///
+    /// # Compile-Time Assertions
+    ///
+    /// This method cannot be called with an `N` which is not equal to
+    /// `size_of::<Self>()`. Attempting to do so results in a compile-time
+    /// assertion error; e.g.:
+    ///
+    /// ```compile_fail,E0080
+    /// use zerocopy::IntoBytes;
+    ///
+    /// let _: &mut [u8; 8] = 0u32.as_mut_array(); // ⚠ Compile Error!
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zerocopy::IntoBytes;
+    /// # use zerocopy_derive::*;
+    ///
+    /// # #[derive(Eq, PartialEq, Debug)]
+    /// #[derive(FromBytes, IntoBytes)]
+    /// #[repr(C)]
+    /// struct PacketHeader {
+    ///     src_port: [u8; 2],
+    ///     dst_port: [u8; 2],
+    ///     length: [u8; 2],
+    ///     checksum: [u8; 2],
+    /// }
+    ///
+    /// let mut header = PacketHeader {
+    ///     src_port: [0, 1],
+    ///     dst_port: [2, 3],
+    ///     length: [4, 5],
+    ///     checksum: [6, 7],
+    /// };
+    ///
+    /// let bytes: &mut [u8; 8] = header.as_mut_array();
+    ///
+    /// bytes.reverse();
+    ///
+    /// assert_eq!(header, PacketHeader {
+    ///     src_port: [7, 6],
+    ///     dst_port: [5, 4],
+    ///     length: [3, 2],
+    ///     checksum: [1, 0],
+    /// });
+    /// ```
+    #[must_use = "has no side effects"]
+    #[inline(always)]
+    fn as_mut_array<const N: usize>(&mut self) -> &mut [u8; N]
+    where
+        Self: Sized + FromBytes,
+    {
+        static_assert!(Self, const N: usize => N == mem::size_of::<Self>());
+        let slf: *mut Self = self;
+
+        // SAFETY:
+        // - `slf.cast::<[u8; N]>()` is valid for reads and writes of `N` bytes
+        //   because `slf` is the same pointer as `self`, which points to an
+        //   object of size `size_of::<Self>() == N` (asserted above).
+        // - `slf` is trivially aligned to `align_of::<[u8; N]>() == 1`.
+        // - `Self: IntoBytes` ensures that all of the bytes of `slf` are
+        //   initialized.
+        // - `Self: FromBytes` ensures that no write to this memory region
+        //   could result in it containing an invalid `Self`.
+        // - Since `slf` is derived from `self`, and `self` is a mutable
+        //   reference, no other references to this memory region can exist.
+        unsafe { &mut *slf.cast::<[u8; N]>() }
+    }
+
     /// Writes a copy of `self` to `dst`.
     ///
     /// If `dst.len() != size_of_val(self)`, `write_to` returns `Err`.
@@ -5125,6 +6190,122 @@ pub unsafe trait IntoBytes {
 #[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
 pub use zerocopy_derive::Unaligned;
 
+/// Converts a field-less enum into an "open" enum which can hold any value of
+/// its underlying integer type.
+///
+/// Protocol fields such as EtherTypes, IP protocol numbers, and DNS record
+/// types are usually modeled as enums, but real traffic contains values which
+/// the enum doesn't name. `#[derive(TryFromBytes)]` rejects such values, and
+/// `#[derive(FromBytes)]` requires an enum to have a variant for every possible
+/// value. `#[open_enum]` instead replaces the enum with a `#[repr(transparent)]`
+/// newtype around the integer type given as its argument, which may be a
+/// primitive integer (e.g., `u8`) or a [byte-order-aware
+/// integer](byteorder) (e.g., `U16<BigEndian>`):
+///
+/// ```
+/// # use zerocopy::{byteorder::{BigEndian, U16}, FromBytes};
+/// # use zerocopy_derive::open_enum;
+/// #[open_enum(U16<BigEndian>)]
+/// pub enum EtherType {
+///     Ipv4 = 0x0800,
+///     Arp = 0x0806,
+///     Ipv6 = 0x86DD,
+/// }
+///
+/// let ether_type = EtherType::read_from_bytes(&[0x86, 0xDD][..]).unwrap();
+/// assert_eq!(ether_type, EtherType::Ipv6);
+/// assert_eq!(ether_type.known(), Some(EtherTypeKnown::Ipv6));
+/// assert_eq!(format!("{ether_type:?}"), "Ipv6");
+///
+/// let ether_type = EtherType::read_from_bytes(&[0x12, 0x34][..]).unwrap();
+/// assert_eq!(ether_type.known(), None);
+/// assert_eq!(format!("{ether_type:#x?}"), "0x1234");
+///
+/// match ether_type {
+///     EtherType::Ipv4 | EtherType::Ipv6 => { /* ... */ }
+///     EtherType::Arp => { /* ... */ }
+///     _ => { /* ... */ }
+/// }
+/// ```
+///
+/// The generated newtype:
+/// - has a public field of the given integer type
+/// - has an associated constant for each variant, which can be used in
+///   patterns
+/// - implements [`KnownLayout`], [`Immutable`], [`FromBytes`], and
+///   [`IntoBytes`], and implements [`Unaligned`] if the integer type is `u8`,
+///   `i8`, or a byte-order-aware integer
+/// - implements `Clone`, `Copy`, `PartialEq`, `Eq`, and `Hash`
+/// - implements `Debug`, printing the name of the variant if the value is
+///   known, and the raw integer value otherwise
+/// - has a `known` method which converts it to a closed Rust enum with the
+///   original variants, returning `None` if the value is not known
+///
+/// The closed enum is named by appending `Known` to the name of the original
+/// enum; pass `known = Name` to choose a different name (e.g.,
+/// `#[open_enum(u8, known = KnownProtocol)]`). It implements `Clone`, `Copy`,
+/// `Debug`, `PartialEq`, `Eq`, and `Hash`, and can be converted back into the
+/// open enum via `From`.
+///
+/// Attributes on the original enum are applied to the newtype, except that
+/// `repr` is not permitted, and neither is deriving any of the traits listed
+/// above, since the newtype already implements them.
+///
+/// Since procedural macros cannot resolve types, the integer type is recognized
+/// by the name of the last segment of its path: `u8`, `i16`, `usize`, etc. are
+/// treated as primitive integers, and `U16`, `I32`, `Usize`, etc. (with any
+/// type arguments) as byte-order-aware integers. Aliases of these types are
+/// not recognized, and a user-defined type which shadows one of these names
+/// will be treated as the type it shadows.
+#[cfg(any(feature = "derive", test))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use zerocopy_derive::open_enum;
+
+/// Inserts explicit padding fields into a `repr(C)` struct.
+///
+/// [`IntoBytes`] cannot be derived for structs which contain padding, since
+/// padding bytes are uninitialized. The usual workaround is to add explicit
+/// `[u8; N]` fields which cover the padding, but these must be recomputed by
+/// hand whenever the struct's fields change. `#[padded]` computes the struct's
+/// `repr(C)` layout and inserts these fields automatically, so that
+/// `IntoBytes` (and [`FromBytes`]) can be derived:
+///
+/// ```
+/// # use zerocopy::IntoBytes;
+/// # use zerocopy_derive::*;
+/// #[padded]
+/// #[derive(FromBytes, IntoBytes, KnownLayout, Immutable)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     // One padding byte is inserted here.
+///     len: u16,
+///     flags: u8,
+///     // One padding byte is inserted here.
+/// }
+///
+/// let header = Header::new(1, 2, 3);
+/// assert_eq!(header.as_bytes().len(), 6);
+/// ```
+///
+/// Since the padding fields are private, values of the struct must be
+/// constructed using the generated `new` constructor, which takes the value of
+/// each field in declaration order and initializes every padding byte to zero.
+/// The constructor is no more visible than the struct or any of its fields, so
+/// it does not allow code which cannot name a private field to construct the
+/// struct. If the struct already has a method called `new`, the constructor
+/// can be given a different name using `#[padded(constructor = name)]`.
+///
+/// The offsets, size, and alignment of the struct are the same as they would
+/// have been without `#[padded]`.
+///
+/// `#[padded]` must be placed before any `#[derive]` attributes. It supports
+/// non-generic `repr(C)` structs with named, sized fields, optionally combined
+/// with `repr(align(N))`.
+#[cfg(any(feature = "derive", test))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use zerocopy_derive::padded;
+
 /// Types with no alignment requirement.
 ///
 /// If `T: Unaligned`, then `align_of::<T>() == 1`.
@@ -5166,6 +6347,10 @@ pub use zerocopy_derive::Unaligned;
 /// This derive performs a sophisticated, compile-time safety analysis to
 /// determine whether a type is `Unaligned`.
 ///
+/// Where the derive cannot be used, `Unaligned` may instead be implemented by
+/// hand using [`unsafe_impl_items!`]. Such an implementation must uphold the
+/// requirements described in the "Safety" section below.
+///
 /// # Safety
 ///
 /// *This section describes what is required in order for `T: Unaligned`, and
@@ -5196,9 +6381,14 @@ pub use zerocopy_derive::Unaligned;
     diagnostic::on_unimplemented(note = "Consider adding `#[derive(Unaligned)]` to `{Self}`")
 )]
 pub unsafe trait Unaligned {
+    /// Marks an implementation of this trait as upholding its safety
+    /// requirements.
+    ///
+    /// This method is provided by zerocopy's derives and by
+    /// [`unsafe_impl_items!`]; it should not otherwise be implemented, and is
+    /// never called.
     // The `Self: Sized` bound makes it so that `Unaligned` is still object
     // safe.
-    #[doc(hidden)]
     fn only_derive_is_allowed_to_implement_this_trait()
     where
         Self: Sized;
@@ -5920,6 +7110,54 @@ mod tests {
         assert!(AU64::mut_from_suffix(&mut buf.t[..]).is_err());
     }
 
+    #[test]
+    fn test_from_array_as_array() {
+        // Test `FromBytes::{ref, mut, read}_from_array`,
+        // `TryFromBytes::try_{ref, mut, read}_from_array`, and
+        // `IntoBytes::{as_array, as_mut_array}`.
+
+        let mut buf =
+            Align::<[u8; 16], AU64>::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 0, 0, 0]);
+
+        let aligned: &mut [u8; 8] = (&mut buf.t[..8]).try_into().unwrap();
+        assert_eq!(
+            AU64::ref_from_array(aligned).unwrap().0.to_ne_bytes(),
+            [0, 1, 2, 3, 4, 5, 6, 7]
+        );
+        AU64::mut_from_array(aligned).unwrap().0 = u64::from_ne_bytes([7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(aligned, &[7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(AU64::read_from_array(aligned).0.to_ne_bytes(), [7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(AU64::try_ref_from_array(aligned).unwrap().0.to_ne_bytes(), *aligned);
+        assert_eq!(AU64::try_mut_from_array(aligned).unwrap().0.to_ne_bytes(), *aligned);
+        assert_eq!(AU64::try_read_from_array(aligned).unwrap().0.to_ne_bytes(), *aligned);
+
+        // `buf.t` is aligned to 8, so an array starting at offset 1 is
+        // misaligned. Only the reads succeed.
+        let misaligned: &mut [u8; 8] = (&mut buf.t[1..9]).try_into().unwrap();
+        assert!(AU64::ref_from_array(misaligned).is_err());
+        assert!(AU64::mut_from_array(misaligned).is_err());
+        assert!(matches!(AU64::try_ref_from_array(misaligned), Err(ConvertError::Alignment(_))));
+        assert!(matches!(AU64::try_mut_from_array(misaligned), Err(ConvertError::Alignment(_))));
+        assert_eq!(AU64::read_from_array(misaligned).0.to_ne_bytes(), [6, 5, 4, 3, 2, 1, 0, 8]);
+        assert_eq!(
+            AU64::try_read_from_array(misaligned).unwrap().0.to_ne_bytes(),
+            [6, 5, 4, 3, 2, 1, 0, 8]
+        );
+
+        // Invalid values are rejected.
+        assert_eq!(bool::try_ref_from_array(&[1]), Ok(&true));
+        assert_eq!(bool::try_mut_from_array(&mut [0]), Ok(&mut false));
+        assert_eq!(bool::try_read_from_array(&[1]), Ok(true));
+        assert!(matches!(bool::try_ref_from_array(&[2]), Err(ConvertError::Validity(_))));
+        assert!(matches!(bool::try_mut_from_array(&mut [2]), Err(ConvertError::Validity(_))));
+        assert!(bool::try_read_from_array(&[2]).is_err());
+
+        let mut val = AU64(u64::from_ne_bytes([0, 1, 2, 3, 4, 5, 6, 7]));
+        assert_eq!(val.as_array(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+        val.as_mut_array::<8>()[0] = 42;
+        assert_eq!(val.0.to_ne_bytes(), [42, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
     #[test]
     fn test_to_methods() {
         /// Run a series of tests by calling `IntoBytes` methods on `t`.
@@ -6227,6 +7465,89 @@ mod tests {
                 Err(AllocError)
             );
         }
+
+        mod rc {
+            use super::*;
+            use ::alloc::{rc::Rc, sync::Arc};
+
+            #[test]
+            fn test_new_rc_arc_zeroed() {
+                assert_eq!(u64::new_rc_zeroed(), Ok(Rc::new(0)));
+                assert_eq!(u64::new_arc_zeroed(), Ok(Arc::new(0)));
+                drop(<[u32; 0x1000]>::new_rc_zeroed());
+                drop(<[u32; 0x1000]>::new_arc_zeroed());
+                // This exists in order to exercise unsafe code, especially
+                // when running under Miri.
+                #[allow(clippy::unit_cmp)]
+                {
+                    assert_eq!(<()>::new_rc_zeroed(), Ok(Rc::new(())));
+                    assert_eq!(<()>::new_arc_zeroed(), Ok(Arc::new(())));
+                }
+            }
+
+            #[test]
+            fn test_new_rc_arc_zeroed_with_elems() {
+                let s: Rc<[u64]> = <[u64]>::new_rc_zeroed_with_elems(3).unwrap();
+                assert_eq!(&*s, &[0, 0, 0]);
+                let s: Arc<[u64]> = <[u64]>::new_arc_zeroed_with_elems(3).unwrap();
+                assert_eq!(&*s, &[0, 0, 0]);
+
+                let s: Rc<[u64]> = <[u64]>::new_rc_zeroed_with_elems(0).unwrap();
+                assert_eq!(s.len(), 0);
+                let s: Arc<[u64]> = <[u64]>::new_arc_zeroed_with_elems(0).unwrap();
+                assert_eq!(s.len(), 0);
+
+                let s: Rc<[()]> = <[()]>::new_rc_zeroed_with_elems(3).unwrap();
+                assert_eq!(s.len(), 3);
+                let s: Arc<[()]> = <[()]>::new_arc_zeroed_with_elems(3).unwrap();
+                assert_eq!(s.len(), 3);
+
+                // Test that cloning and dropping the last reference behave
+                // correctly (in particular, that the allocation is freed with
+                // the layout with which it was allocated).
+                let s: Arc<[AU64]> = <[AU64]>::new_arc_zeroed_with_elems(5).unwrap();
+                let t = Arc::clone(&s);
+                drop(s);
+                assert_eq!(&*t, &[AU64(0); 5]);
+            }
+
+            #[test]
+            fn test_new_rc_arc_zeroed_with_elems_dst() {
+                #[derive(KnownLayout, FromZeros)]
+                #[repr(C, align(64))]
+                struct Dst {
+                    header: u16,
+                    trailing: [u32],
+                }
+
+                let dst: Rc<Dst> = Dst::new_rc_zeroed_with_elems(7).unwrap();
+                assert_eq!(dst.header, 0);
+                assert_eq!(&dst.trailing, &[0; 7]);
+                assert_eq!(mem::size_of_val(&*dst), 64);
+                assert_eq!(util::AsAddress::addr(&*dst) % 64, 0);
+
+                let dst: Arc<Dst> = Dst::new_arc_zeroed_with_elems(17).unwrap();
+                assert_eq!(dst.header, 0);
+                assert_eq!(&dst.trailing, &[0; 17]);
+                assert_eq!(mem::size_of_val(&*dst), 128);
+                assert_eq!(util::AsAddress::addr(&*dst) % 64, 0);
+            }
+
+            #[test]
+            fn test_new_rc_arc_zeroed_with_elems_errors() {
+                assert_eq!(<[u16]>::new_rc_zeroed_with_elems(usize::MAX), Err(AllocError));
+                assert_eq!(<[u16]>::new_arc_zeroed_with_elems(usize::MAX), Err(AllocError));
+
+                let max = <usize as core::convert::TryFrom<_>>::try_from(isize::MAX).unwrap();
+                assert_eq!(
+                    <[u16]>::new_rc_zeroed_with_elems((max / mem::size_of::<u16>()) + 1),
+                    Err(AllocError)
+                );
+                // Fits in `isize`, but not once the reference counts are
+                // included.
+                assert_eq!(<[u8]>::new_arc_zeroed_with_elems(max), Err(AllocError));
+            }
+        }
     }
 }
 
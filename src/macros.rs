@@ -678,6 +678,675 @@ macro_rules! try_transmute_mut {
     }}
 }
 
+/// Safely transmutes a mutable or immutable reference to a slice of one type
+/// to an immutable reference to a slice of another type.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// const fn transmute_slice<'src, 'dst, Src, Dst>(src: &'src [Src]) -> &'dst [Dst]
+/// where
+///     'src: 'dst,
+///     Src: IntoBytes + Immutable,
+///     Dst: FromBytes + Immutable,
+///     size_of::<Src>() != 0,
+///     size_of::<Dst>() != 0,
+///     size_of::<Src>() % size_of::<Dst>() == 0,
+///     align_of::<Src>() >= align_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The returned slice addresses the same bytes as `src`; its length is
+/// `src.len() * (size_of::<Src>() / size_of::<Dst>())`. The size and alignment
+/// requirements are checked at compile time. To transmute to a slice whose
+/// element type is larger than that of `src` (e.g., `&[u8]` to `&[u16]`), use
+/// [`FromBytes::ref_from_bytes`], which checks size and alignment at runtime.
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// [`FromBytes::ref_from_bytes`]: crate::FromBytes::ref_from_bytes
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::{transmute_slice, byteorder::{BigEndian, U32}};
+/// let quads: &[[u8; 4]] = &[[0, 0, 0, 1], [0, 0, 0, 2]];
+///
+/// let words: &[U32<BigEndian>] = transmute_slice!(quads);
+/// assert_eq!(words, [U32::new(1), U32::new(2)]);
+///
+/// let bytes: &[u8] = transmute_slice!(words);
+/// assert_eq!(bytes, [0, 0, 0, 1, 0, 0, 0, 2]);
+/// ```
+///
+/// # Use in `const` contexts
+///
+/// This macro can be invoked in `const` contexts.
+///
+/// # Compile-time assertions
+///
+/// If the size of `Dst` does not evenly divide the size of `Src`, or if `Dst`
+/// has a stricter alignment requirement than `Src`, the invocation fails to
+/// compile; e.g.:
+///
+/// ```compile_fail,E0080
+/// let bytes: &[u8] = &[0, 1, 2, 3];
+/// let increase_size: &[u16] = zerocopy::transmute_slice!(bytes);
+/// ```
+#[macro_export]
+macro_rules! transmute_slice {
+    ($e:expr) => {{
+        // Ensure that the source type is a slice reference or a mutable slice
+        // reference (note that mutable references are implicitly reborrowed
+        // here).
+        let e: &[_] = $e;
+        $crate::util::macro_util::must_use($crate::util::macro_util::transmute_slice(e))
+    }};
+}
+
+/// Safely transmutes a mutable reference to a slice of one type to a mutable
+/// reference to a slice of another type.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn transmute_slice_mut<'src, 'dst, Src, Dst>(src: &'src mut [Src]) -> &'dst mut [Dst]
+/// where
+///     'src: 'dst,
+///     Src: FromBytes + IntoBytes,
+///     Dst: FromBytes + IntoBytes,
+///     size_of::<Src>() != 0,
+///     size_of::<Dst>() != 0,
+///     size_of::<Src>() % size_of::<Dst>() == 0,
+///     align_of::<Src>() >= align_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The returned slice addresses the same bytes as `src`; its length is
+/// `src.len() * (size_of::<Src>() / size_of::<Dst>())`. The size and alignment
+/// requirements are checked at compile time.
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::transmute_slice_mut;
+/// let mut quads: [[u8; 4]; 2] = [[0, 1, 2, 3], [4, 5, 6, 7]];
+///
+/// let bytes: &mut [u8] = transmute_slice_mut!(&mut quads[..]);
+/// bytes.reverse();
+///
+/// assert_eq!(quads, [[7, 6, 5, 4], [3, 2, 1, 0]]);
+/// ```
+///
+/// # Compile-time assertions
+///
+/// If the size of `Dst` does not evenly divide the size of `Src`, or if `Dst`
+/// has a stricter alignment requirement than `Src`, the invocation fails to
+/// compile; e.g.:
+///
+/// ```compile_fail,E0080
+/// let bytes: &mut [u8] = &mut [0, 1, 2, 3];
+/// let increase_size: &mut [u16] = zerocopy::transmute_slice_mut!(bytes);
+/// ```
+#[macro_export]
+macro_rules! transmute_slice_mut {
+    ($e:expr) => {{
+        // Ensure that the source type is a mutable slice reference.
+        let e: &mut [_] = $e;
+        $crate::util::macro_util::must_use($crate::util::macro_util::transmute_slice_mut(e))
+    }};
+}
+
+/// Conditionally transmutes a mutable or immutable reference to a slice of one
+/// type to an immutable reference to a slice of another type.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn try_transmute_slice<Src, Dst>(src: &[Src]) -> Result<&[Dst], ValidityError<&[Src], [Dst]>>
+/// where
+///     Src: IntoBytes + Immutable,
+///     Dst: TryFromBytes + Immutable,
+///     size_of::<Src>() != 0,
+///     size_of::<Dst>() != 0,
+///     size_of::<Src>() % size_of::<Dst>() == 0,
+///     align_of::<Src>() >= align_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The size and alignment requirements are checked at compile time, as for
+/// [`transmute_slice!`]. The only runtime error is a [`ValidityError`], which is
+/// returned if any element of the transmuted slice is not a valid `Dst`.
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// [`ValidityError`]: crate::ValidityError
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::*;
+/// // [0u8, 1u8] → [false, true]
+/// let src: &[u8] = &[0, 1];
+/// assert_eq!(try_transmute_slice!(src), Ok(&[false, true][..]));
+///
+/// // [0u8, 2u8] → error
+/// let src: &[u8] = &[0, 2];
+/// assert!(matches!(
+///     try_transmute_slice!(src),
+///     Result::<&[bool], _>::Err(ValidityError { .. })
+/// ));
+/// ```
+#[macro_export]
+macro_rules! try_transmute_slice {
+    ($e:expr) => {{
+        // Ensure that the source type is a slice reference or a mutable slice
+        // reference (note that mutable references are implicitly reborrowed
+        // here).
+        let e: &[_] = $e;
+        $crate::util::macro_util::try_transmute_slice(e)
+    }};
+}
+
+/// Conditionally transmutes a mutable reference to a slice of one type to a
+/// mutable reference to a slice of another type.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn try_transmute_slice_mut<Src, Dst>(src: &mut [Src]) -> Result<&mut [Dst], ValidityError<&mut [Src], [Dst]>>
+/// where
+///     Src: FromBytes + IntoBytes,
+///     Dst: TryFromBytes + IntoBytes,
+///     size_of::<Src>() != 0,
+///     size_of::<Dst>() != 0,
+///     size_of::<Src>() % size_of::<Dst>() == 0,
+///     align_of::<Src>() >= align_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The size and alignment requirements are checked at compile time, as for
+/// [`transmute_slice_mut!`]. The only runtime error is a [`ValidityError`],
+/// which is returned if any element of the transmuted slice is not a valid
+/// `Dst`.
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// [`ValidityError`]: crate::ValidityError
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::*;
+/// // [0u8, 1u8] → [false, true]
+/// let src: &mut [u8] = &mut [0, 1];
+/// assert_eq!(try_transmute_slice_mut!(src), Ok(&mut [false, true][..]));
+///
+/// // [0u8, 2u8] → error
+/// let src: &mut [u8] = &mut [0, 2];
+/// assert!(matches!(
+///     try_transmute_slice_mut!(src),
+///     Result::<&mut [bool], _>::Err(ValidityError { .. })
+/// ));
+/// ```
+#[macro_export]
+macro_rules! try_transmute_slice_mut {
+    ($e:expr) => {{
+        // Ensure that the source type is a mutable slice reference.
+        let e: &mut [_] = $e;
+        $crate::util::macro_util::try_transmute_slice_mut(e)
+    }};
+}
+
+/// Safely transmutes a `Box<Src>` into a `Box<Dst>`, where every `Src` has the
+/// same size and alignment as some `Dst`.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn transmute_box<Src, Dst>(src: Box<Src>) -> Box<Dst>
+/// where
+///     Src: ?Sized + KnownLayout + IntoBytes,
+///     Dst: ?Sized + KnownLayout + FromBytes,
+///     align_of::<Src>() == align_of::<Dst>(),
+///     every Src has the same size as some Dst,
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// The allocation is reused; no bytes are copied.
+///
+/// # Dynamically sized types
+///
+/// `Src` and `Dst` may be [slice DSTs][slice-dst]. Since the allocation is
+/// eventually deallocated as a `Dst`, the returned `Dst` must have exactly the
+/// same size and alignment as the `Src`. This macro requires that such a `Dst`
+/// exist for *every* `Src`, which holds if `Src` and `Dst` have the same
+/// alignment, and either:
+/// - `Src` is sized, and some `Dst` has the same size as `Src`, or
+/// - `Src` and `Dst` are both slice DSTs whose trailing slices begin at the
+///   same offset, and the size of `Dst`'s trailing slice element evenly
+///   divides the size of `Src`'s.
+///
+/// The returned `Dst` has as many trailing slice elements as fit in the bytes
+/// of the `Src`. To transmute between types whose sizes only sometimes
+/// coincide, such as from a `Box<[u8]>` into a `Box<Packet>` which has a
+/// header followed by a trailing slice, use [`try_transmute_box!`], which
+/// checks the size at runtime.
+///
+/// The same rules apply to [`transmute_rc!`] and [`transmute_arc!`], and to
+/// their `try_` forms.
+///
+/// [slice-dst]: crate::KnownLayout#dynamically-sized-types
+/// [`try_transmute_box!`]: crate::try_transmute_box
+/// [`transmute_rc!`]: crate::transmute_rc
+/// [`transmute_arc!`]: crate::transmute_arc
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::transmute_box;
+/// use std::boxed::Box;
+///
+/// let one_dimensional: Box<[u8; 8]> = Box::new([0, 1, 2, 3, 4, 5, 6, 7]);
+///
+/// let two_dimensional: Box<[[u8; 4]; 2]> = transmute_box!(one_dimensional);
+/// assert_eq!(*two_dimensional, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+///
+/// let pairs: Box<[[u8; 2]]> = vec![[0, 1], [2, 3], [4, 5]].into_boxed_slice();
+///
+/// let bytes: Box<[u8]> = transmute_box!(pairs);
+/// assert_eq!(*bytes, [0, 1, 2, 3, 4, 5]);
+/// ```
+///
+/// # Compile-time assertions
+///
+/// If some `Src` does not have the same size and alignment as any `Dst`, the
+/// invocation fails to compile; e.g.:
+///
+/// ```compile_fail,E0080
+/// # use std::boxed::Box;
+/// let change_alignment: Box<u16> = zerocopy::transmute_box!(Box::new([0u8; 2]));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! transmute_box {
+    ($e:expr) => {{
+        let e: $crate::util::macro_util::Box<_> = $e;
+        $crate::util::macro_util::transmute_box(e)
+    }};
+}
+
+/// Conditionally transmutes a `Box<Src>` into a `Box<Dst>`, where `Src` and
+/// `Dst` have the same alignment.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn try_transmute_box<Src, Dst>(src: Box<Src>) -> Result<Box<Dst>, TryReadError<Box<Src>, Dst>>
+/// where
+///     Src: ?Sized + KnownLayout + IntoBytes,
+///     Dst: ?Sized + KnownLayout + TryFromBytes,
+///     align_of::<Src>() == align_of::<Dst>(),
+///     if Src and Dst are both sized, size_of::<Src>() == size_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// The allocation is reused; no bytes are copied. `Src` and `Dst` may be
+/// [slice DSTs][slice-dst]. If no `Dst` has the same size as the referent, the
+/// original `Box<Src>` is returned in a [`SizeError`]. If the referent is not
+/// a valid `Dst`, the original `Box<Src>` is returned in a [`ValidityError`].
+/// See the [documentation for `transmute_box!`][transmute-box-dsts] for
+/// details on dynamically sized types.
+///
+/// [slice-dst]: crate::KnownLayout#dynamically-sized-types
+/// [`SizeError`]: crate::SizeError
+/// [`ValidityError`]: crate::ValidityError
+/// [transmute-box-dsts]: crate::transmute_box#dynamically-sized-types
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::*;
+/// # use zerocopy_derive::*;
+/// use std::boxed::Box;
+///
+/// // 1u8 → bool = true
+/// let src = Box::new(1u8);
+/// assert_eq!(try_transmute_box!(src), Ok(Box::new(true)));
+///
+/// // 2u8 → bool = error
+/// let src = Box::new(2u8);
+/// let err: TryReadError<_, bool> = try_transmute_box!(src).unwrap_err();
+/// assert_eq!(*err.into_src(), 2);
+///
+/// #[derive(TryFromBytes, KnownLayout)]
+/// #[repr(C)]
+/// struct Packet {
+///     is_urgent: bool,
+///     body: [u8],
+/// }
+///
+/// let src: Box<[u8]> = vec![1, 0xC0, 0xFF, 0xEE].into_boxed_slice();
+/// let packet: Box<Packet> = try_transmute_box!(src).unwrap();
+/// assert!(packet.is_urgent);
+/// assert_eq!(packet.body, [0xC0, 0xFF, 0xEE]);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! try_transmute_box {
+    ($e:expr) => {{
+        let e: $crate::util::macro_util::Box<_> = $e;
+        $crate::util::macro_util::try_transmute_box(e)
+    }};
+}
+
+/// Safely transmutes an `Rc<Src>` into an `Rc<Dst>`, where every `Src` has the
+/// same size and alignment as some `Dst`.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn transmute_rc<Src, Dst>(src: Rc<Src>) -> Rc<Dst>
+/// where
+///     Src: ?Sized + KnownLayout + IntoBytes + Immutable,
+///     Dst: ?Sized + KnownLayout + FromBytes + Immutable,
+///     align_of::<Src>() == align_of::<Dst>(),
+///     every Src has the same size as some Dst,
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// The allocation is reused; no bytes are copied. `Src` and `Dst` may be
+/// [slice DSTs][slice-dst]; see the [documentation for
+/// `transmute_box!`][transmute-box-dsts] for details.
+///
+/// Any other `Rc<Src>`s which share the allocation continue to observe it
+/// as a `Src`. Since `Src` and `Dst` are both `Immutable`, the bytes cannot
+/// change while they are shared.
+///
+/// [slice-dst]: crate::KnownLayout#dynamically-sized-types
+/// [transmute-box-dsts]: crate::transmute_box#dynamically-sized-types
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::transmute_rc;
+/// use std::rc::Rc;
+///
+/// let one_dimensional: Rc<[u8; 8]> = Rc::new([0, 1, 2, 3, 4, 5, 6, 7]);
+///
+/// let two_dimensional: Rc<[[u8; 4]; 2]> = transmute_rc!(one_dimensional);
+///
+/// assert_eq!(*two_dimensional, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+/// ```
+///
+/// # Compile-time assertions
+///
+/// If some `Src` does not have the same size and alignment as any `Dst`, the
+/// invocation fails to compile; e.g.:
+///
+/// ```compile_fail,E0080
+/// # use std::rc::Rc;
+/// let change_alignment: Rc<u16> = zerocopy::transmute_rc!(Rc::new([0u8; 2]));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! transmute_rc {
+    ($e:expr) => {{
+        let e: $crate::util::macro_util::Rc<_> = $e;
+        $crate::util::macro_util::transmute_rc(e)
+    }};
+}
+
+/// Conditionally transmutes an `Rc<Src>` into an `Rc<Dst>`, where `Src` and
+/// `Dst` have the same alignment.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn try_transmute_rc<Src, Dst>(src: Rc<Src>) -> Result<Rc<Dst>, TryReadError<Rc<Src>, Dst>>
+/// where
+///     Src: ?Sized + KnownLayout + IntoBytes + Immutable,
+///     Dst: ?Sized + KnownLayout + TryFromBytes + Immutable,
+///     align_of::<Src>() == align_of::<Dst>(),
+///     if Src and Dst are both sized, size_of::<Src>() == size_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// The allocation is reused; no bytes are copied. `Src` and `Dst` may be
+/// [slice DSTs][slice-dst]. If no `Dst` has the same size as the referent, the
+/// original `Rc<Src>` is returned in a [`SizeError`]. If the referent is not a
+/// valid `Dst`, the original `Rc<Src>` is returned in a [`ValidityError`]. See
+/// the [documentation for `transmute_box!`][transmute-box-dsts] for details on
+/// dynamically sized types.
+///
+/// Any other `Rc<Src>`s which share the allocation continue to observe it
+/// as a `Src`. Since `Src` and `Dst` are both `Immutable`, the bytes cannot
+/// change while they are shared.
+///
+/// [slice-dst]: crate::KnownLayout#dynamically-sized-types
+/// [`SizeError`]: crate::SizeError
+/// [`ValidityError`]: crate::ValidityError
+/// [transmute-box-dsts]: crate::transmute_box#dynamically-sized-types
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::*;
+/// use std::rc::Rc;
+///
+/// // 1u8 → bool = true
+/// let src = Rc::new(1u8);
+/// assert_eq!(try_transmute_rc!(src), Ok(Rc::new(true)));
+///
+/// // 2u8 → bool = error
+/// let src = Rc::new(2u8);
+/// let err: TryReadError<_, bool> = try_transmute_rc!(src).unwrap_err();
+/// assert_eq!(*err.into_src(), 2);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! try_transmute_rc {
+    ($e:expr) => {{
+        let e: $crate::util::macro_util::Rc<_> = $e;
+        $crate::util::macro_util::try_transmute_rc(e)
+    }};
+}
+
+/// Safely transmutes an `Arc<Src>` into an `Arc<Dst>`, where every `Src` has
+/// the same size and alignment as some `Dst`.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn transmute_arc<Src, Dst>(src: Arc<Src>) -> Arc<Dst>
+/// where
+///     Src: ?Sized + KnownLayout + IntoBytes + Immutable,
+///     Dst: ?Sized + KnownLayout + FromBytes + Immutable,
+///     align_of::<Src>() == align_of::<Dst>(),
+///     every Src has the same size as some Dst,
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// The allocation is reused; no bytes are copied. `Src` and `Dst` may be
+/// [slice DSTs][slice-dst]; see the [documentation for
+/// `transmute_box!`][transmute-box-dsts] for details.
+///
+/// Any other `Arc<Src>`s which share the allocation continue to observe it
+/// as a `Src`. Since `Src` and `Dst` are both `Immutable`, the bytes cannot
+/// change while they are shared.
+///
+/// [slice-dst]: crate::KnownLayout#dynamically-sized-types
+/// [transmute-box-dsts]: crate::transmute_box#dynamically-sized-types
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::transmute_arc;
+/// use std::sync::Arc;
+///
+/// let one_dimensional: Arc<[u8; 8]> = Arc::new([0, 1, 2, 3, 4, 5, 6, 7]);
+///
+/// let two_dimensional: Arc<[[u8; 4]; 2]> = transmute_arc!(one_dimensional);
+///
+/// assert_eq!(*two_dimensional, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+/// ```
+///
+/// # Compile-time assertions
+///
+/// If some `Src` does not have the same size and alignment as any `Dst`, the
+/// invocation fails to compile; e.g.:
+///
+/// ```compile_fail,E0080
+/// # use std::sync::Arc;
+/// let change_alignment: Arc<u16> = zerocopy::transmute_arc!(Arc::new([0u8; 2]));
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! transmute_arc {
+    ($e:expr) => {{
+        let e: $crate::util::macro_util::Arc<_> = $e;
+        $crate::util::macro_util::transmute_arc(e)
+    }};
+}
+
+/// Conditionally transmutes an `Arc<Src>` into an `Arc<Dst>`, where `Src` and
+/// `Dst` have the same alignment.
+///
+/// This macro behaves like an invocation of this function:
+///
+/// ```ignore
+/// fn try_transmute_arc<Src, Dst>(src: Arc<Src>) -> Result<Arc<Dst>, TryReadError<Arc<Src>, Dst>>
+/// where
+///     Src: ?Sized + KnownLayout + IntoBytes + Immutable,
+///     Dst: ?Sized + KnownLayout + TryFromBytes + Immutable,
+///     align_of::<Src>() == align_of::<Dst>(),
+///     if Src and Dst are both sized, size_of::<Src>() == size_of::<Dst>(),
+/// {
+/// # /*
+///     ...
+/// # */
+/// }
+/// ```
+///
+/// The types `Src` and `Dst` are inferred from the calling context; they cannot
+/// be explicitly specified in the macro invocation.
+///
+/// The allocation is reused; no bytes are copied. `Src` and `Dst` may be
+/// [slice DSTs][slice-dst]. If no `Dst` has the same size as the referent, the
+/// original `Arc<Src>` is returned in a [`SizeError`]. If the referent is not
+/// a valid `Dst`, the original `Arc<Src>` is returned in a [`ValidityError`].
+/// See the [documentation for `transmute_box!`][transmute-box-dsts] for
+/// details on dynamically sized types.
+///
+/// Any other `Arc<Src>`s which share the allocation continue to observe it
+/// as a `Src`. Since `Src` and `Dst` are both `Immutable`, the bytes cannot
+/// change while they are shared.
+///
+/// [slice-dst]: crate::KnownLayout#dynamically-sized-types
+/// [`SizeError`]: crate::SizeError
+/// [`ValidityError`]: crate::ValidityError
+/// [transmute-box-dsts]: crate::transmute_box#dynamically-sized-types
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::*;
+/// # use zerocopy_derive::*;
+/// use std::sync::Arc;
+///
+/// // 1u8 → bool = true
+/// let src = Arc::new(1u8);
+/// assert_eq!(try_transmute_arc!(src), Ok(Arc::new(true)));
+///
+/// // 2u8 → bool = error
+/// let src = Arc::new(2u8);
+/// let err: TryReadError<_, bool> = try_transmute_arc!(src).unwrap_err();
+/// assert_eq!(*err.into_src(), 2);
+///
+/// #[derive(TryFromBytes, KnownLayout, Immutable)]
+/// #[repr(C)]
+/// struct Packet {
+///     is_urgent: bool,
+///     body: [u8],
+/// }
+///
+/// let src: Arc<[u8]> = Arc::from(&[0, 0xC0, 0xFF, 0xEE][..]);
+/// let packet: Arc<Packet> = try_transmute_arc!(src).unwrap();
+/// assert!(!packet.is_urgent);
+/// assert_eq!(packet.body, [0xC0, 0xFF, 0xEE]);
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! try_transmute_arc {
+    ($e:expr) => {{
+        let e: $crate::util::macro_util::Arc<_> = $e;
+        $crate::util::macro_util::try_transmute_arc(e)
+    }};
+}
+
 /// Includes a file and safely transmutes it to a value of an arbitrary type.
 ///
 /// The file will be included as a byte array, `[u8; N]`, which will be
@@ -733,6 +1402,351 @@ macro_rules! include_value {
     };
 }
 
+/// Includes a file and safely casts it to a reference to a slice or slice DST.
+///
+/// The file will be included as a byte array, `[u8; N]`, which will be stored
+/// in a `static` aligned to the alignment of `T`, and will then be cast to a
+/// `&'static T`. Unlike [`include_value!`], `T` may be unsized: it may be a
+/// slice type, `[E]`, or any [slice DST] (a type whose last field is a slice).
+/// `T` must implement [`KnownLayout`], [`FromBytes`], and [`Immutable`], and
+/// must be specified explicitly.
+///
+/// The length of the file must be a valid size for `T`. For `T = [E]`, that
+/// means that it must be a multiple of `size_of::<E>()`. If it is not, then
+/// the invocation will fail to compile.
+///
+/// The file is located relative to the current file (similarly to how modules
+/// are found). The provided path is interpreted in a platform-specific way at
+/// compile time. So, for instance, an invocation with a Windows path containing
+/// backslashes `\` would not compile correctly on Unix.
+///
+/// `include_slice!` is ignorant of byte order. For byte order-aware types, see
+/// the [`byteorder`] module.
+///
+/// [`KnownLayout`]: crate::KnownLayout
+/// [`FromBytes`]: crate::FromBytes
+/// [`Immutable`]: crate::Immutable
+/// [`byteorder`]: crate::byteorder
+/// [slice DST]: crate::KnownLayout#dynamically-sized-types
+///
+/// # Examples
+///
+/// Assume there are two files in the same directory with the following
+/// contents:
+///
+/// File `data` (no trailing newline):
+///
+/// ```text
+/// abcd
+/// ```
+///
+/// File `main.rs`:
+///
+/// ```rust
+/// use zerocopy::{byteorder::big_endian::U16, include_slice};
+/// # use zerocopy_derive::*;
+/// # macro_rules! include_slice {
+/// # ($t:ty, $file:expr) => { zerocopy::include_slice!($t, concat!("../testdata/include_value/", $file)) };
+/// # }
+///
+/// #[derive(KnownLayout, FromBytes, Immutable)]
+/// #[repr(C)]
+/// struct Record {
+///     tag: u8,
+///     body: [u8],
+/// }
+///
+/// fn main() {
+///     let as_u16s: &'static [U16] = include_slice!([U16], "data");
+///     assert_eq!(as_u16s, [U16::new(0x6162), U16::new(0x6364)]);
+///
+///     let record: &'static Record = include_slice!(Record, "data");
+///     assert_eq!(record.tag, b'a');
+///     assert_eq!(&record.body, b"bcd");
+/// }
+/// ```
+///
+/// If the file's length is not a valid size for `T`, the invocation fails to
+/// compile:
+///
+/// ```compile_fail
+/// # use zerocopy::include_slice;
+/// // `data` is 4 bytes long, which is not a multiple of 3.
+/// const NOT_A_MULTIPLE: &[[u8; 3]] = include_slice!([[u8; 3]], "../testdata/include_value/data");
+/// ```
+///
+/// # Use in `const` contexts
+///
+/// This macro can be invoked in `const` contexts.
+#[doc(alias("include_bytes", "include_data", "include_type"))]
+#[macro_export]
+macro_rules! include_slice {
+    ($t:ty, $file:expr $(,)?) => {{
+        use $crate::util::macro_util::{AlignTo, AlignedBytes, ValidAlignment};
+
+        const LEN: usize = ::core::include_bytes!($file).len();
+        const ALIGN: usize = $crate::util::macro_util::align_of_known_layout::<$t>();
+        type Chunk = <AlignTo<ALIGN> as ValidAlignment>::Chunk;
+
+        const DST: &'static $t = {
+            const BYTES: &'static AlignedBytes<Chunk, LEN> =
+                &AlignedBytes::new(*::core::include_bytes!($file));
+            let elems = $crate::util::macro_util::include_slice_elems::<$t>(LEN);
+            let ptr = $crate::util::macro_util::core_reexport::ptr::slice_from_raw_parts(
+                BYTES.as_ptr(),
+                elems,
+            );
+            // This `as` cast preserves the address and the number of trailing
+            // slice elements [1] (if `$t` is sized, it discards the element
+            // count, which `include_slice_elems` guarantees is 0).
+            //
+            // [1] Per https://doc.rust-lang.org/reference/expressions/operator-expr.html#pointer-to-pointer-cast:
+            //
+            //   For slice types like `[T]` and `[U]`, the raw pointer types
+            //   `*const [T]`, `*mut [T]`, `*const [U]`, and `*mut [U]` encode
+            //   the number of elements in this slice. Casts between these raw
+            //   pointer types preserve the number of elements. ... The same
+            //   holds for `str` and any compound type whose unsized tail is a
+            //   slice type, such as `struct Foo(i32, [u8])` or `(u64, Foo)`.
+            //
+            // Clippy: `elems` is already the element count of `$t`'s trailing
+            // slice, not of a `[u8]`.
+            #[allow(clippy::as_conversions, clippy::cast_slice_different_sizes)]
+            let ptr = ptr as *const $t;
+            // SAFETY:
+            // - `ptr` is aligned to `align_of::<Chunk>()`, which is equal to
+            //   `$t`'s alignment because `Chunk` is the `ValidAlignment::Chunk`
+            //   type for `AlignTo<ALIGN>`.
+            // - `include_slice_elems` guarantees that a `$t` with `elems`
+            //   trailing slice elements is exactly `LEN` bytes long, so `ptr`
+            //   refers to exactly the bytes of `BYTES`, all of which are
+            //   initialized.
+            // - `$t: FromBytes`, so any initialized bytes are a valid `$t`.
+            // - `$t: Immutable`, and `BYTES` is immutable, so there is no
+            //   interior mutability to worry about.
+            // - `BYTES` lives for `'static`.
+            unsafe { &*ptr }
+        };
+        DST
+    }};
+}
+
+/// Projects a [`Maybe`] into one of its referent's fields.
+///
+/// Given `candidate: Maybe<'_, T>`, where `T` is a struct named `Type`,
+/// `project!(candidate, Type, field)` evaluates to a `Maybe<'_, F>` which
+/// references `candidate`'s `field: F`. Tuple struct fields are named by their
+/// index, as in `project!(candidate, Type, 0)`. `candidate` must be a mutable
+/// binding; it is reborrowed rather than consumed, so that each field can be
+/// projected in turn.
+///
+/// This is intended for use in hand-written [`TryFromBytes`] impls (see
+/// [`unsafe_impl_items!`]). The projected field may be checked with
+/// [`Maybe::is_bit_valid`] or, if it is [`FromBytes`], read with
+/// [`Maybe::read_unaligned`].
+///
+/// `Type` must name a struct (possibly `repr(packed)`, and possibly with an
+/// unsized trailing field) which has `field` as one of its own fields. Unions,
+/// enums, and fields which are only reachable through a [`Deref`] impl are
+/// rejected at compile time. Type arguments must be omitted from `Type`; they
+/// are inferred from `candidate`.
+///
+/// [`Maybe`]: crate::Maybe
+/// [`Maybe::is_bit_valid`]: crate::Maybe::is_bit_valid
+/// [`Maybe::read_unaligned`]: crate::Maybe::read_unaligned
+/// [`TryFromBytes`]: crate::TryFromBytes
+/// [`unsafe_impl_items!`]: crate::unsafe_impl_items
+/// [`FromBytes`]: crate::FromBytes
+/// [`Deref`]: core::ops::Deref
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::{project, unsafe_impl_items, Maybe, TryFromBytes};
+/// # use zerocopy_derive::*;
+///
+/// #[derive(KnownLayout, Immutable)]
+/// #[repr(C)]
+/// struct Percent {
+///     value: u8,
+/// }
+///
+/// // SAFETY: `is_bit_valid` only returns `true` if `value <= 100`, and all
+/// // such values are valid `Percent`s.
+/// unsafe impl TryFromBytes for Percent {
+///     unsafe_impl_items!(|candidate| {
+///         let value: u8 = project!(candidate, Percent, value).read_unaligned();
+///         value <= 100
+///     });
+/// }
+///
+/// assert!(Percent::try_ref_from_bytes(&[100]).is_ok());
+/// assert!(Percent::try_ref_from_bytes(&[101]).is_err());
+/// ```
+///
+/// Only a type's own fields may be projected, and not those reachable through
+/// [`Deref`]:
+///
+/// ```compile_fail,E0026
+/// use core::ops::Deref;
+/// use zerocopy::{project, unsafe_impl_items, TryFromBytes};
+/// # use zerocopy_derive::*;
+///
+/// struct Inner {
+///     value: u8,
+/// }
+///
+/// #[derive(KnownLayout, Immutable)]
+/// #[repr(C)]
+/// struct Outer {
+///     ptr: &'static Inner,
+/// }
+///
+/// impl Deref for Outer {
+///     type Target = Inner;
+///
+///     fn deref(&self) -> &Inner {
+///         self.ptr
+///     }
+/// }
+///
+/// unsafe impl TryFromBytes for Outer {
+///     unsafe_impl_items!(|candidate| {
+///         project!(candidate, Outer, value).is_bit_valid()
+///     });
+/// }
+/// ```
+#[macro_export]
+macro_rules! project {
+    ($candidate:ident, $($ty:ident)::+, $field:tt $(,)?) => {{
+        let candidate = $crate::Ptr::reborrow(&mut $candidate);
+        // SAFETY: The projection below only type-checks if `$field` is a field
+        // of the struct `$ty` itself; the pattern in the `if false` block
+        // rejects fields which are only reachable by auto-deref through a
+        // `Deref` impl (which `addr_of_mut!` would otherwise silently invoke),
+        // as well as unions and enums. Thus, `addr_of_mut!((*slf).$field)`
+        // does not dereference `slf`, and it:
+        // - addresses a subset of the bytes addressed by `slf`, since a
+        //   struct's fields are contained within the struct
+        // - has the same provenance as `slf`, since `addr_of_mut!` preserves
+        //   provenance
+        // - contains `UnsafeCell`s at exactly the byte ranges at which `*slf`
+        //   does within those bytes, since a struct contains no `UnsafeCell`s
+        //   other than those of its fields
+        unsafe {
+            candidate.project(|slf| {
+                if false {
+                    let $($ty)::+ { $field: _, .. } = &*slf;
+                }
+                $crate::util::macro_util::core_reexport::ptr::addr_of_mut!((*slf).$field)
+            })
+        }
+    }};
+}
+
+/// Provides the items of a hand-written `unsafe impl` of one of zerocopy's
+/// traits.
+///
+/// Zerocopy's traits are normally implemented via their derives, which check
+/// that the implementing type satisfies each trait's safety requirements.
+/// Where a derive cannot be used (e.g., for types generated by other macros,
+/// or for FFI wrappers whose invariants the derive cannot see), the traits
+/// [`Immutable`], [`TryFromBytes`], [`FromZeros`], [`FromBytes`],
+/// [`IntoBytes`], and [`Unaligned`] may instead be implemented by hand, using
+/// this macro to generate the body of the `unsafe impl`. [`KnownLayout`] must
+/// still be derived.
+///
+/// For all traits other than `TryFromBytes`, invoke this macro with no
+/// arguments; it expands to the trait's marker method,
+/// `only_derive_is_allowed_to_implement_this_trait`. For `TryFromBytes`, pass
+/// it a closure-like validity check, `|candidate| body`, which it expands to an
+/// implementation of [`TryFromBytes::is_bit_valid`]. `candidate` is a
+/// [`Maybe<Self, A>`][Maybe] for some `A:` [`Reference`], and `body` must
+/// evaluate to `true` only if `candidate` references a bit-valid `Self`.
+/// Fields of `candidate` can be projected with [`project!`] and then checked
+/// with [`Maybe::is_bit_valid`] or read with [`Maybe::read_unaligned`].
+///
+/// # Safety
+///
+/// This macro does not check anything. Each `unsafe impl` must uphold the
+/// requirements described in the "Safety" section of the trait it implements;
+/// in particular, a `TryFromBytes` validity check may only return `true` if
+/// `candidate` references a bit-valid `Self`, and it must not assume that
+/// `candidate` is aligned.
+///
+/// [`Immutable`]: crate::Immutable
+/// [`TryFromBytes`]: crate::TryFromBytes
+/// [`FromZeros`]: crate::FromZeros
+/// [`FromBytes`]: crate::FromBytes
+/// [`IntoBytes`]: crate::IntoBytes
+/// [`Unaligned`]: crate::Unaligned
+/// [`KnownLayout`]: crate::KnownLayout
+/// [`TryFromBytes::is_bit_valid`]: crate::TryFromBytes::is_bit_valid
+/// [Maybe]: crate::Maybe
+/// [`Reference`]: crate::pointer::invariant::Reference
+/// [`Maybe::is_bit_valid`]: crate::Maybe::is_bit_valid
+/// [`Maybe::read_unaligned`]: crate::Maybe::read_unaligned
+///
+/// # Examples
+///
+/// ```
+/// use zerocopy::{project, unsafe_impl_items, FromZeros, Immutable, TryFromBytes};
+/// # use zerocopy_derive::*;
+///
+/// /// A handle which a C library promises is never negative.
+/// #[derive(KnownLayout)]
+/// #[repr(C)]
+/// struct Handle {
+///     kind: bool,
+///     raw: i32,
+/// }
+///
+/// // SAFETY: `Handle` has no `UnsafeCell`s.
+/// unsafe impl Immutable for Handle {
+///     unsafe_impl_items!();
+/// }
+///
+/// // SAFETY: `is_bit_valid` only returns `true` if `kind` is a valid `bool`
+/// // and `raw` is non-negative, which is exactly the set of valid `Handle`s.
+/// unsafe impl TryFromBytes for Handle {
+///     unsafe_impl_items!(|candidate| {
+///         project!(candidate, Handle, kind).is_bit_valid()
+///             && project!(candidate, Handle, raw).read_unaligned() >= 0
+///     });
+/// }
+///
+/// // SAFETY: An all-zeros `Handle` has `kind: false` and `raw: 0`, which is
+/// // valid.
+/// unsafe impl FromZeros for Handle {
+///     unsafe_impl_items!();
+/// }
+///
+/// assert!(Handle::try_read_from_bytes(&[1, 0, 0, 0, 7, 0, 0, 0]).is_ok());
+/// assert!(Handle::try_read_from_bytes(&[2, 0, 0, 0, 7, 0, 0, 0]).is_err());
+/// assert!(Handle::try_read_from_bytes(&[1, 0, 0, 0, 0, 0, 0, 0x80]).is_err());
+/// assert_eq!(Handle::new_zeroed().raw, 0);
+/// ```
+#[macro_export]
+macro_rules! unsafe_impl_items {
+    () => {
+        #[allow(clippy::missing_inline_in_public_items)]
+        fn only_derive_is_allowed_to_implement_this_trait() {}
+    };
+    (|$candidate:ident| $is_bit_valid:expr) => {
+        $crate::unsafe_impl_items!();
+
+        #[inline]
+        fn is_bit_valid<___ZerocopyAliasing>(
+            #[allow(unused_mut)] mut $candidate: $crate::Maybe<'_, Self, ___ZerocopyAliasing>,
+        ) -> bool
+        where
+            ___ZerocopyAliasing: $crate::pointer::invariant::Reference,
+        {
+            $is_bit_valid
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::util::testutil::*;
@@ -949,6 +1963,275 @@ mod tests {
         assert_eq!(*y, 0);
     }
 
+    #[test]
+    fn test_transmute_slice() {
+        // Test that memory is transmuted as expected, and that the length is
+        // rescaled.
+        let array_of_u8s = [0u8, 1, 2, 3, 4, 5, 6, 7];
+        let array_of_arrays = [[0, 1], [2, 3], [4, 5], [6, 7]];
+        let x: &[u8] = transmute_slice!(&array_of_arrays[..]);
+        assert_eq!(x, array_of_u8s);
+        let x: &[[u8; 2]] = transmute_slice!(&array_of_arrays[..]);
+        assert_eq!(x, array_of_arrays);
+        let x: &[u8] = transmute_slice!(&array_of_arrays[..0]);
+        assert!(x.is_empty());
+
+        // Test that `transmute_slice!` is legal in a const context.
+        const ARRAY_OF_ARRAYS: &[[u8; 2]] = &[[0, 1], [2, 3], [4, 5], [6, 7]];
+        const X: &[u8] = transmute_slice!(ARRAY_OF_ARRAYS);
+        assert_eq!(X, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        // Test that `transmute_slice!` supports decreasing alignment.
+        let u = [AU64(0), AU64(u64::MAX)];
+        let x: &[[u8; 4]] = transmute_slice!(&u[..]);
+        assert_eq!(x, [[0; 4], [0; 4], [0xFF; 4], [0xFF; 4]]);
+
+        // Test that a mutable reference can be turned into an immutable one.
+        let mut x = [0u16; 2];
+        let y: &[u8] = transmute_slice!(&mut x[..]);
+        assert_eq!(y, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_transmute_slice_mut() {
+        let mut array_of_arrays = [[0u8, 1], [2, 3], [4, 5], [6, 7]];
+        let x: &mut [u8] = transmute_slice_mut!(&mut array_of_arrays[..]);
+        assert_eq!(x, [0, 1, 2, 3, 4, 5, 6, 7]);
+        x.reverse();
+        assert_eq!(array_of_arrays, [[7, 6], [5, 4], [3, 2], [1, 0]]);
+
+        // Test that `transmute_slice_mut!` supports decreasing alignment.
+        let mut u = [AU64(0), AU64(0)];
+        let x: &mut [u32] = transmute_slice_mut!(&mut u[..]);
+        assert_eq!(x.len(), 4);
+        x[3] = u32::MAX;
+        assert_eq!(u[1].0.to_ne_bytes()[4..], [0xFF; 4]);
+    }
+
+    #[test]
+    fn test_try_transmute_slice() {
+        let src: &[u8] = &[0, 1, 0];
+        let dst: Result<&[bool], _> = try_transmute_slice!(src);
+        assert_eq!(dst, Ok(&[false, true, false][..]));
+
+        let src: &[u16] = &[0, 1];
+        let dst: Result<&[bool], _> = try_transmute_slice!(src);
+        assert_eq!(dst.unwrap().len(), 4);
+
+        let src: &[u8] = &[0, 1, 2];
+        let dst: Result<&[bool], _> = try_transmute_slice!(src);
+        assert_eq!(dst.unwrap_err().into_src(), src);
+
+        let mut src = [0u8, 1];
+        let dst: Result<&mut [bool], _> = try_transmute_slice_mut!(&mut src[..]);
+        let dst = dst.unwrap();
+        dst[1] = false;
+        assert_eq!(src, [0, 0]);
+
+        let mut src = [0u8, 2];
+        let dst: Result<&mut [bool], _> = try_transmute_slice_mut!(&mut src[..]);
+        assert_eq!(dst.unwrap_err().into_src(), &mut [0u8, 2][..]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_transmute_box_rc_arc() {
+        use alloc::{boxed::Box, rc::Rc};
+
+        let array_of_u8s = [0u8, 1, 2, 3, 4, 5, 6, 7];
+        let array_of_arrays = [[0, 1], [2, 3], [4, 5], [6, 7]];
+
+        let x: Box<[[u8; 2]; 4]> = transmute_box!(Box::new(array_of_u8s));
+        assert_eq!(*x, array_of_arrays);
+        let x: Box<[u8; 8]> = transmute_box!(x);
+        assert_eq!(*x, array_of_u8s);
+
+        let x: Rc<[[u8; 2]; 4]> = transmute_rc!(Rc::new(array_of_u8s));
+        assert_eq!(*x, array_of_arrays);
+        // Test that other `Rc`s which share the allocation are unaffected.
+        let y = Rc::clone(&x);
+        let x: Rc<[u8; 8]> = transmute_rc!(x);
+        assert_eq!(*x, array_of_u8s);
+        assert_eq!(*y, array_of_arrays);
+        assert_eq!(Rc::strong_count(&x), 2);
+        drop(y);
+        assert_eq!(Rc::strong_count(&x), 1);
+
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            use alloc::sync::Arc;
+
+            let x: Arc<[[u8; 2]; 4]> = transmute_arc!(Arc::new(array_of_u8s));
+            assert_eq!(*x, array_of_arrays);
+            let y = Arc::clone(&x);
+            let x: Arc<[u8; 8]> = transmute_arc!(x);
+            assert_eq!(*x, array_of_u8s);
+            assert_eq!(*y, array_of_arrays);
+            assert_eq!(Arc::strong_count(&x), 2);
+        }
+
+        // Test that `!Immutable` types can be transmuted in a `Box`.
+        let x: Box<usize> = transmute_box!(Box::new(UnsafeCell::new(1usize)));
+        assert_eq!(*x, 1);
+        let x: Box<AU64> = transmute_box!(Box::new(2u64));
+        assert_eq!(x.0, 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_transmute_box_rc_arc() {
+        use alloc::{boxed::Box, rc::Rc};
+
+        let x: Result<Box<[bool; 2]>, _> = try_transmute_box!(Box::new([0u8, 1]));
+        assert_eq!(x, Ok(Box::new([false, true])));
+        let x: Result<Box<[bool; 2]>, _> = try_transmute_box!(Box::new([0u8, 2]));
+        assert_eq!(*x.unwrap_err().into_src(), [0u8, 2]);
+
+        let x: Result<Rc<[bool; 2]>, _> = try_transmute_rc!(Rc::new([1u8, 0]));
+        assert_eq!(x, Ok(Rc::new([true, false])));
+        let src = Rc::new([2u8, 0]);
+        let x: Result<Rc<[bool; 2]>, _> = try_transmute_rc!(Rc::clone(&src));
+        let x = x.unwrap_err().into_src();
+        assert!(Rc::ptr_eq(&x, &src));
+
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            use alloc::sync::Arc;
+
+            let x: Result<Arc<[bool; 2]>, _> = try_transmute_arc!(Arc::new([1u8, 1]));
+            assert_eq!(x, Ok(Arc::new([true, true])));
+            let src = Arc::new([1u8, 3]);
+            let x: Result<Arc<[bool; 2]>, _> = try_transmute_arc!(Arc::clone(&src));
+            let x = x.unwrap_err().into_src();
+            assert!(Arc::ptr_eq(&x, &src));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_transmute_box_rc_arc_dst() {
+        use alloc::{boxed::Box, rc::Rc, vec};
+
+        use crate::byteorder::{BigEndian, U16};
+
+        // Slice DSTs whose trailing slices begin at the same offset, where the
+        // destination element size divides the source element size.
+        let x: Box<[[u8; 2]]> = vec![[0, 1], [2, 3], [4, 5]].into_boxed_slice();
+        let x: Box<[u8]> = transmute_box!(x);
+        assert_eq!(*x, [0, 1, 2, 3, 4, 5]);
+
+        let x: Box<[[u8; 2]]> = Box::new([]);
+        let x: Box<[u8]> = transmute_box!(x);
+        assert_eq!(x.len(), 0);
+
+        #[derive(KnownLayout, FromBytes, IntoBytes, Immutable, Unaligned)]
+        #[repr(C, packed)]
+        struct Words {
+            len: U16<BigEndian>,
+            words: [U16<BigEndian>],
+        }
+
+        #[derive(KnownLayout, FromBytes, IntoBytes, Immutable, Unaligned)]
+        #[repr(C, packed)]
+        struct Bytes {
+            len: [u8; 2],
+            bytes: [u8],
+        }
+
+        let x: Rc<Words> = transmute_rc!(Rc::new([0u8, 1, 2, 3]));
+        assert_eq!(x.len.get(), 1);
+        assert_eq!(x.words, [U16::new(0x0203)]);
+        let x: Rc<Bytes> = transmute_rc!(x);
+        assert_eq!(x.len, [0, 1]);
+        assert_eq!(x.bytes, [2, 3]);
+
+        // Sized to slice DST.
+        let x: Box<[u8]> = transmute_box!(Box::new([0u8, 1, 2, 3]));
+        assert_eq!(*x, [0, 1, 2, 3]);
+        let x: Box<Words> = transmute_box!(Box::new([0u8, 1, 2, 3]));
+        assert_eq!(x.words, [U16::new(0x0203)]);
+
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            use alloc::sync::Arc;
+
+            let x: Arc<[U16<BigEndian>]> = Arc::from(&[U16::new(1), U16::new(2)][..]);
+            let y = Arc::clone(&x);
+            let x: Arc<[u8]> = transmute_arc!(x);
+            assert_eq!(*x, [0, 1, 0, 2]);
+            assert_eq!(*y, [U16::new(1), U16::new(2)]);
+            assert_eq!(Arc::strong_count(&x), 2);
+        }
+
+        // Test that the allocation is freed with the layout with which it was
+        // allocated when the alignment of `Dst` exceeds its element size.
+        #[derive(KnownLayout, FromBytes, Immutable)]
+        #[repr(C, align(4))]
+        struct Aligned {
+            bytes: [u8],
+        }
+
+        let x: Box<[u32]> = vec![1, 2, 3].into_boxed_slice();
+        let x: Box<Aligned> = transmute_box!(x);
+        assert_eq!(x.bytes.len(), 12);
+        assert_eq!(x.bytes[..4], 1u32.to_ne_bytes());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_transmute_box_rc_arc_dst() {
+        use alloc::{boxed::Box, rc::Rc, vec};
+
+        #[derive(TryFromBytes, KnownLayout, Immutable, PartialEq, Debug)]
+        #[repr(C)]
+        struct Packet {
+            is_urgent: bool,
+            body: [u8],
+        }
+
+        let x: Box<[u8]> = vec![1, 2, 3].into_boxed_slice();
+        let x: Box<Packet> = try_transmute_box!(x).unwrap();
+        assert!(x.is_urgent);
+        assert_eq!(x.body, [2, 3]);
+
+        let x: Box<[u8]> = vec![2, 2, 3].into_boxed_slice();
+        let x: Result<Box<Packet>, _> = try_transmute_box!(x);
+        assert!(matches!(x, Err(ConvertError::Validity(_))));
+        assert_eq!(*x.unwrap_err().into_src(), [2, 2, 3]);
+
+        // Too short for the header.
+        let src: Rc<[u8]> = Rc::from(&[][..]);
+        let x: Result<Rc<Packet>, _> = try_transmute_rc!(Rc::clone(&src));
+        assert!(matches!(x, Err(ConvertError::Size(_))));
+        assert!(Rc::ptr_eq(&x.unwrap_err().into_src(), &src));
+
+        // No `[u16]` has an odd number of bytes.
+        let src: Rc<[u8]> = Rc::from(&[0, 1, 2][..]);
+        let x: Result<Rc<[[u8; 2]]>, _> = try_transmute_rc!(Rc::clone(&src));
+        assert!(matches!(x, Err(ConvertError::Size(_))));
+        let x: Result<Rc<[[u8; 3]]>, _> = try_transmute_rc!(Rc::clone(&src));
+        assert_eq!(*x.unwrap(), [[0, 1, 2]]);
+
+        // Slice DST to sized.
+        let x: Box<[u8]> = vec![0, 1].into_boxed_slice();
+        let x: Result<Box<[bool; 2]>, _> = try_transmute_box!(x);
+        assert_eq!(x, Ok(Box::new([false, true])));
+        let x: Box<[u8]> = vec![0, 1, 0].into_boxed_slice();
+        let x: Result<Box<[bool; 2]>, _> = try_transmute_box!(x);
+        assert_eq!(*x.unwrap_err().into_src(), [0, 1, 0]);
+
+        #[cfg(target_has_atomic = "ptr")]
+        {
+            use alloc::sync::Arc;
+
+            let src: Arc<[u8]> = Arc::from(&[0, 4, 5][..]);
+            let x: Arc<Packet> = try_transmute_arc!(Arc::clone(&src)).unwrap();
+            assert!(!x.is_urgent);
+            assert_eq!(x.body, [4, 5]);
+            assert_eq!(Arc::strong_count(&src), 2);
+        }
+    }
+
     #[test]
     fn test_macros_evaluate_args_once() {
         let mut ctr = 0;
@@ -990,4 +2273,85 @@ mod tests {
         const AS_I32: i32 = include_value!("../testdata/include_value/data");
         assert_eq!(AS_I32, i32::from_ne_bytes([b'a', b'b', b'c', b'd']));
     }
+
+    #[test]
+    fn test_include_slice() {
+        const AS_U8S: &[u8] = include_slice!([u8], "../testdata/include_value/data");
+        assert_eq!(AS_U8S, b"abcd");
+        const AS_U16S: &[u16] = include_slice!([u16], "../testdata/include_value/data");
+        assert_eq!(AS_U16S, [u16::from_ne_bytes([b'a', b'b']), u16::from_ne_bytes([b'c', b'd'])]);
+        assert_eq!(util::AsAddress::addr(AS_U16S) % mem::align_of::<u16>(), 0);
+        const AS_U32: &u32 = include_slice!(u32, "../testdata/include_value/data");
+        assert_eq!(*AS_U32, u32::from_ne_bytes([b'a', b'b', b'c', b'd']));
+
+        #[derive(KnownLayout, FromBytes, Immutable)]
+        #[repr(C, align(4))]
+        struct Dst {
+            header: [u8; 3],
+            trailing: [u8],
+        }
+
+        let dst: &'static Dst = include_slice!(Dst, "../testdata/include_value/data");
+        assert_eq!(&dst.header, b"abc");
+        assert_eq!(&dst.trailing, b"d");
+        assert_eq!(util::AsAddress::addr(dst) % 4, 0);
+
+        // Test that over-aligned types are stored at an aligned address.
+        let aligned: &'static [AU64] = include_slice!([AU64], "../testdata/include_value/data8");
+        assert_eq!(aligned, [AU64(u64::from_ne_bytes(*b"abcdefgh"))]);
+        assert_eq!(util::AsAddress::addr(aligned) % mem::align_of::<AU64>(), 0);
+    }
+
+    #[test]
+    fn test_project() {
+        // A tuple struct with a generic field, a trailing slice, and a
+        // validity constraint which spans two fields.
+        #[derive(KnownLayout, Immutable)]
+        #[repr(C)]
+        struct Range<T>(T, u8, [bool]);
+
+        // SAFETY: Checks that each field is valid, and that `.0 <= .1`.
+        unsafe impl<T: TryFromBytes + FromBytes + Immutable + Copy + Into<u16>> TryFromBytes for Range<T> {
+            unsafe_impl_items!(|candidate| {
+                let lo: T = project!(candidate, Range, 0).read_unaligned();
+                let hi: u8 = project!(candidate, Range, 1).read_unaligned();
+                lo.into() <= u16::from(hi) && project!(candidate, Range, 2).is_bit_valid()
+            });
+        }
+
+        let r = Range::<u8>::try_ref_from_bytes(&[1, 2, 0, 1][..]).unwrap();
+        assert_eq!((r.0, r.1, &r.2), (1, 2, &[false, true][..]));
+        assert!(Range::<u8>::try_ref_from_bytes(&[3, 2][..]).is_err());
+        assert!(Range::<u8>::try_ref_from_bytes(&[1, 2, 2][..]).is_err());
+
+        // A packed struct with an under-aligned field, and with a field which
+        // is not `Immutable`, which can only be checked through an exclusive
+        // reference.
+        #[derive(KnownLayout)]
+        #[repr(C, packed)]
+        struct Packed {
+            flag: bool,
+            value: u32,
+            cell: UnsafeCell<[u8; 2]>,
+        }
+
+        // SAFETY: Checks that `flag` is valid and that `value` is less than
+        // 256; all bit patterns are valid for `value` and `cell`.
+        unsafe impl TryFromBytes for Packed {
+            unsafe_impl_items!(|candidate| {
+                let value: u32 = project!(candidate, Packed, value).read_unaligned();
+                project!(candidate, Packed, flag).is_bit_valid() && value < 256
+            });
+        }
+
+        let mut bytes = [1, 0, 0, 0, 0, 2, 3];
+        bytes[1..5].copy_from_slice(&255u32.to_ne_bytes());
+        let p = Packed::try_mut_from_bytes(&mut bytes[..]).unwrap();
+        assert!(p.flag);
+        assert_eq!({ p.value }, 255);
+        assert_eq!(p.cell.get_mut(), &[2, 3]);
+        bytes[1..5].copy_from_slice(&256u32.to_ne_bytes());
+        assert!(Packed::try_mut_from_bytes(&mut bytes[..]).is_err());
+        assert!(Packed::try_mut_from_bytes(&mut [2, 0, 0, 0, 0, 0, 0][..]).is_err());
+    }
 }
@@ -15,8 +15,11 @@
 
 /// The invariants of a [`Ptr`][super::Ptr].
 pub trait Invariants: Sealed {
+    /// The aliasing invariant.
     type Aliasing: Aliasing;
+    /// The alignment invariant.
     type Alignment: Alignment;
+    /// The validity invariant.
     type Validity: Validity;
 
     /// Invariants identical to `Self` except with a different aliasing
@@ -253,6 +256,7 @@ mod sealed {
     impl<A: Sealed, AA: Sealed, V: Sealed> Sealed for (A, AA, V) {}
 }
 
+#[doc(hidden)]
 pub use mapping::*;
 mod mapping {
     use super::*;
@@ -268,6 +272,7 @@ mod mapping {
     ///
     /// Mappings are used by [`Ptr`](crate::Ptr) conversion methods to preserve
     /// or modify invariants as required by each method's semantics.
+    #[doc(hidden)]
     pub trait AliasingMapping {
         type FromInaccessible: Aliasing;
         type FromShared: Aliasing;
@@ -285,6 +290,7 @@ mod mapping {
     ///
     /// Mappings are used by [`Ptr`](crate::Ptr) conversion methods to preserve
     /// or modify invariants as required by each method's semantics.
+    #[doc(hidden)]
     pub trait AlignmentMapping {
         type FromUnknown: Alignment;
         type FromAligned: Alignment;
@@ -301,6 +307,7 @@ mod mapping {
     ///
     /// Mappings are used by [`Ptr`](crate::Ptr) conversion methods to preserve
     /// or modify invariants as required by each method's semantics.
+    #[doc(hidden)]
     pub trait ValidityMapping {
         type FromUnknown: Validity;
         type FromAsInitialized: Validity;
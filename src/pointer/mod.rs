@@ -9,21 +9,33 @@
 //! Abstractions over raw pointers.
 
 mod inner;
-#[doc(hidden)]
 pub mod invariant;
 mod ptr;
 
 #[doc(hidden)]
 pub use invariant::{BecauseExclusive, BecauseImmutable, Read};
-#[doc(hidden)]
 pub use ptr::Ptr;
 
-use crate::Unaligned;
+use crate::{FromBytes, TryFromBytes, Unaligned};
 
 /// A shorthand for a maybe-valid, maybe-aligned reference. Used as the argument
 /// to [`TryFromBytes::is_bit_valid`].
 ///
+/// A `Maybe<'a, T, Aliasing>` references as many bytes as a `T` would occupy,
+/// all of which are initialized and live for at least `'a`. It permits
+/// accessing those bytes as described by `Aliasing` (either [`Shared`] or
+/// [`Exclusive`]). Its referent may not be aligned for `T`, and may not be a
+/// bit-valid `T`.
+///
+/// Safe code may only inspect a `Maybe` by projecting its fields with
+/// [`project!`], delegating to a field's validity check with
+/// [`Maybe::is_bit_valid`], or reading a [`FromBytes`] field with
+/// [`Maybe::read_unaligned`].
+///
 /// [`TryFromBytes::is_bit_valid`]: crate::TryFromBytes::is_bit_valid
+/// [`Shared`]: invariant::Shared
+/// [`Exclusive`]: invariant::Exclusive
+/// [`project!`]: crate::project
 pub type Maybe<'a, T, Aliasing = invariant::Shared, Alignment = invariant::Unknown> =
     Ptr<'a, T, (Aliasing, Alignment, invariant::Initialized)>;
 
@@ -76,6 +88,56 @@ where
     }
 }
 
+// These methods are defined on the type alias, `Maybe`, so as to bring them to
+// the forefront of the rendered rustdoc for that type alias. Together with
+// [`project!`], they are the supported means of writing a hand-written
+// [`TryFromBytes::is_bit_valid`] (see [`unsafe_impl_items!`]).
+//
+// [`project!`]: crate::project
+// [`unsafe_impl_items!`]: crate::unsafe_impl_items
+impl<'a, T, Aliasing, Alignment> Maybe<'a, T, Aliasing, Alignment>
+where
+    T: 'a + ?Sized,
+    Aliasing: invariant::Reference,
+    Alignment: invariant::Alignment,
+{
+    /// Checks whether the referent is a bit-valid `T`.
+    ///
+    /// This delegates to `T`'s [`TryFromBytes`] implementation, and is
+    /// typically called on a field projected with [`project!`].
+    ///
+    /// [`project!`]: crate::project
+    #[must_use]
+    #[inline]
+    pub fn is_bit_valid(self) -> bool
+    where
+        T: TryFromBytes,
+    {
+        T::is_bit_valid(self.forget_aligned())
+    }
+}
+
+impl<'a, T, Aliasing, Alignment> Maybe<'a, T, Aliasing, Alignment>
+where
+    T: 'a,
+    Aliasing: invariant::Aliasing,
+    Alignment: invariant::Alignment,
+{
+    /// Reads the value from `Maybe`.
+    ///
+    /// This is only available if `T` is [`FromBytes`], in which case every
+    /// initialized byte sequence is a valid `T`.
+    #[must_use]
+    #[inline]
+    pub fn read_unaligned<R>(self) -> T
+    where
+        T: FromBytes + Copy,
+        T: invariant::Read<Aliasing, R>,
+    {
+        self.bikeshed_recall_valid().read_unaligned::<R>()
+    }
+}
+
 /// Checks if the referent is zeroed.
 pub(crate) fn is_zeroed<T, I>(ptr: Ptr<'_, T, I>) -> bool
 where
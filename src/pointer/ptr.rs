@@ -40,6 +40,10 @@ mod def {
     ///
     /// `Ptr<'a, T>` is [covariant] in `'a` and `T`.
     ///
+    /// Code outside of zerocopy does not construct `Ptr`s directly; it receives
+    /// one as the [`Maybe`](crate::Maybe) argument to
+    /// [`TryFromBytes::is_bit_valid`](crate::TryFromBytes::is_bit_valid).
+    ///
     /// [covariant]: https://doc.rust-lang.org/reference/subtyping.html
     pub struct Ptr<'a, T, I>
     where
@@ -273,7 +277,6 @@ mod _conversions {
         /// Since `self` is borrowed immutably, this prevents any mutable
         /// methods from being called on `self` as long as the returned `Ptr`
         /// exists.
-        #[doc(hidden)]
         #[inline]
         #[allow(clippy::needless_lifetimes)] // Allows us to name the lifetime in the safety comment below.
         pub fn reborrow<'b>(&'b mut self) -> Ptr<'b, T, I>
@@ -667,7 +670,9 @@ mod _transitions {
                 // contains a bit-valid instance of `Self`.
                 Ok(unsafe { self.assume_valid() })
             } else {
-                Err(ValidityError::new(self))
+                let mismatched_constant =
+                    T::mismatched_constant(self.reborrow().forget_aligned().unify_invariants());
+                Err(ValidityError::new(self).with_mismatched_constant(mismatched_constant))
             }
         }
 
@@ -1066,11 +1071,17 @@ mod _project {
     {
         /// Projects a field from `self`.
         ///
+        /// Prefer [`project!`](crate::project), which checks these safety
+        /// preconditions for struct fields.
+        ///
         /// # Safety
         ///
-        /// `project` has the same safety preconditions as
-        /// `cast_unsized_unchecked`.
-        #[doc(hidden)]
+        /// The caller promises that `u = projector(p)` is a pointer cast with
+        /// the following properties:
+        /// - `u` addresses a subset of the bytes addressed by `p`
+        /// - `u` has the same provenance as `p`
+        /// - If `I::Aliasing` is [`Shared`], `UnsafeCell`s in `*u` must exist
+        ///   at ranges identical to those at which `UnsafeCell`s exist in `*p`
         #[inline]
         pub unsafe fn project<U: 'a + ?Sized>(
             self,
@@ -1314,7 +1325,8 @@ mod tests {
                 let ptr = Ptr::from_ref(&bytes[..]);
                 let res =
                     ptr.try_cast_into::<$ty, BecauseImmutable>(CastType::Prefix, Some($elems));
-                if let Some(expect) = $expect {
+                let expect: Option<usize> = $expect;
+                if let Some(expect) = expect {
                     let (ptr, _) = res.unwrap();
                     assert_eq!(
                         KnownLayout::pointer_to_metadata(ptr.as_inner().as_non_null().as_ptr()),
@@ -681,6 +681,26 @@ where
     }
 }
 
+impl<B, T> Ref<B, T>
+where
+    B: ByteSlice,
+    T: ?Sized + DescribeLayout,
+{
+    /// Gets an annotated hex dump of the underlying bytes.
+    ///
+    /// The bytes are annotated according to `T`'s [`TypeLayout`]. See
+    /// [`HexDump`] for details.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Ref::hex_dump(r)` instead of `r.hex_dump()`. This is so that
+    /// there is no conflict with a method on the inner type.
+    #[must_use = "has no side effects"]
+    #[inline]
+    pub fn hex_dump(r: &Self) -> HexDump<'_> {
+        HexDump::from_bytes(Ref::bytes(r), &T::TYPE_LAYOUT)
+    }
+}
+
 impl<B, T> Ref<B, T>
 where
     B: ByteSliceMut,
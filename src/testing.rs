@@ -0,0 +1,500 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! Utilities for testing [`TryFromBytes`] and [`IntoBytes`] impls.
+//!
+//! These functions are intended to be called from unit tests. They check a
+//! type's validity check against byte patterns - either supplied by the caller
+//! or generated exhaustively or pseudo-randomly - and panic with a descriptive
+//! message if:
+//! - [`try_read_from_bytes`], [`try_ref_from_bytes`], and
+//!   [`try_mut_from_bytes`] disagree about whether a byte pattern is valid
+//! - a value read from a byte pattern does not encode back to that same byte
+//!   pattern
+//! - a byte pattern is accepted or rejected contrary to the caller's
+//!   expectations
+//!
+//! All functions are deterministic: the pseudo-random byte patterns are
+//! derived from a caller-supplied seed, so any failure can be reproduced.
+//!
+//! Note that these checks can only observe the *result* of a validity check.
+//! A validity check which accepts a byte pattern that is not, in fact, a valid
+//! instance of its type causes undefined behavior as soon as the resulting
+//! value is used; to catch such bugs reliably, run these tests under [Miri].
+//!
+//! [`try_read_from_bytes`]: TryFromBytes::try_read_from_bytes
+//! [`try_ref_from_bytes`]: TryFromBytes::try_ref_from_bytes
+//! [`try_mut_from_bytes`]: TryFromBytes::try_mut_from_bytes
+//! [Miri]: https://github.com/rust-lang/miri
+//!
+//! # Examples
+//!
+//! ```
+//! use zerocopy::testing;
+//! # use zerocopy_derive::*;
+//!
+//! #[derive(TryFromBytes, IntoBytes, KnownLayout, Immutable)]
+//! #[repr(u8)]
+//! enum Level {
+//!     Low = 1,
+//!     High = 2,
+//! }
+//!
+//! #[derive(TryFromBytes, IntoBytes, KnownLayout, Immutable)]
+//! #[repr(C)]
+//! struct Reading {
+//!     level: Level,
+//!     enabled: bool,
+//!     value: [u8; 6],
+//! }
+//!
+//! // `Level` is small enough to check every byte pattern.
+//! testing::check_exhaustive::<Level>(|bytes| matches!(bytes, [1] | [2]));
+//! testing::check_encodings(&[Level::Low, Level::High]);
+//!
+//! // `Reading` is not, so it is checked against pseudo-random byte patterns.
+//! testing::check_roundtrip::<Reading>();
+//! testing::check_random::<Reading>(0x5EED, 4096, |bytes| {
+//!     matches!(bytes[0], 1 | 2) && bytes[1] < 2
+//! });
+//!
+//! testing::assert_valid::<Reading>(&[2, 1, 0, 0, 0, 0, 0, 0]);
+//! testing::assert_invalid::<Reading>(&[3, 1, 0, 0, 0, 0, 0, 0]);
+//! testing::assert_invalid::<Reading>(&[2, 2, 0, 0, 0, 0, 0, 0]);
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::{any::type_name, mem::size_of};
+
+use crate::{ConvertError, Immutable, IntoBytes, KnownLayout, TryFromBytes};
+
+/// The largest type size, in bytes, for which [`check_exhaustive`] is
+/// permitted and for which [`check_roundtrip`] checks every byte pattern.
+pub const EXHAUSTIVE_MAX_SIZE: usize = 2;
+
+/// The number of pseudo-random byte patterns which [`check_roundtrip`] checks
+/// for types larger than [`EXHAUSTIVE_MAX_SIZE`].
+pub const RANDOM_ITERATIONS: usize = 1024;
+
+/// The seed which [`check_roundtrip`] uses to generate byte patterns.
+const ROUNDTRIP_SEED: u64 = 0x7A65_726F_636F_7079;
+
+/// Checks that `T`'s validity check is consistent and lossless.
+///
+/// If `T` is no larger than [`EXHAUSTIVE_MAX_SIZE`], every byte pattern is
+/// checked. Otherwise, the all-zeros pattern, the all-`0xFF` pattern, and
+/// [`RANDOM_ITERATIONS`] pseudo-random patterns are checked.
+///
+/// For each byte pattern, this checks that [`try_read_from_bytes`],
+/// [`try_ref_from_bytes`], and [`try_mut_from_bytes`] all agree about whether
+/// the pattern is valid and, if it is, that the resulting value encodes back
+/// to the same pattern.
+///
+/// # Panics
+///
+/// Panics if any of these checks fail.
+///
+/// [`try_read_from_bytes`]: TryFromBytes::try_read_from_bytes
+/// [`try_ref_from_bytes`]: TryFromBytes::try_ref_from_bytes
+/// [`try_mut_from_bytes`]: TryFromBytes::try_mut_from_bytes
+#[inline]
+pub fn check_roundtrip<T>()
+where
+    T: TryFromBytes + IntoBytes + KnownLayout + Immutable,
+{
+    if size_of::<T>() <= EXHAUSTIVE_MAX_SIZE {
+        for_each_exhaustive::<T>(|bytes| {
+            let _ = check_bytes::<T>(bytes);
+        });
+    } else {
+        let _ = check_bytes::<T>(&vec![0x00; size_of::<T>()]);
+        let _ = check_bytes::<T>(&vec![0xFF; size_of::<T>()]);
+        for_each_random::<T>(ROUNDTRIP_SEED, RANDOM_ITERATIONS, |bytes| {
+            let _ = check_bytes::<T>(bytes);
+        });
+    }
+}
+
+/// Checks every byte pattern of `T` against `is_valid`.
+///
+/// For each byte pattern, this performs the checks of [`check_roundtrip`], and
+/// additionally checks that the pattern is accepted if and only if `is_valid`
+/// returns `true` for it.
+///
+/// # Panics
+///
+/// Panics if any of these checks fail, or if `T` is larger than
+/// [`EXHAUSTIVE_MAX_SIZE`].
+#[inline]
+pub fn check_exhaustive<T>(mut is_valid: impl FnMut(&[u8]) -> bool)
+where
+    T: TryFromBytes + IntoBytes + KnownLayout + Immutable,
+{
+    assert!(
+        size_of::<T>() <= EXHAUSTIVE_MAX_SIZE,
+        "`{}` is {} bytes, which is too large to check exhaustively (the maximum is {}); use `check_random` instead",
+        type_name::<T>(),
+        size_of::<T>(),
+        EXHAUSTIVE_MAX_SIZE,
+    );
+    for_each_exhaustive::<T>(|bytes| check_expected::<T>(bytes, is_valid(bytes)));
+}
+
+/// Checks `iterations` pseudo-random byte patterns of `T` against `is_valid`.
+///
+/// The byte patterns are generated deterministically from `seed`. For each
+/// byte pattern, this performs the checks of [`check_roundtrip`], and
+/// additionally checks that the pattern is accepted if and only if `is_valid`
+/// returns `true` for it.
+///
+/// Note that uniformly random byte patterns rarely satisfy types with sparse
+/// validity (e.g., enums with few variants in a wide representation); use
+/// [`check_encodings`] and [`assert_valid`] to cover such types' valid
+/// patterns.
+///
+/// # Panics
+///
+/// Panics if any of these checks fail.
+#[inline]
+pub fn check_random<T>(seed: u64, iterations: usize, mut is_valid: impl FnMut(&[u8]) -> bool)
+where
+    T: TryFromBytes + IntoBytes + KnownLayout + Immutable,
+{
+    for_each_random::<T>(seed, iterations, |bytes| check_expected::<T>(bytes, is_valid(bytes)));
+}
+
+/// Checks that the encoding of each of `values` is accepted by `T`'s validity
+/// check.
+///
+/// For each value, this performs the checks of [`check_roundtrip`] on its
+/// encoding, and additionally checks that the encoding is accepted. To check
+/// that `T` accepts *only* these encodings, pair this with
+/// [`check_exhaustive`]:
+///
+/// ```
+/// # use zerocopy::{testing, IntoBytes};
+/// let values = [false, true];
+/// testing::check_encodings(&values);
+/// testing::check_exhaustive::<bool>(|bytes| values.iter().any(|v| v.as_bytes() == bytes));
+/// ```
+///
+/// # Panics
+///
+/// Panics if any of these checks fail.
+#[inline]
+pub fn check_encodings<T>(values: &[T])
+where
+    T: TryFromBytes + IntoBytes + KnownLayout + Immutable,
+{
+    for value in values {
+        check_expected::<T>(value.as_bytes(), true);
+    }
+}
+
+/// Asserts that `bytes` is a valid instance of `T`.
+///
+/// `T` may be unsized, in which case `bytes` must be a [valid size] for `T`.
+///
+/// # Panics
+///
+/// Panics if `bytes` is not a valid instance of `T`, or if it is not a valid
+/// size for `T`.
+///
+/// [valid size]: crate::KnownLayout#what-is-a-valid-size
+#[inline]
+#[track_caller]
+pub fn assert_valid<T>(bytes: &[u8])
+where
+    T: ?Sized + TryFromBytes + KnownLayout + Immutable,
+{
+    match is_valid::<T>(bytes) {
+        Ok(true) => {}
+        Ok(false) => panic!("`{}` rejected known-good bytes {:?}", type_name::<T>(), bytes),
+        Err(msg) => panic!("{}", msg),
+    }
+}
+
+/// Asserts that `bytes` is not a valid instance of `T`.
+///
+/// `T` may be unsized, in which case `bytes` must be a [valid size] for `T`;
+/// this ensures that the rejection is due to `T`'s validity check, and not
+/// due to `bytes` having the wrong size.
+///
+/// # Panics
+///
+/// Panics if `bytes` is a valid instance of `T`, or if it is not a valid size
+/// for `T`.
+///
+/// [valid size]: crate::KnownLayout#what-is-a-valid-size
+#[inline]
+#[track_caller]
+pub fn assert_invalid<T>(bytes: &[u8])
+where
+    T: ?Sized + TryFromBytes + KnownLayout + Immutable,
+{
+    match is_valid::<T>(bytes) {
+        Ok(false) => {}
+        Ok(true) => panic!("`{}` accepted known-bad bytes {:?}", type_name::<T>(), bytes),
+        Err(msg) => panic!("{}", msg),
+    }
+}
+
+/// Checks `bytes` as described in [`check_roundtrip`], and additionally checks
+/// that it is accepted if and only if `expected` is `true`.
+#[track_caller]
+fn check_expected<T>(bytes: &[u8], expected: bool)
+where
+    T: TryFromBytes + IntoBytes + KnownLayout + Immutable,
+{
+    let accepted = check_bytes::<T>(bytes);
+    assert_eq!(
+        accepted,
+        expected,
+        "`{}` {} bytes {:?}, but they were expected to be {}",
+        type_name::<T>(),
+        if accepted { "accepted" } else { "rejected" },
+        bytes,
+        if expected { "valid" } else { "invalid" },
+    );
+}
+
+/// Checks `bytes` as described in [`check_roundtrip`], returning whether it is
+/// a valid `T`.
+#[track_caller]
+fn check_bytes<T>(bytes: &[u8]) -> bool
+where
+    T: TryFromBytes + IntoBytes + KnownLayout + Immutable,
+{
+    let read = T::try_read_from_bytes(bytes).ok();
+    let mut buf = AlignedBuf::new::<T>(bytes);
+    let by_ref = T::try_ref_from_bytes(buf.as_slice()).is_ok();
+    let by_mut = T::try_mut_from_bytes(buf.as_mut_slice()).is_ok();
+
+    assert!(
+        read.is_some() == by_ref && by_ref == by_mut,
+        "`{}` gave inconsistent results for bytes {:?}: `try_read_from_bytes` {}, `try_ref_from_bytes` {}, `try_mut_from_bytes` {}",
+        type_name::<T>(),
+        bytes,
+        if read.is_some() { "accepted" } else { "rejected" },
+        if by_ref { "accepted" } else { "rejected" },
+        if by_mut { "accepted" } else { "rejected" },
+    );
+
+    match read {
+        Some(value) => {
+            assert_eq!(
+                value.as_bytes(),
+                bytes,
+                "`{}` did not round-trip: bytes {:?} were read as a value which encodes as {:?}",
+                type_name::<T>(),
+                bytes,
+                value.as_bytes(),
+            );
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns whether `bytes` is a valid `T`, or an error message if it is not a
+/// valid size for `T`.
+fn is_valid<T>(bytes: &[u8]) -> Result<bool, alloc::string::String>
+where
+    T: ?Sized + TryFromBytes + KnownLayout + Immutable,
+{
+    let buf = AlignedBuf::new::<T>(bytes);
+    match T::try_ref_from_bytes(buf.as_slice()) {
+        Ok(_) => Ok(true),
+        Err(ConvertError::Validity(_)) => Ok(false),
+        Err(ConvertError::Size(_)) => Err(alloc::format!(
+            "{} bytes is not a valid size for `{}`",
+            bytes.len(),
+            type_name::<T>()
+        )),
+        Err(ConvertError::Alignment(_)) => {
+            unreachable!("`AlignedBuf` is aligned to `{}`", type_name::<T>())
+        }
+    }
+}
+
+/// Calls `f` with every byte pattern of the size of `T`.
+fn for_each_exhaustive<T>(mut f: impl FnMut(&[u8])) {
+    let mut bytes = vec![0u8; size_of::<T>()];
+    loop {
+        f(&bytes);
+        // Increment `bytes` as a little-endian counter, stopping once it wraps
+        // back around to all zeros.
+        let mut carry = true;
+        for byte in bytes.iter_mut() {
+            let (b, c) = byte.overflowing_add(1);
+            *byte = b;
+            carry = c;
+            if !carry {
+                break;
+            }
+        }
+        if carry {
+            return;
+        }
+    }
+}
+
+/// Calls `f` with `iterations` pseudo-random byte patterns of the size of `T`,
+/// generated from `seed`.
+fn for_each_random<T>(seed: u64, iterations: usize, mut f: impl FnMut(&[u8])) {
+    let mut rng = SplitMix64(seed);
+    let mut bytes = vec![0u8; size_of::<T>()];
+    for _ in 0..iterations {
+        for chunk in bytes.chunks_mut(size_of::<u64>()) {
+            for (byte, random) in chunk.iter_mut().zip(rng.next().to_le_bytes()) {
+                *byte = random;
+            }
+        }
+        f(&bytes);
+    }
+}
+
+/// The SplitMix64 pseudo-random number generator.
+///
+/// This is not suitable for cryptographic use, but is small, fast, and
+/// produces well-distributed output from any seed, which is all that is needed
+/// here.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// A copy of some bytes, aligned to the alignment of a type.
+struct AlignedBuf {
+    storage: Vec<u8>,
+    offset: usize,
+    len: usize,
+}
+
+// Clippy: `align >= 1`, and `bytes.len() + align - 1` cannot overflow since
+// `bytes.len() <= isize::MAX` and `align` is at most 2^29. For a `u8` pointer,
+// `align_offset(align) < align`, so `offset + len <= storage.len()` and the
+// slicing below is in-bounds.
+#[allow(clippy::arithmetic_side_effects, clippy::indexing_slicing)]
+impl AlignedBuf {
+    fn new<T: ?Sized + KnownLayout>(bytes: &[u8]) -> AlignedBuf {
+        let align = T::LAYOUT.align.get();
+        let mut storage = vec![0u8; bytes.len() + align - 1];
+        let offset = storage.as_ptr().align_offset(align);
+        storage[offset..][..bytes.len()].copy_from_slice(bytes);
+        AlignedBuf { storage, offset, len: bytes.len() }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.storage[self.offset..][..self.len]
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.storage[self.offset..][..self.len]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU8;
+
+    use super::*;
+    use crate::util::testutil::AU64;
+
+    #[test]
+    fn test_exhaustive() {
+        let mut count = 0;
+        for_each_exhaustive::<[u8; 2]>(|_| count += 1);
+        assert_eq!(count, 1 << 16);
+
+        check_exhaustive::<()>(|bytes| bytes.is_empty());
+        check_exhaustive::<bool>(|bytes| bytes[0] < 2);
+        check_exhaustive::<NonZeroU8>(|bytes| bytes[0] != 0);
+        check_exhaustive::<[bool; 2]>(|bytes| bytes.iter().all(|&b| b < 2));
+        check_exhaustive::<Option<NonZeroU8>>(|_| true);
+    }
+
+    #[test]
+    fn test_random() {
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        for_each_random::<[u8; 13]>(1, 4, |bytes| a.push(bytes.to_vec()));
+        for_each_random::<[u8; 13]>(1, 4, |bytes| b.push(bytes.to_vec()));
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 4);
+        assert!(a.windows(2).all(|w| w[0] != w[1]));
+
+        check_random::<u64>(0, 256, |_| true);
+        check_random::<char>(0, 256, |bytes| {
+            char::from_u32(u32::from_ne_bytes(bytes.try_into().unwrap())).is_some()
+        });
+        check_random::<[bool; 4]>(0, 256, |bytes| bytes.iter().all(|&b| b < 2));
+    }
+
+    #[test]
+    fn test_roundtrip_and_encodings() {
+        check_roundtrip::<u8>();
+        check_roundtrip::<bool>();
+        check_roundtrip::<char>();
+        check_roundtrip::<[AU64; 3]>();
+        check_roundtrip::<Option<NonZeroU8>>();
+
+        check_encodings(&[false, true]);
+        check_encodings(&['\0', 'a', '\u{10FFFF}']);
+    }
+
+    #[test]
+    fn test_known_bytes() {
+        assert_valid::<bool>(&[1]);
+        assert_invalid::<bool>(&[2]);
+        assert_valid::<[bool]>(&[]);
+        assert_valid::<[bool]>(&[0, 1, 0]);
+        assert_invalid::<[bool]>(&[0, 1, 2]);
+        assert_valid::<str>(b"hello");
+        assert_invalid::<str>(&[0xFF]);
+        assert_valid::<[char]>('x'.as_bytes());
+        assert_invalid::<[char]>(&0xD800u32.to_ne_bytes());
+    }
+
+    #[test]
+    #[should_panic(expected = "rejected known-good bytes")]
+    fn test_assert_valid_fails() {
+        assert_valid::<bool>(&[2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "accepted known-bad bytes")]
+    fn test_assert_invalid_fails() {
+        assert_invalid::<bool>(&[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid size for `bool`")]
+    fn test_known_bytes_wrong_size() {
+        assert_invalid::<bool>(&[2, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but they were expected to be valid")]
+    fn test_exhaustive_mismatch() {
+        check_exhaustive::<bool>(|_| true);
+    }
+
+    #[test]
+    #[should_panic(expected = "too large to check exhaustively")]
+    fn test_exhaustive_too_large() {
+        check_exhaustive::<u32>(|_| true);
+    }
+}
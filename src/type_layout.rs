@@ -0,0 +1,817 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+//! Runtime descriptions of the layouts of types.
+
+use core::ops::Range;
+
+use super::*;
+
+/// A description of the layout of a type.
+///
+/// A `TypeLayout` describes the size and alignment of a type and, for structs,
+/// arrays, and slices, the layouts of the values it contains. It can be
+/// obtained for any type which implements [`DescribeLayout`] via
+/// [`DescribeLayout::TYPE_LAYOUT`].
+///
+/// `TypeLayout` implements `PartialEq`, which compares layouts structurally
+/// (including the names of types and fields). This can be used to check that
+/// the layout of a type has not changed between versions of a program.
+///
+/// `TypeLayout` is intended for diagnostics and tooling. Unsafe code may not
+/// rely on a `TypeLayout` accurately describing its type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypeLayout {
+    size: usize,
+    align: usize,
+    kind: TypeKind,
+    /// How values of the type are encoded, if it is a primitive type whose
+    /// values [`HexDump`] can decode.
+    encoding: Option<Encoding>,
+    /// Whether the type is one of the byte-order-aware types in [`byteorder`].
+    byte_order_aware: bool,
+}
+
+/// How the value of a primitive type is encoded in its bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Encoding {
+    Unsigned { big_endian: bool },
+    Signed { big_endian: bool },
+    Float { big_endian: bool },
+    Bool,
+    Char,
+}
+
+/// Whether the target's native byte order is big-endian.
+const NATIVE_BIG_ENDIAN: bool = cfg!(target_endian = "big");
+
+/// The kind of a type described by a [`TypeLayout`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TypeKind {
+    /// A type whose contents are not described, such as a primitive type.
+    Primitive {
+        /// The name of the type.
+        name: &'static str,
+    },
+    /// A struct.
+    Struct {
+        /// The name of the struct, without any generic arguments.
+        name: &'static str,
+        /// The struct's fields, in declaration order.
+        fields: &'static [FieldLayout],
+    },
+    /// An array.
+    Array {
+        /// The layout of the array's element type.
+        elem: &'static TypeLayout,
+        /// The number of elements in the array.
+        len: usize,
+    },
+    /// A slice.
+    Slice {
+        /// The layout of the slice's element type.
+        elem: &'static TypeLayout,
+    },
+}
+
+/// A description of a field of a struct, as described by [`TypeKind::Struct`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FieldLayout {
+    name: &'static str,
+    offset: usize,
+    layout: &'static TypeLayout,
+}
+
+impl TypeLayout {
+    /// Constructs a `TypeLayout` for the sized type `T` with the given kind.
+    pub(crate) const fn new_sized<T>(kind: TypeKind) -> TypeLayout {
+        TypeLayout {
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            kind,
+            encoding: None,
+            byte_order_aware: false,
+        }
+    }
+
+    /// Constructs a `TypeLayout` for the primitive type `T`, whose values are
+    /// encoded as described by `encoding`.
+    const fn new_primitive<T>(name: &'static str, encoding: Encoding) -> TypeLayout {
+        TypeLayout {
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            kind: TypeKind::Primitive { name },
+            encoding: Some(encoding),
+            byte_order_aware: false,
+        }
+    }
+
+    /// Constructs a `TypeLayout` for a type whose layout is described by
+    /// `layout`.
+    pub(crate) const fn from_dst_layout(layout: DstLayout, kind: TypeKind) -> TypeLayout {
+        let align = layout.align.get();
+        TypeLayout {
+            size: util::macro_util::layout_min_size(layout),
+            align,
+            kind,
+            encoding: None,
+            byte_order_aware: false,
+        }
+    }
+
+    /// The size of the type, in bytes.
+    ///
+    /// If the type is dynamically sized, this is the size of an instance of the
+    /// type whose trailing slice has zero elements.
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The alignment of the type, in bytes.
+    #[must_use]
+    #[inline]
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+
+    /// The kind of the type.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> &TypeKind {
+        &self.kind
+    }
+
+    /// The fields of the type, if it is a struct, or an empty slice otherwise.
+    #[must_use]
+    #[inline]
+    pub const fn fields(&self) -> &'static [FieldLayout] {
+        match self.kind {
+            TypeKind::Struct { fields, .. } => fields,
+            _ => &[],
+        }
+    }
+
+    /// Whether values of the type are stored in big-endian byte order, if it
+    /// is a primitive integer or floating-point type.
+    #[cfg(any(feature = "alloc", test))]
+    pub(crate) const fn big_endian(&self) -> Option<bool> {
+        match self.encoding {
+            Some(
+                Encoding::Unsigned { big_endian }
+                | Encoding::Signed { big_endian }
+                | Encoding::Float { big_endian },
+            ) => Some(big_endian),
+            Some(Encoding::Bool | Encoding::Char) | None => None,
+        }
+    }
+
+    /// Whether the type is one of the byte-order-aware types in [`byteorder`],
+    /// which, unlike the native types, have no alignment requirement.
+    #[cfg(any(feature = "alloc", test))]
+    pub(crate) const fn is_byte_order_aware(&self) -> bool {
+        self.byte_order_aware
+    }
+
+    /// The layout of the elements of the type's trailing slice, if it is a
+    /// slice or a dynamically-sized struct.
+    #[must_use]
+    #[inline]
+    pub fn trailing_slice_elem(&self) -> Option<&'static TypeLayout> {
+        match self.kind {
+            TypeKind::Slice { elem } => Some(elem),
+            TypeKind::Struct { fields, .. } => {
+                fields.last().and_then(|field| field.layout.trailing_slice_elem())
+            }
+            TypeKind::Primitive { .. } | TypeKind::Array { .. } => None,
+        }
+    }
+
+    /// Calls `f` with the range of each run of padding bytes in the type, in
+    /// increasing order of offset.
+    ///
+    /// This includes padding inside of fields and array elements. If the type
+    /// is dynamically sized, only the padding in an instance whose trailing
+    /// slice has zero elements is reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use zerocopy::DescribeLayout;
+    /// # use zerocopy_derive::*;
+    /// #[derive(KnownLayout, DescribeLayout)]
+    /// #[repr(C)]
+    /// struct Foo {
+    ///     a: u8,
+    ///     b: u16,
+    ///     c: u8,
+    /// }
+    ///
+    /// let mut padding = Vec::new();
+    /// Foo::TYPE_LAYOUT.for_each_padding(|range| padding.push(range));
+    /// assert_eq!(padding, [1..2, 5..6]);
+    /// ```
+    #[inline]
+    pub fn for_each_padding<F: FnMut(Range<usize>)>(&self, mut f: F) {
+        self.visit_padding(0, &mut f);
+    }
+
+    // Arithmetic in this function cannot overflow, since every offset it
+    // computes is within an instance of the described type, whose size cannot
+    // exceed `isize::MAX`.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn visit_padding(&self, base: usize, f: &mut dyn FnMut(Range<usize>)) {
+        match self.kind {
+            TypeKind::Primitive { .. } | TypeKind::Slice { .. } => {}
+            TypeKind::Array { elem, len } => {
+                for i in 0..len {
+                    elem.visit_padding(base + i * elem.size, f);
+                }
+            }
+            TypeKind::Struct { fields, .. } => {
+                let mut end = 0;
+                for field in fields {
+                    if field.offset > end {
+                        f(base + end..base + field.offset);
+                    }
+                    field.layout.visit_padding(base + field.offset, f);
+                    end = core::cmp::max(end, field.offset + field.layout.size);
+                }
+                if self.size > end {
+                    f(base + end..base + self.size);
+                }
+            }
+        }
+    }
+}
+
+impl Display for TypeLayout {
+    /// Formats the name of the described type (e.g., `[u8; 4]`).
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            TypeKind::Primitive { name } | TypeKind::Struct { name, .. } => f.write_str(name),
+            TypeKind::Array { elem, len } => write!(f, "[{}; {}]", elem, len),
+            TypeKind::Slice { elem } => write!(f, "[{}]", elem),
+        }
+    }
+}
+
+impl FieldLayout {
+    /// Constructs a new `FieldLayout`.
+    pub(crate) const fn new(
+        name: &'static str,
+        offset: usize,
+        layout: &'static TypeLayout,
+    ) -> FieldLayout {
+        FieldLayout { name, offset, layout }
+    }
+
+    /// The name of the field (for tuple structs, its index).
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The offset of the field within its struct, in bytes.
+    #[must_use]
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The layout of the field's type.
+    #[must_use]
+    #[inline]
+    pub const fn layout(&self) -> &'static TypeLayout {
+        self.layout
+    }
+}
+
+impl Encoding {
+    /// Formats the value encoded by `bytes`, which must be exactly as long as
+    /// the encoded type.
+    fn fmt(self, bytes: &[u8], f: &mut Formatter<'_>) -> fmt::Result {
+        let big_endian = match self {
+            Encoding::Unsigned { big_endian }
+            | Encoding::Signed { big_endian }
+            | Encoding::Float { big_endian } => big_endian,
+            Encoding::Bool | Encoding::Char => NATIVE_BIG_ENDIAN,
+        };
+
+        // Normalize the value to little-endian and zero-extend it to 128 bits.
+        let mut le = [0u8; 16];
+        if bytes.len() > le.len() {
+            return f.write_str("?");
+        }
+        if big_endian {
+            le.iter_mut().zip(bytes.iter().rev()).for_each(|(dst, src)| *dst = *src);
+        } else {
+            le.iter_mut().zip(bytes).for_each(|(dst, src)| *dst = *src);
+        }
+
+        match (self, bytes.len()) {
+            (Encoding::Unsigned { .. }, _) => write!(f, "{}", u128::from_le_bytes(le)),
+            (Encoding::Signed { .. }, len) => {
+                let negative = len.checked_sub(1).and_then(|i| le.get(i)).map(|b| b & 0x80 != 0);
+                if negative == Some(true) {
+                    le.iter_mut().skip(len).for_each(|b| *b = 0xFF);
+                }
+                write!(f, "{}", i128::from_le_bytes(le))
+            }
+            (Encoding::Float { .. }, 4) => {
+                write!(f, "{}", f32::from_le_bytes([le[0], le[1], le[2], le[3]]))
+            }
+            (Encoding::Float { .. }, 8) => {
+                let bytes = [le[0], le[1], le[2], le[3], le[4], le[5], le[6], le[7]];
+                write!(f, "{}", f64::from_le_bytes(bytes))
+            }
+            (Encoding::Bool, _) => match le[0] {
+                0 => f.write_str("false"),
+                1 => f.write_str("true"),
+                _ => f.write_str("<invalid>"),
+            },
+            (Encoding::Char, _) => {
+                match char::from_u32(u32::from_le_bytes([le[0], le[1], le[2], le[3]])) {
+                    Some(c) => write!(f, "'{}'", c.escape_debug()),
+                    None => f.write_str("<invalid>"),
+                }
+            }
+            (Encoding::Float { .. }, _) => f.write_str("?"),
+        }
+    }
+}
+
+/// An annotated hex dump of a value.
+///
+/// A `HexDump` formats bytes according to a [`TypeLayout`], grouping them by
+/// field. Each line shows the offset of its bytes, the bytes themselves, and
+/// what they encode: a field's name (nested fields and array elements are
+/// written as paths like `a.b[2].c`) and type and, for primitive types, the
+/// decoded value. Padding bytes are marked as `<padding>`, bytes beyond the
+/// end of the described type as `<trailing bytes>`, and bytes which are
+/// missing because the dumped bytes are too short as `<missing N bytes>`.
+///
+/// `Debug` and `Display` both produce this format.
+///
+/// # Examples
+///
+/// ```
+/// # use zerocopy::{byteorder::{BigEndian, U16}, DescribeLayout, HexDump};
+/// # use zerocopy_derive::*;
+/// #[derive(KnownLayout, DescribeLayout)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     length: U16<BigEndian>,
+///     id: u32,
+///     tag: [u8; 2],
+/// }
+///
+/// let bytes = [0x01, 0x01, 0x02, 0xFF, 0x07, 0x07, 0x07, 0x07, b'h', b'i', 0xFF, 0xFF];
+/// let dump = HexDump::from_bytes(&bytes, &Header::TYPE_LAYOUT);
+/// assert_eq!(
+///     dump.to_string(),
+///     "Header (12 bytes)
+/// 0000  01           kind: u8 = 1
+/// 0001  01 02        length: U16 = 258
+/// 0003  ff           <padding>
+/// 0004  07 07 07 07  id: u32 = 117901063
+/// 0008  68 69        tag: [u8; 2]
+/// 000a  ff ff        <padding>"
+/// );
+/// ```
+#[derive(Copy, Clone)]
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    layout: &'static TypeLayout,
+}
+
+/// The maximum number of bytes printed on one row of a [`HexDump`].
+const BYTES_PER_ROW: usize = 16;
+
+/// A range of bytes in a [`HexDump`], along with what they encode.
+struct Line<'p> {
+    range: Range<usize>,
+    label: Label<'p>,
+}
+
+enum Label<'p> {
+    Value { path: Option<&'p Path<'p>>, layout: &'static TypeLayout },
+    Padding,
+    Trailing,
+}
+
+/// The path of a field or array element, relative to the dumped value.
+enum Path<'p> {
+    Field { parent: Option<&'p Path<'p>>, name: &'static str },
+    Index { parent: Option<&'p Path<'p>>, index: usize },
+}
+
+impl Display for Path<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Path::Field { parent: Some(parent), name } => write!(f, "{}.{}", parent, name),
+            Path::Field { parent: None, name } => f.write_str(name),
+            Path::Index { parent: Some(parent), index } => write!(f, "{}[{}]", parent, index),
+            Path::Index { parent: None, index } => write!(f, "[{}]", index),
+        }
+    }
+}
+
+impl<'a> HexDump<'a> {
+    /// Constructs a `HexDump` of the bytes of `value`.
+    #[must_use]
+    #[inline]
+    pub fn new<T: ?Sized + IntoBytes + Immutable + DescribeLayout>(value: &'a T) -> HexDump<'a> {
+        HexDump { bytes: value.as_bytes(), layout: &T::TYPE_LAYOUT }
+    }
+
+    /// Constructs a `HexDump` which describes `bytes` as a value whose layout
+    /// is described by `layout`.
+    ///
+    /// `bytes` need not be a valid instance of the described type, nor be of
+    /// the correct length.
+    #[must_use]
+    #[inline]
+    pub const fn from_bytes(bytes: &'a [u8], layout: &'static TypeLayout) -> HexDump<'a> {
+        HexDump { bytes, layout }
+    }
+
+    /// Calls `visit` with each line of the dump, in increasing order of
+    /// offset.
+    fn for_each_line(&self, visit: &mut dyn FnMut(Line<'_>) -> fmt::Result) -> fmt::Result {
+        let end = self.visit(self.layout, 0, None, visit)?;
+        if self.bytes.len() > end {
+            visit(Line { range: end..self.bytes.len(), label: Label::Trailing })?;
+        }
+        Ok(())
+    }
+
+    /// Visits the lines of a value with the given layout at `offset`,
+    /// returning the offset of the end of the value.
+    //
+    // Arithmetic in this function cannot overflow: every offset it computes
+    // is either within an instance of a sized type, whose size cannot exceed
+    // `isize::MAX`, or within a trailing slice whose elements lie within
+    // `self.bytes`.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn visit(
+        &self,
+        layout: &'static TypeLayout,
+        offset: usize,
+        path: Option<&Path<'_>>,
+        visit: &mut dyn FnMut(Line<'_>) -> fmt::Result,
+    ) -> Result<usize, fmt::Error> {
+        let (elem, len) = match layout.kind {
+            TypeKind::Primitive { .. } => {
+                let end = offset + layout.size;
+                visit(Line { range: offset..end, label: Label::Value { path, layout } })?;
+                return Ok(end);
+            }
+            TypeKind::Struct { fields, .. } => {
+                let mut end = offset;
+                for field in fields {
+                    let start = offset + field.offset;
+                    if start > end {
+                        visit(Line { range: end..start, label: Label::Padding })?;
+                    }
+                    let path = Path::Field { parent: path, name: field.name };
+                    end = core::cmp::max(end, self.visit(field.layout, start, Some(&path), visit)?);
+                }
+                let size_end = offset + layout.size;
+                if size_end > end {
+                    visit(Line { range: end..size_end, label: Label::Padding })?;
+                    end = size_end;
+                }
+                return Ok(end);
+            }
+            TypeKind::Array { elem, len } => (elem, len),
+            TypeKind::Slice { elem } => {
+                let len = self.bytes.len().saturating_sub(offset).checked_div(elem.size);
+                (elem, len.unwrap_or(0))
+            }
+        };
+
+        // Arrays and slices of primitives are printed on a single line; the
+        // elements of other arrays and slices are printed individually.
+        if let TypeKind::Primitive { .. } = elem.kind {
+            let end = offset + elem.size * len;
+            visit(Line { range: offset..end, label: Label::Value { path, layout } })?;
+            return Ok(end);
+        }
+        let mut end = offset;
+        for index in 0..len {
+            let path = Path::Index { parent: path, index };
+            end = self.visit(elem, offset + elem.size * index, Some(&path), visit)?;
+        }
+        Ok(end)
+    }
+
+    /// Writes a single line, split into rows of at most [`BYTES_PER_ROW`]
+    /// bytes. The hex column of the first row, which is followed by the line's
+    /// label, is padded to `width`.
+    #[allow(clippy::arithmetic_side_effects)] // Offsets are within `self.bytes`.
+    fn fmt_line(&self, line: &Line<'_>, width: usize, f: &mut Formatter<'_>) -> fmt::Result {
+        let bytes = self.available(&line.range);
+        let mut rows = bytes.chunks(BYTES_PER_ROW);
+        let first = rows.next().unwrap_or(&[]);
+        self.fmt_row(line.range.start, first, f)?;
+        for _ in hex_width(first.len())..width {
+            f.write_str(" ")?;
+        }
+
+        f.write_str("  ")?;
+        match line.label {
+            Label::Value { path, layout } => {
+                if let Some(path) = path {
+                    write!(f, "{}: ", path)?;
+                }
+                write!(f, "{}", layout)?;
+                if let (Some(encoding), true) = (layout.encoding, bytes.len() == line.range.len()) {
+                    f.write_str(" = ")?;
+                    encoding.fmt(bytes, f)?;
+                }
+            }
+            Label::Padding => f.write_str("<padding>")?,
+            Label::Trailing => f.write_str("<trailing bytes>")?,
+        }
+        let missing = line.range.len() - bytes.len();
+        if missing > 0 {
+            write!(f, " <missing {} byte{}>", missing, if missing == 1 { "" } else { "s" })?;
+        }
+
+        for (i, row) in rows.enumerate() {
+            f.write_str("\n")?;
+            self.fmt_row(line.range.start + (i + 1) * BYTES_PER_ROW, row, f)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the offset and hex bytes of a single row.
+    fn fmt_row(&self, offset: usize, row: &[u8], f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}  ", offset)?;
+        for (i, byte) in row.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+
+    /// The bytes in `range` which are present in `self.bytes`.
+    fn available(&self, range: &Range<usize>) -> &'a [u8] {
+        let len = self.bytes.len();
+        self.bytes.get(range.start.min(len)..range.end.min(len)).unwrap_or(&[])
+    }
+}
+
+/// The width of the hex representation of `len` bytes.
+#[allow(clippy::arithmetic_side_effects)] // `len` is at most `BYTES_PER_ROW`.
+const fn hex_width(len: usize) -> usize {
+    (len * 3).saturating_sub(1)
+}
+
+impl Display for HexDump<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Pad the hex columns of every row to the width of the widest row.
+        let mut width = 0;
+        self.for_each_line(&mut |line| {
+            let len = core::cmp::min(self.available(&line.range).len(), BYTES_PER_ROW);
+            width = core::cmp::max(width, hex_width(len));
+            Ok(())
+        })?;
+
+        let len = self.bytes.len();
+        write!(f, "{} ({} byte{})", self.layout, len, if len == 1 { "" } else { "s" })?;
+        self.for_each_line(&mut |line| {
+            f.write_str("\n")?;
+            self.fmt_line(&line, width, f)
+        })
+    }
+}
+
+impl Debug for HexDump<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Types whose layout can be described at runtime.
+///
+/// # Implementation
+///
+/// This trait is implemented for primitive types, arrays, slices, and the
+/// types in the [`byteorder`] module. It can be derived for `repr(C)` structs
+/// whose fields implement `DescribeLayout`:
+///
+/// ```
+/// # use zerocopy::{byteorder::{BigEndian, U16}, DescribeLayout, TypeKind};
+/// # use zerocopy_derive::*;
+/// #[derive(KnownLayout, DescribeLayout)]
+/// #[repr(C)]
+/// struct Packet {
+///     length: U16<BigEndian>,
+///     body: [u8],
+/// }
+///
+/// let layout = Packet::TYPE_LAYOUT;
+/// assert_eq!(layout.to_string(), "Packet");
+/// assert_eq!(layout.size(), 2);
+///
+/// let body = &layout.fields()[1];
+/// assert_eq!((body.name(), body.offset()), ("body", 2));
+/// assert_eq!(body.layout().to_string(), "[u8]");
+/// assert_eq!(layout.trailing_slice_elem(), Some(&u8::TYPE_LAYOUT));
+/// ```
+///
+/// Implementations of `DescribeLayout` are not checked for accuracy, and so
+/// unsafe code may not rely on them.
+pub trait DescribeLayout: KnownLayout {
+    /// A description of the layout of `Self`.
+    const TYPE_LAYOUT: TypeLayout;
+}
+
+/// Implements [`DescribeLayout`] for a type, which is described as a
+/// [`TypeKind::Primitive`] whose values are encoded as described by the given
+/// [`Encoding`].
+///
+/// The generic form uses the name of the type without its generic arguments.
+macro_rules! impl_describe_layout_primitive {
+    ($($ty:ident => $encoding:expr),*) => {
+        $(
+            impl DescribeLayout for $ty {
+                const TYPE_LAYOUT: TypeLayout =
+                    TypeLayout::new_primitive::<$ty>(stringify!($ty), $encoding);
+            }
+        )*
+    };
+    ($($ty:ident<O> => $encoding:ident),*) => {
+        $(
+            impl<O: ByteOrder> DescribeLayout for $ty<O> {
+                const TYPE_LAYOUT: TypeLayout = TypeLayout {
+                    byte_order_aware: true,
+                    ..TypeLayout::new_primitive::<$ty<O>>(
+                        stringify!($ty),
+                        Encoding::$encoding {
+                            big_endian: matches!(O::ORDER, byteorder::Order::BigEndian),
+                        },
+                    )
+                };
+            }
+        )*
+    };
+}
+
+const NATIVE_UNSIGNED: Encoding = Encoding::Unsigned { big_endian: NATIVE_BIG_ENDIAN };
+const NATIVE_SIGNED: Encoding = Encoding::Signed { big_endian: NATIVE_BIG_ENDIAN };
+const NATIVE_FLOAT: Encoding = Encoding::Float { big_endian: NATIVE_BIG_ENDIAN };
+
+#[rustfmt::skip]
+impl_describe_layout_primitive!(
+    bool => Encoding::Bool, char => Encoding::Char,
+    u8 => NATIVE_UNSIGNED, u16 => NATIVE_UNSIGNED, u32 => NATIVE_UNSIGNED,
+    u64 => NATIVE_UNSIGNED, u128 => NATIVE_UNSIGNED, usize => NATIVE_UNSIGNED,
+    i8 => NATIVE_SIGNED, i16 => NATIVE_SIGNED, i32 => NATIVE_SIGNED,
+    i64 => NATIVE_SIGNED, i128 => NATIVE_SIGNED, isize => NATIVE_SIGNED,
+    f32 => NATIVE_FLOAT, f64 => NATIVE_FLOAT
+);
+#[rustfmt::skip]
+impl_describe_layout_primitive!(
+    U16<O> => Unsigned, U32<O> => Unsigned, U64<O> => Unsigned, U128<O> => Unsigned,
+    Usize<O> => Unsigned, I16<O> => Signed, I32<O> => Signed, I64<O> => Signed,
+    I128<O> => Signed, Isize<O> => Signed, F32<O> => Float, F64<O> => Float
+);
+
+impl DescribeLayout for () {
+    const TYPE_LAYOUT: TypeLayout = TypeLayout::new_sized::<()>(TypeKind::Primitive { name: "()" });
+}
+
+impl<T: ?Sized> DescribeLayout for PhantomData<T> {
+    const TYPE_LAYOUT: TypeLayout =
+        TypeLayout::new_sized::<PhantomData<T>>(TypeKind::Primitive { name: "PhantomData" });
+}
+
+impl<T: DescribeLayout, const N: usize> DescribeLayout for [T; N] {
+    const TYPE_LAYOUT: TypeLayout =
+        TypeLayout::new_sized::<[T; N]>(TypeKind::Array { elem: &T::TYPE_LAYOUT, len: N });
+}
+
+impl<T: DescribeLayout> DescribeLayout for [T] {
+    const TYPE_LAYOUT: TypeLayout =
+        TypeLayout::from_dst_layout(<[T]>::LAYOUT, TypeKind::Slice { elem: &T::TYPE_LAYOUT });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(KnownLayout, DescribeLayout)]
+    #[repr(C)]
+    struct Inner {
+        a: u8,
+        b: u32,
+    }
+
+    #[derive(KnownLayout, DescribeLayout)]
+    #[repr(C)]
+    struct Outer {
+        a: [Inner; 2],
+        b: u16,
+        c: [u32],
+    }
+
+    #[test]
+    fn test_type_layout() {
+        let layout = Outer::TYPE_LAYOUT;
+        assert_eq!(layout.to_string(), "Outer");
+        assert_eq!((layout.size(), layout.align()), (20, 4));
+
+        let names: Vec<_> = layout
+            .fields()
+            .iter()
+            .map(|field| (field.name(), field.offset(), field.layout().to_string()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("a", 0, "[Inner; 2]".to_string()),
+                ("b", 16, "u16".to_string()),
+                ("c", 20, "[u32]".to_string()),
+            ]
+        );
+        assert_eq!(layout.trailing_slice_elem(), Some(&u32::TYPE_LAYOUT));
+        assert_eq!(Inner::TYPE_LAYOUT.trailing_slice_elem(), None);
+
+        let mut padding = Vec::new();
+        layout.for_each_padding(|range| padding.push(range));
+        assert_eq!(padding, [1..4, 9..12, 18..20]);
+
+        assert_eq!(<[U16<BigEndian>; 3]>::TYPE_LAYOUT.to_string(), "[U16; 3]");
+        assert_ne!(Inner::TYPE_LAYOUT, <[u8; 8]>::TYPE_LAYOUT);
+    }
+
+    #[test]
+    fn test_hex_dump() {
+        let bytes = [
+            1, 0xAA, 0xAA, 0xAA, 7, 7, 7, 7, 3, 0xAA, 0xAA, 0xAA, 0, 0, 0, 0, 5, 5, 0xAA, 0xAA, 6,
+            0, 0, 0, 7, 0, 0, 0, 8,
+        ];
+        assert_eq!(
+            HexDump::from_bytes(&bytes, &Outer::TYPE_LAYOUT).to_string(),
+            "Outer (29 bytes)
+0000  01                       a[0].a: u8 = 1
+0001  aa aa aa                 <padding>
+0004  07 07 07 07              a[0].b: u32 = 117901063
+0008  03                       a[1].a: u8 = 3
+0009  aa aa aa                 <padding>
+000c  00 00 00 00              a[1].b: u32 = 0
+0010  05 05                    b: u16 = 1285
+0012  aa aa                    <padding>
+0014  06 00 00 00 07 00 00 00  c: [u32]
+001c  08                       <trailing bytes>"
+        );
+
+        #[derive(KnownLayout, DescribeLayout)]
+        #[repr(C)]
+        struct Record {
+            id: U32<BigEndian>,
+            n: I16<LittleEndian>,
+            f: F32<BigEndian>,
+            ok: bool,
+            data: [u8; 20],
+        }
+
+        let mut bytes = [0u8; 29];
+        bytes[..11].copy_from_slice(&[0, 0, 1, 0, 0xFE, 0xFF, 0x3F, 0xC0, 0, 0, 1]);
+        bytes[11..].iter_mut().zip(0..).for_each(|(b, i)| *b = i);
+        assert_eq!(
+            HexDump::from_bytes(&bytes, &Record::TYPE_LAYOUT).to_string(),
+            "Record (29 bytes)
+0000  00 00 01 00                                      id: U32 = 256
+0004  fe ff                                            n: I16 = -2
+0006  3f c0 00 00                                      f: F32 = 1.5
+000a  01                                               ok: bool = true
+000b  00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  data: [u8; 20] <missing 2 bytes>
+001b  10 11"
+        );
+
+        let r = Ref::<_, [U16<BigEndian>]>::from_bytes(&[0, 1, 0, 2][..]).unwrap();
+        assert_eq!(format!("{:?}", Ref::hex_dump(&r)), "[U16] (4 bytes)\n0000  00 01 00 02  [U16]");
+        assert_eq!(
+            HexDump::new(&[true, false]).to_string(),
+            "[bool; 2] (2 bytes)\n0000  01 00  [bool; 2]"
+        );
+    }
+}
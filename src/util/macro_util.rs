@@ -17,17 +17,30 @@
 
 #![allow(missing_debug_implementations)]
 
-use core::mem::{self, ManuallyDrop};
+use core::{
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    num::NonZeroUsize,
+};
 
 // TODO(#29), TODO(https://github.com/rust-lang/rust/issues/69835): Remove this
 // `cfg` when `size_of_val_raw` is stabilized.
 #[cfg(__ZEROCOPY_INTERNAL_USE_ONLY_NIGHTLY_FEATURES_IN_TESTS)]
-use core::ptr::{self, NonNull};
+use core::ptr;
+#[cfg(any(feature = "alloc", __ZEROCOPY_INTERNAL_USE_ONLY_NIGHTLY_FEATURES_IN_TESTS))]
+use core::ptr::NonNull;
 
 use crate::{
     pointer::invariant::{self, BecauseExclusive, BecauseImmutable, Invariants},
-    Immutable, IntoBytes, Ptr, TryFromBytes, Unalign, ValidityError,
+    util::padding_needed_for,
+    CastError, CastType, DstLayout, FieldLayout, FromBytes, Immutable, IntoBytes, KnownLayout, Ptr,
+    SizeError, SizeInfo, TrailingSliceLayout, TryCastError, TryFromBytes, TypeKind, TypeLayout,
+    Unalign, Unaligned, ValidityError,
 };
+#[cfg(feature = "alloc")]
+use crate::{PointerMetadata, TryReadError};
 
 #[cfg_attr(
     zerocopy_diagnostic_on_unimplemented,
@@ -78,6 +91,436 @@ impl<T, U> MaxAlignsOf<T, U> {
     }
 }
 
+/// A type-level representation of the alignment `N`.
+#[allow(missing_copy_implementations)]
+pub enum AlignTo<const N: usize> {}
+
+/// Implemented for [`AlignTo<N>`] for every valid alignment, `N`.
+pub trait ValidAlignment {
+    /// A type whose size and alignment are both equal to `N`.
+    type Chunk;
+}
+
+macro_rules! define_align_chunks {
+    ($($chunk:ident => $align:literal),*) => {
+        $(
+            #[doc(hidden)]
+            #[allow(missing_copy_implementations)]
+            #[repr(C, align($align))]
+            pub struct $chunk([u8; $align]);
+
+            impl ValidAlignment for AlignTo<$align> {
+                type Chunk = $chunk;
+            }
+        )*
+    };
+}
+
+for_each_alignment!(define_align_chunks);
+
+/// Bytes whose address is aligned to `align_of::<A>()`.
+///
+/// Used by `include_slice!`.
+#[repr(C)]
+pub struct AlignedBytes<A, const N: usize> {
+    _align: [A; 0],
+    bytes: [u8; N],
+}
+
+impl<A, const N: usize> AlignedBytes<A, N> {
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(bytes: [u8; N]) -> AlignedBytes<A, N> {
+        AlignedBytes { _align: [], bytes }
+    }
+
+    /// Returns a pointer to the first byte.
+    ///
+    /// The returned pointer is aligned to `align_of::<A>()`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const u8 {
+        // `bytes` is at offset 0 because it is preceded only by a zero-sized
+        // field in a `repr(C)` struct. Since `self` is aligned to
+        // `align_of::<A>()`, so is `bytes`.
+        self.bytes.as_ptr()
+    }
+}
+
+/// Returns `T`'s alignment.
+///
+/// Used by `include_slice!`.
+#[inline(always)]
+#[must_use]
+pub const fn align_of_known_layout<T: ?Sized + KnownLayout>() -> usize {
+    T::LAYOUT.align.get()
+}
+
+/// Computes the number of trailing slice elements of a `T` which is exactly
+/// `len` bytes long and which lives at an address aligned to `T`'s alignment.
+///
+/// Used by `include_slice!`. For sized `T`, this returns 0.
+///
+/// # Panics
+///
+/// Panics if `len` is not a valid size for `T`, or if `T` is a DST whose
+/// trailing slice element is zero-sized. Since this is only called in `const`
+/// contexts, such panics are compile-time errors.
+#[inline(always)]
+#[must_use]
+pub const fn include_slice_elems<T>(len: usize) -> usize
+where
+    T: ?Sized + KnownLayout + FromBytes + Immutable,
+{
+    if let SizeInfo::SliceDst(TrailingSliceLayout { elem_size: 0, .. }) = T::LAYOUT.size_info {
+        panic!("cannot include a file as a dynamically-sized type whose trailing slice element is zero-sized");
+    }
+    match T::LAYOUT.validate_cast_and_convert_metadata(0, len, CastType::Prefix) {
+        Ok((elems, split_at)) if split_at == len => elems,
+        _ => panic!("the length of the included file is not a valid size for the destination type"),
+    }
+}
+
+/// Computes the number of padding bytes in a `repr(C)` struct between the end
+/// of the fields described by `preceding` and the start of the field described
+/// by `next`, or the end of the struct if `next` is `None`.
+///
+/// `preceding` must be constructed from `DstLayout::new_zst` followed by one
+/// call to `extend` for each field which precedes `next`, in declaration
+/// order. Used by `#[padded]`.
+///
+/// # Panics
+///
+/// Panics if `preceding` or `next` describes a dynamically-sized type. Since
+/// this is only called in `const` contexts, such panics are compile-time
+/// errors.
+#[inline(always)]
+#[must_use]
+pub const fn repr_c_padding(preceding: DstLayout, next: Option<DstLayout>) -> usize {
+    const fn size_of_layout(layout: &DstLayout) -> usize {
+        match layout.size_info {
+            SizeInfo::Sized { size } => size,
+            SizeInfo::SliceDst(_) => {
+                panic!("`#[padded]` does not support dynamically-sized fields")
+            }
+        }
+    }
+
+    let end = size_of_layout(&preceding);
+    let next_offset = match next {
+        Some(next) => {
+            let next_size = size_of_layout(&next);
+            // `extend` places `next` at the first suitably-aligned offset at or
+            // after `end`, so the extended size is at least `end + next_size`.
+            match size_of_layout(&preceding.extend(next, None)).checked_sub(next_size) {
+                Some(offset) => offset,
+                None => unreachable!(),
+            }
+        }
+        None => size_of_layout(&preceding.pad_to_align()),
+    };
+    match next_offset.checked_sub(end) {
+        Some(padding) => padding,
+        None => unreachable!(),
+    }
+}
+
+/// Computes the offset of a field with layout `field` in a `repr(C)` struct,
+/// where `preceding` describes the fields which precede it.
+///
+/// `preceding` must be constructed from `DstLayout::new_zst` followed by one
+/// call to `extend` for each field which precedes `field`, in declaration
+/// order, each passing `repr_packed`. Used by `#[derive(FieldOffsets)]`.
+#[inline(always)]
+#[must_use]
+pub const fn repr_c_field_offset(
+    preceding: DstLayout,
+    field: DstLayout,
+    repr_packed: Option<NonZeroUsize>,
+) -> usize {
+    let field_size_info = field.size_info;
+    let offset = match (preceding.extend(field, repr_packed).size_info, field_size_info) {
+        // `extend` returns a layout whose size is the offset of `field` plus
+        // `field`'s size...
+        (SizeInfo::Sized { size }, SizeInfo::Sized { size: field_size }) => {
+            size.checked_sub(field_size)
+        }
+        // ...or, if `field` is a DST, whose trailing slice offset is the
+        // offset of `field` plus `field`'s own trailing slice offset.
+        (
+            SizeInfo::SliceDst(TrailingSliceLayout { offset, .. }),
+            SizeInfo::SliceDst(TrailingSliceLayout { offset: field_offset, .. }),
+        ) => offset.checked_sub(field_offset),
+        _ => None,
+    };
+    match offset {
+        Some(offset) => offset,
+        None => unreachable!(),
+    }
+}
+
+/// Computes the size of a type with the given layout or, if the layout
+/// describes a DST, the size of an instance with a zero-length trailing slice.
+///
+/// Used by `#[derive(FieldOffsets)]`.
+#[inline(always)]
+#[must_use]
+pub const fn layout_min_size(layout: DstLayout) -> usize {
+    match layout.size_info {
+        SizeInfo::Sized { size } => size,
+        SizeInfo::SliceDst(TrailingSliceLayout { offset, .. }) => {
+            match offset.checked_add(padding_needed_for(offset, layout.align)) {
+                Some(size) => size,
+                None => panic!("size overflows `usize`"),
+            }
+        }
+    }
+}
+
+/// Constructs the `TypeLayout` of a struct.
+///
+/// Used by `#[derive(DescribeLayout)]`.
+#[inline(always)]
+#[must_use]
+pub const fn struct_type_layout<T: ?Sized + KnownLayout>(
+    name: &'static str,
+    fields: &'static [FieldLayout],
+) -> TypeLayout {
+    TypeLayout::from_dst_layout(T::LAYOUT, TypeKind::Struct { name, fields })
+}
+
+/// Constructs the `FieldLayout` of a field of a struct.
+///
+/// Used by `#[derive(DescribeLayout)]`.
+#[inline(always)]
+#[must_use]
+pub const fn field_layout(
+    name: &'static str,
+    offset: usize,
+    layout: &'static TypeLayout,
+) -> FieldLayout {
+    FieldLayout::new(name, offset, layout)
+}
+
+/// Converts the constant given by a `#[zerocopy(eq = ...)]` attribute to the
+/// type of its field.
+///
+/// Used by `#[derive(TryFromBytes)]`. Besides constants of the field's own
+/// type, this accepts byte string literals for byte array fields and native
+/// integers and floats for fields of the corresponding [`byteorder`] types.
+///
+/// [`byteorder`]: crate::byteorder
+pub trait IntoConstField<T> {
+    /// Converts `self` to a value of the field's type.
+    fn into_const_field(self) -> T;
+}
+
+impl<T> IntoConstField<T> for T {
+    #[inline(always)]
+    fn into_const_field(self) -> T {
+        self
+    }
+}
+
+impl<const N: usize> IntoConstField<[u8; N]> for &[u8; N] {
+    #[inline(always)]
+    fn into_const_field(self) -> [u8; N] {
+        *self
+    }
+}
+
+macro_rules! impl_into_const_field_for_byteorder {
+    ($($name:ident($native:ident)),*) => {
+        $(
+            impl<O: crate::byteorder::ByteOrder> IntoConstField<crate::byteorder::$name<O>>
+                for $native
+            {
+                #[inline(always)]
+                fn into_const_field(self) -> crate::byteorder::$name<O> {
+                    crate::byteorder::$name::new(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_const_field_for_byteorder!(
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    F32(f32),
+    F64(f64)
+);
+
+/// Converts the value of a field annotated by `#[zerocopy(len = ...)]` to a
+/// trailing slice element count.
+///
+/// Used by `#[derive(KnownLayout)]`. Implemented for unsigned integers and the
+/// corresponding [`byteorder`] types.
+///
+/// [`byteorder`]: crate::byteorder
+pub trait LinkedLen {
+    /// Converts `self` to an element count, or returns `None` if it does not
+    /// fit in a `usize`.
+    fn into_len(self) -> Option<usize>;
+}
+
+macro_rules! impl_linked_len {
+    ($($native:ident $(/ $byteorder:ident)?),*) => {
+        $(
+            impl LinkedLen for $native {
+                #[inline(always)]
+                #[allow(clippy::useless_conversion)]
+                fn into_len(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+            }
+
+            $(
+                impl<O: crate::byteorder::ByteOrder> LinkedLen for crate::byteorder::$byteorder<O> {
+                    #[inline(always)]
+                    fn into_len(self) -> Option<usize> {
+                        self.get().into_len()
+                    }
+                }
+            )?
+        )*
+    };
+}
+
+impl_linked_len!(u8, u16 / U16, u32 / U32, u64 / U64, u128 / U128, usize / Usize);
+
+/// Interprets the prefix of `source` as a `&T` whose trailing slice has `len`
+/// elements, where `len` is read from the prefix of `source`.
+///
+/// Used by `#[derive(KnownLayout)]`.
+#[inline(always)]
+pub fn ref_from_prefix_linked<T, F>(
+    source: &[u8],
+    len: F,
+) -> Result<(&T, &[u8]), CastError<&[u8], T>>
+where
+    T: ?Sized + KnownLayout<PointerMetadata = usize> + FromBytes + Immutable,
+    F: FnOnce(&T) -> Option<usize>,
+{
+    let (header, _) = T::ref_from_prefix_with_elems(source, 0)?;
+    match len(header) {
+        Some(elems) => T::ref_from_prefix_with_elems(source, elems),
+        None => Err(SizeError::new(source).into()),
+    }
+}
+
+/// Attempts to interpret the prefix of `source` as a `&T` whose trailing slice
+/// has `len` elements, where `len` is read from the prefix of `source`.
+///
+/// Used by `#[derive(KnownLayout)]`.
+#[inline(always)]
+pub fn try_ref_from_prefix_linked<T, F>(
+    source: &[u8],
+    len: F,
+) -> Result<(&T, &[u8]), TryCastError<&[u8], T>>
+where
+    T: ?Sized + KnownLayout<PointerMetadata = usize> + TryFromBytes + Immutable,
+    F: FnOnce(&T) -> Option<usize>,
+{
+    let (header, _) = T::try_ref_from_prefix_with_elems(source, 0)?;
+    match len(header) {
+        Some(elems) => T::try_ref_from_prefix_with_elems(source, elems),
+        None => Err(SizeError::new(source).into()),
+    }
+}
+
+/// The discriminant of a wire type generated for an enum with a
+/// `#[zerocopy(discriminant = ...)]` attribute.
+///
+/// Used by `#[derive(TryFromBytes)]`. The wire type's field has this type
+/// rather than `D` itself so that code in the enum's module, which can access
+/// the wire type's private field, cannot construct a wire value holding an
+/// invalid discriminant without `unsafe`. For the same reason, this type does
+/// not implement `TryFromBytes`, `FromZeros`, or `FromBytes`.
+///
+/// `E` is the enum whose discriminant this is. Since it is part of the type, a
+/// `WireDiscriminant` obtained from one enum's wire type cannot be moved into
+/// the wire type of another enum which shares the same discriminant type.
+#[repr(transparent)]
+pub struct WireDiscriminant<D, E>(D, PhantomData<E>);
+
+impl<D, E> WireDiscriminant<D, E> {
+    /// Wraps `discriminant`.
+    ///
+    /// # Safety
+    ///
+    /// `discriminant` must be equal to the discriminant of one of the variants
+    /// of `E`.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn new_unchecked(discriminant: D) -> WireDiscriminant<D, E> {
+        WireDiscriminant(discriminant, PhantomData)
+    }
+
+    /// Returns the wrapped discriminant.
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(self) -> D
+    where
+        D: Copy,
+    {
+        self.0
+    }
+}
+
+// These are implemented by hand rather than derived so that they do not
+// require `E` to implement them.
+
+impl<D: Clone, E> Clone for WireDiscriminant<D, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        WireDiscriminant(self.0.clone(), PhantomData)
+    }
+}
+
+impl<D: Copy, E> Copy for WireDiscriminant<D, E> {}
+
+impl<D: PartialEq, E> PartialEq for WireDiscriminant<D, E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<D: Eq, E> Eq for WireDiscriminant<D, E> {}
+
+impl<D: Hash, E> Hash for WireDiscriminant<D, E> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<D: Debug, E> Debug for WireDiscriminant<D, E> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+safety_comment! {
+    /// SAFETY:
+    /// `WireDiscriminant<D, E>` is `repr(transparent)` around `D` (its other
+    /// field is a `PhantomData`), so it has the same layout and bit validity as
+    /// `D`, and contains an `UnsafeCell` exactly where `D` does.
+    unsafe_impl!(D: Immutable, E => Immutable for WireDiscriminant<D, E>);
+    unsafe_impl!(D: IntoBytes, E => IntoBytes for WireDiscriminant<D, E>);
+    unsafe_impl!(D: Unaligned, E => Unaligned for WireDiscriminant<D, E>);
+}
+
 const _64K: usize = 1 << 16;
 
 // TODO(#29), TODO(https://github.com/rust-lang/rust/issues/69835): Remove this
@@ -517,7 +960,7 @@ pub unsafe fn transmute_mut<'dst, 'src: 'dst, Src: 'src, Dst: 'dst>(
 /// [`is_bit_valid`]: TryFromBytes::is_bit_valid
 #[doc(hidden)]
 #[inline]
-fn try_cast_or_pme<Src, Dst, I, R>(
+pub(crate) fn try_cast_or_pme<Src, Dst, I, R>(
     src: Ptr<'_, Src, I>,
 ) -> Result<
     Ptr<'_, Dst, (I::Aliasing, invariant::Unknown, invariant::Valid)>,
@@ -548,6 +991,7 @@ where
         Ok(ptr) => Ok(ptr),
         Err(err) => {
             // Re-cast `Ptr<Dst>` to `Ptr<Src>`.
+            let mismatched_constant = err.mismatched_constant();
             let ptr = err.into_src();
             // SAFETY: This is a pointer cast, satisfying the following
             // properties:
@@ -561,7 +1005,8 @@ where
             let ptr = unsafe { ptr.assume_alignment::<I::Alignment>() };
             // SAFETY: `ptr` is `src` and has the same validity invariant.
             let ptr = unsafe { ptr.assume_validity::<I::Validity>() };
-            Err(ValidityError::new(ptr.unify_invariants()))
+            Err(ValidityError::new(ptr.unify_invariants())
+                .with_mismatched_constant(mismatched_constant))
         }
     }
 }
@@ -659,6 +1104,688 @@ where
     }
 }
 
+/// Computes the length of the `[Dst]` which occupies the same bytes as a
+/// `[Src]` with `len` elements.
+///
+/// A helper for `transmute_slice!` and friends.
+///
+/// # Panics
+///
+/// `transmute_slice_len` produces a post-monomorphization error if `Src` or
+/// `Dst` is zero-sized, if `size_of::<Dst>()` does not evenly divide
+/// `size_of::<Src>()`, or if `Dst` has a stricter alignment requirement than
+/// `Src`.
+#[inline(always)]
+const fn transmute_slice_len<Src, Dst>(len: usize) -> usize {
+    static_assert!(Src, Dst => mem::size_of::<Src>() != 0
+        && matches!(mem::size_of::<Src>().checked_rem(mem::size_of::<Dst>()), Some(0)),
+        "the size of the destination element type must be non-zero and evenly divide the size of the source element type");
+    static_assert!(Src, Dst => mem::align_of::<Dst>() <= mem::align_of::<Src>(),
+        "the destination element type must not have a stricter alignment requirement than the source element type");
+
+    // Clippy: Neither operation can overflow or panic. We assert above that
+    // `size_of::<Dst>() != 0`, and so the division cannot panic. The product
+    // is the number of `Dst`s which fit in `len * size_of::<Src>()` bytes. The
+    // caller is transmuting an existing `[Src]` of `len` elements, and so that
+    // byte count is no larger than `isize::MAX`.
+    #[allow(clippy::arithmetic_side_effects)]
+    let dst_len = len * (mem::size_of::<Src>() / mem::size_of::<Dst>());
+    dst_len
+}
+
+/// Transmutes a slice of one type to a slice of another type.
+///
+/// A helper for `transmute_slice!`.
+///
+/// # Panics
+///
+/// `transmute_slice` produces a post-monomorphization error under the same
+/// circumstances as `transmute_slice_len`.
+#[inline(always)]
+pub const fn transmute_slice<
+    'dst,
+    'src: 'dst,
+    Src: 'src + IntoBytes + Immutable,
+    Dst: 'dst + FromBytes + Immutable,
+>(
+    src: &'src [Src],
+) -> &'dst [Dst] {
+    let len = transmute_slice_len::<Src, Dst>(src.len());
+    let dst = src.as_ptr().cast::<Dst>();
+    // SAFETY:
+    // - `dst` is non-null and is valid for reads of `len * size_of::<Dst>()`
+    //   bytes because it is derived from `src`, and `transmute_slice_len`
+    //   guarantees that `len * size_of::<Dst>() == src.len() *
+    //   size_of::<Src>()`.
+    // - `transmute_slice_len` guarantees that `align_of::<Src>() >=
+    //   align_of::<Dst>()`, and so `dst` is validly aligned for `Dst`.
+    // - We know that it is sound to view the referent of `src` as a `[Dst]`
+    //   because `Src: IntoBytes` and `Dst: FromBytes`.
+    // - We know that there are no `UnsafeCell`s, and thus we don't have to
+    //   worry about `UnsafeCell` overlap, because `Src: Immutable` and `Dst:
+    //   Immutable`.
+    // - We know that the returned lifetime will not outlive the input lifetime
+    //   thanks to the lifetime bounds on this function.
+    unsafe { core::slice::from_raw_parts(dst, len) }
+}
+
+/// Transmutes a mutable slice of one type to a mutable slice of another type.
+///
+/// A helper for `transmute_slice_mut!`.
+///
+/// # Panics
+///
+/// `transmute_slice_mut` produces a post-monomorphization error under the same
+/// circumstances as `transmute_slice_len`.
+#[inline(always)]
+pub fn transmute_slice_mut<
+    'dst,
+    'src: 'dst,
+    Src: 'src + FromBytes + IntoBytes,
+    Dst: 'dst + FromBytes + IntoBytes,
+>(
+    src: &'src mut [Src],
+) -> &'dst mut [Dst] {
+    let len = transmute_slice_len::<Src, Dst>(src.len());
+    let dst = src.as_mut_ptr().cast::<Dst>();
+    // SAFETY:
+    // - `dst` is non-null and is valid for reads and writes of `len *
+    //   size_of::<Dst>()` bytes because it is derived from `src`, and
+    //   `transmute_slice_len` guarantees that `len * size_of::<Dst>() ==
+    //   src.len() * size_of::<Src>()`.
+    // - `transmute_slice_len` guarantees that `align_of::<Src>() >=
+    //   align_of::<Dst>()`, and so `dst` is validly aligned for `Dst`.
+    // - We know that it is sound to view the referent of `src` as a `[Dst]`
+    //   and vice-versa because `Src: FromBytes + IntoBytes` and `Dst:
+    //   FromBytes + IntoBytes`.
+    // - We know that the returned lifetime will not outlive the input lifetime
+    //   thanks to the lifetime bounds on this function.
+    unsafe { core::slice::from_raw_parts_mut(dst, len) }
+}
+
+/// Is a given source a valid instance of `[Dst]`?
+///
+/// If so, returns `src` casted to a `Ptr<[Dst], _>`. Otherwise returns the
+/// original `src` in a `ValidityError`.
+///
+/// # Safety
+///
+/// Unsafe code may assume that, if `try_cast_slice_or_pme(src)` returns `Ok`,
+/// `*src` is a bit-valid instance of `[Dst]` which addresses the same bytes as
+/// `src`.
+///
+/// # Panics
+///
+/// `try_cast_slice_or_pme` produces a post-monomorphization error under the
+/// same circumstances as `transmute_slice_len`. Otherwise,
+/// `try_cast_slice_or_pme` panics under the same circumstances as
+/// [`is_bit_valid`].
+///
+/// [`is_bit_valid`]: TryFromBytes::is_bit_valid
+#[inline]
+fn try_cast_slice_or_pme<Src, Dst, I, R>(
+    src: Ptr<'_, [Src], I>,
+) -> Result<
+    Ptr<'_, [Dst], (I::Aliasing, invariant::Unknown, invariant::Valid)>,
+    ValidityError<Ptr<'_, [Src], I>, [Dst]>,
+>
+where
+    Src: IntoBytes,
+    [Src]: invariant::Read<I::Aliasing, R>,
+    Dst: TryFromBytes,
+    [Dst]: invariant::Read<I::Aliasing, R>,
+    I: Invariants<Validity = invariant::Valid>,
+    I::Aliasing: invariant::Reference,
+{
+    let src_len = src.len();
+    let dst_len = transmute_slice_len::<Src, Dst>(src_len);
+
+    // SAFETY: This is a pointer cast, satisfying the following properties:
+    // - `slice_from_raw_parts_mut(p.cast::<Dst>(), dst_len)` addresses the
+    //   same bytes as `p`, because `transmute_slice_len` guarantees that
+    //   `dst_len * size_of::<Dst>() == src_len * size_of::<Src>()`.
+    // - `p.cast::<Dst>()` and `slice_from_raw_parts_mut` preserve provenance.
+    let c_ptr = unsafe {
+        src.cast_unsized(|p| core::ptr::slice_from_raw_parts_mut(p.cast::<Dst>(), dst_len))
+    };
+
+    // SAFETY: `c_ptr` is derived from `src` which is `IntoBytes`. By
+    // invariant on `IntoByte`s, `c_ptr`'s referent consists entirely of
+    // initialized bytes.
+    let c_ptr = unsafe { c_ptr.assume_initialized() };
+
+    match c_ptr.try_into_valid() {
+        Ok(ptr) => Ok(ptr),
+        Err(err) => {
+            // Re-cast `Ptr<[Dst]>` to `Ptr<[Src]>`.
+            let mismatched_constant = err.mismatched_constant();
+            let ptr = err.into_src();
+            // SAFETY: This is a pointer cast, satisfying the following
+            // properties:
+            // - `slice_from_raw_parts_mut(p.cast::<Src>(), src_len)` addresses
+            //   the same bytes as `ptr`, which addresses the same bytes as
+            //   `src`.
+            // - `p.cast::<Src>()` and `slice_from_raw_parts_mut` preserve
+            //   provenance.
+            let ptr = unsafe {
+                ptr.cast_unsized(|p| core::ptr::slice_from_raw_parts_mut(p.cast::<Src>(), src_len))
+            };
+            // SAFETY: `ptr` is `src`, and has the same alignment invariant.
+            let ptr = unsafe { ptr.assume_alignment::<I::Alignment>() };
+            // SAFETY: `ptr` is `src` and has the same validity invariant.
+            let ptr = unsafe { ptr.assume_validity::<I::Validity>() };
+            Err(ValidityError::new(ptr.unify_invariants())
+                .with_mismatched_constant(mismatched_constant))
+        }
+    }
+}
+
+/// Attempts to transmute `&[Src]` into `&[Dst]`.
+///
+/// A helper for `try_transmute_slice!`.
+///
+/// # Panics
+///
+/// `try_transmute_slice` produces a post-monomorphization error under the same
+/// circumstances as `transmute_slice_len`. Otherwise, `try_transmute_slice`
+/// panics under the same circumstances as [`is_bit_valid`].
+///
+/// [`is_bit_valid`]: TryFromBytes::is_bit_valid
+#[inline(always)]
+pub fn try_transmute_slice<Src, Dst>(src: &[Src]) -> Result<&[Dst], ValidityError<&[Src], [Dst]>>
+where
+    Src: IntoBytes + Immutable,
+    Dst: TryFromBytes + Immutable,
+{
+    match try_cast_slice_or_pme::<Src, Dst, _, BecauseImmutable>(Ptr::from_ref(src)) {
+        Ok(ptr) => {
+            // SAFETY: `try_cast_slice_or_pme` checks that `Dst` does not have a
+            // stricter alignment requirement than `Src`.
+            let ptr = unsafe { ptr.assume_alignment::<invariant::Aligned>() };
+            Ok(ptr.as_ref())
+        }
+        Err(err) => Err(err.map_src(Ptr::as_ref)),
+    }
+}
+
+/// Attempts to transmute `&mut [Src]` into `&mut [Dst]`.
+///
+/// A helper for `try_transmute_slice_mut!`.
+///
+/// # Panics
+///
+/// `try_transmute_slice_mut` produces a post-monomorphization error under the
+/// same circumstances as `transmute_slice_len`. Otherwise,
+/// `try_transmute_slice_mut` panics under the same circumstances as
+/// [`is_bit_valid`].
+///
+/// [`is_bit_valid`]: TryFromBytes::is_bit_valid
+#[inline(always)]
+pub fn try_transmute_slice_mut<Src, Dst>(
+    src: &mut [Src],
+) -> Result<&mut [Dst], ValidityError<&mut [Src], [Dst]>>
+where
+    Src: FromBytes + IntoBytes,
+    Dst: TryFromBytes + IntoBytes,
+{
+    match try_cast_slice_or_pme::<Src, Dst, _, BecauseExclusive>(Ptr::from_mut(src)) {
+        Ok(ptr) => {
+            // SAFETY: `try_cast_slice_or_pme` checks that `Dst` does not have a
+            // stricter alignment requirement than `Src`.
+            let ptr = unsafe { ptr.assume_alignment::<invariant::Aligned>() };
+            Ok(ptr.as_mut())
+        }
+        Err(err) => Err(err.map_src(Ptr::as_mut)),
+    }
+}
+
+/// Can every `Src` be reinterpreted in place as a `Dst` of exactly the same
+/// size and alignment?
+///
+/// This holds if `Src` and `Dst` have the same alignment, and either:
+/// - `Src` is sized, and some `Dst` has exactly the size of `Src`, or
+/// - `Src` and `Dst` are both slice DSTs whose trailing slices begin at the
+///   same offset, and the size of `Dst`'s trailing slice element is non-zero
+///   and evenly divides the size of `Src`'s.
+#[cfg(feature = "alloc")]
+const fn is_transmutable_in_place(src: DstLayout, dst: DstLayout) -> bool {
+    if src.align.get() != dst.align.get() {
+        return false;
+    }
+
+    match (src.size_info, dst.size_info) {
+        (_, SizeInfo::SliceDst(TrailingSliceLayout { elem_size: 0, .. })) => false,
+        (SizeInfo::Sized { size }, _) => matches!(
+            dst.validate_cast_and_convert_metadata(0, size, CastType::Prefix),
+            Ok((_, split_at)) if split_at == size
+        ),
+        (SizeInfo::SliceDst(src), SizeInfo::SliceDst(dst)) => {
+            src.offset == dst.offset && matches!(src.elem_size.checked_rem(dst.elem_size), Some(0))
+        }
+        (SizeInfo::SliceDst(_), SizeInfo::Sized { .. }) => false,
+    }
+}
+
+/// Can some `Src` be reinterpreted in place as a `Dst` of exactly the same
+/// size and alignment?
+///
+/// This holds if `Src` and `Dst` have the same alignment, `Dst` is not a slice
+/// DST whose trailing slice element is zero-sized, and `Src` and `Dst` do not
+/// have different sizes if they are both sized.
+#[cfg(feature = "alloc")]
+const fn may_be_transmutable_in_place(src: DstLayout, dst: DstLayout) -> bool {
+    if src.align.get() != dst.align.get() {
+        return false;
+    }
+
+    match (src.size_info, dst.size_info) {
+        (_, SizeInfo::SliceDst(TrailingSliceLayout { elem_size: 0, .. })) => false,
+        (SizeInfo::Sized { size: src }, SizeInfo::Sized { size: dst }) => src == dst,
+        _ => true,
+    }
+}
+
+/// Asserts that every `Src` can be reinterpreted in place as a `Dst`.
+///
+/// A helper for `transmute_box!`, `transmute_rc!`, and `transmute_arc!`.
+///
+/// # Panics
+///
+/// `assert_transmutable_in_place` produces a post-monomorphization error
+/// unless `is_transmutable_in_place(Src::LAYOUT, Dst::LAYOUT)`.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn assert_transmutable_in_place<Src, Dst>()
+where
+    Src: ?Sized + KnownLayout,
+    Dst: ?Sized + KnownLayout,
+{
+    static_assert!(Src: ?Sized + KnownLayout, Dst: ?Sized + KnownLayout => is_transmutable_in_place(Src::LAYOUT, Dst::LAYOUT),
+        "the source and destination types must have the same alignment, and every value of the source type must have the same size as some value of the destination type");
+}
+
+/// Asserts that some `Src` can be reinterpreted in place as a `Dst`.
+///
+/// A helper for `try_transmute_box!`, `try_transmute_rc!`, and
+/// `try_transmute_arc!`.
+///
+/// # Panics
+///
+/// `assert_may_be_transmutable_in_place` produces a post-monomorphization
+/// error unless `may_be_transmutable_in_place(Src::LAYOUT, Dst::LAYOUT)`.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn assert_may_be_transmutable_in_place<Src, Dst>()
+where
+    Src: ?Sized + KnownLayout,
+    Dst: ?Sized + KnownLayout,
+{
+    static_assert!(Src: ?Sized + KnownLayout, Dst: ?Sized + KnownLayout => may_be_transmutable_in_place(Src::LAYOUT, Dst::LAYOUT),
+        "the source and destination types must have the same alignment and, if both are sized, the same size");
+}
+
+/// Computes the pointer metadata of the `Dst` which has the same size as `src`.
+///
+/// The caller must have asserted `is_transmutable_in_place(Src::LAYOUT,
+/// Dst::LAYOUT)`, in which case such a `Dst` exists, and the returned metadata
+/// is the same as that computed by `same_size_metadata`.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn transmuted_metadata<Src, Dst>(src: &Src) -> Dst::PointerMetadata
+where
+    Src: ?Sized,
+    Dst: ?Sized + KnownLayout,
+{
+    let elems = match Dst::LAYOUT.size_info {
+        SizeInfo::Sized { .. } => 0,
+        SizeInfo::SliceDst(TrailingSliceLayout { offset, elem_size }) => {
+            // Clippy: Per `is_transmutable_in_place`, `elem_size` is non-zero,
+            // and so the division cannot panic. If `Src` is sized, some `Dst`
+            // has the size of `src`, which is thus at least `offset`. If `Src`
+            // is a slice DST, its trailing slice begins at `offset`, and so
+            // the size of `src` is at least `offset`. Thus, the subtraction
+            // cannot underflow.
+            #[allow(clippy::arithmetic_side_effects)]
+            let elems = (mem::size_of_val(src) - offset) / elem_size;
+            elems
+        }
+    };
+    <Dst::PointerMetadata as PointerMetadata>::from_elem_count(elems)
+}
+
+/// Computes the pointer metadata of a `Dst` which has the same size as `src`.
+///
+/// Returns `None` if no `Dst` has the same size as `src`. The caller must have
+/// asserted `may_be_transmutable_in_place(Src::LAYOUT, Dst::LAYOUT)`.
+///
+/// # Safety
+///
+/// Unsafe code may assume that, if `same_size_metadata(src)` returns
+/// `Some(meta)`, then a `Dst` with pointer metadata `meta` has the same size
+/// as `src`.
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn same_size_metadata<Src, Dst>(src: &Src) -> Option<Dst::PointerMetadata>
+where
+    Src: ?Sized,
+    Dst: ?Sized + KnownLayout,
+{
+    let size = mem::size_of_val(src);
+    // `src` is validly aligned for `Src`, and thus for `Dst`, which has the
+    // same alignment. We pass an address of 0 so that only the size is
+    // checked.
+    match Dst::LAYOUT.validate_cast_and_convert_metadata(0, size, CastType::Prefix) {
+        Ok((elems, split_at)) if split_at == size => {
+            Some(<Dst::PointerMetadata as PointerMetadata>::from_elem_count(elems))
+        }
+        _ => None,
+    }
+}
+
+/// Is the referent of `src` a valid `Dst` with pointer metadata `meta`?
+///
+/// On failure, returns the name of the field which did not hold its required
+/// constant, if known.
+///
+/// # Safety
+///
+/// The caller promises that a `Dst` with pointer metadata `meta` has the same
+/// size as `*src`.
+///
+/// # Panics
+///
+/// `is_valid_in_place` panics under the same circumstances as
+/// [`is_bit_valid`].
+///
+/// [`is_bit_valid`]: TryFromBytes::is_bit_valid
+#[cfg(feature = "alloc")]
+#[inline(always)]
+unsafe fn is_valid_in_place<'a, Src, Dst, I, R>(
+    src: Ptr<'a, Src, I>,
+    meta: Dst::PointerMetadata,
+) -> Result<(), Option<&'static str>>
+where
+    Src: ?Sized + IntoBytes + invariant::Read<I::Aliasing, R>,
+    Dst: 'a + ?Sized + KnownLayout + TryFromBytes + invariant::Read<I::Aliasing, R>,
+    I: Invariants<Validity = invariant::Valid>,
+    I::Aliasing: invariant::Reference,
+{
+    // SAFETY: This is a pointer cast, satisfying the following properties:
+    // - `raw_from_ptr_len` returns a pointer with the same address and
+    //   provenance as `p`, and `NonNull::cast` preserves address and
+    //   provenance. Since the caller promises that a `Dst` with pointer
+    //   metadata `meta` has the same size as `*src`, the returned pointer
+    //   addresses exactly the bytes addressed by `p`.
+    // - `p` is derived from a `Ptr`, and so it is non-null.
+    let c_ptr = unsafe {
+        src.cast_unsized::<Dst, _, R, R>(|p| {
+            Dst::raw_from_ptr_len(NonNull::new_unchecked(p).cast::<u8>(), meta).as_ptr()
+        })
+    };
+
+    // SAFETY: `c_ptr` addresses exactly the bytes of `src`, which is
+    // `IntoBytes`. By invariant on `IntoBytes`, `c_ptr`'s referent consists
+    // entirely of initialized bytes.
+    let c_ptr = unsafe { c_ptr.assume_initialized() };
+
+    match c_ptr.try_into_valid() {
+        Ok(_) => Ok(()),
+        Err(err) => Err(err.mismatched_constant()),
+    }
+}
+
+/// Transmutes a `Box<Src>` into a `Box<Dst>`.
+///
+/// A helper for `transmute_box!`.
+///
+/// # Panics
+///
+/// `transmute_box` produces a post-monomorphization error unless every `Src`
+/// can be reinterpreted in place as a `Dst` of the same size and alignment.
+#[cfg(feature = "alloc")]
+#[must_use]
+#[inline(always)]
+pub fn transmute_box<Src, Dst>(src: Box<Src>) -> Box<Dst>
+where
+    Src: ?Sized + KnownLayout + IntoBytes,
+    Dst: ?Sized + KnownLayout + FromBytes,
+{
+    assert_transmutable_in_place::<Src, Dst>();
+    let meta = transmuted_metadata::<Src, Dst>(&*src);
+    let dst = Dst::raw_from_ptr_len(NonNull::from(Box::leak(src)).cast::<u8>(), meta);
+    // SAFETY: `dst` has the same address and provenance as a pointer returned
+    // by `Box::leak`, and so it is valid to convert it back into a `Box`
+    // "given that the `Layout` used with the allocator is correct for the
+    // type" [1]. `Src` and `Dst` have the same alignment (asserted above), and
+    // by postcondition on `transmuted_metadata`, `*dst` has the same size as
+    // the `Src`. Thus, `*dst` has the layout with which the allocation was
+    // made. Since `Src: IntoBytes` and `Dst: FromBytes`, the referent of `dst`
+    // is a valid `Dst`.
+    //
+    // [1] https://doc.rust-lang.org/1.82.0/std/boxed/index.html#memory-layout
+    unsafe { Box::from_raw(dst.as_ptr()) }
+}
+
+/// Attempts to transmute a `Box<Src>` into a `Box<Dst>`.
+///
+/// A helper for `try_transmute_box!`.
+///
+/// # Panics
+///
+/// `try_transmute_box` produces a post-monomorphization error unless `Src`
+/// and `Dst` have the same alignment and, if both are sized, the same size.
+/// Otherwise, `try_transmute_box` panics under the same circumstances as
+/// [`is_bit_valid`].
+///
+/// [`is_bit_valid`]: TryFromBytes::is_bit_valid
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn try_transmute_box<Src, Dst>(
+    mut src: Box<Src>,
+) -> Result<Box<Dst>, TryReadError<Box<Src>, Dst>>
+where
+    Src: ?Sized + KnownLayout + IntoBytes,
+    Dst: ?Sized + KnownLayout + TryFromBytes,
+{
+    assert_may_be_transmutable_in_place::<Src, Dst>();
+    let meta = match same_size_metadata::<Src, Dst>(&*src) {
+        Some(meta) => meta,
+        None => return Err(SizeError::new(src).into()),
+    };
+    // SAFETY: By postcondition on `same_size_metadata`, a `Dst` with pointer
+    // metadata `meta` has the same size as `*src`.
+    let is_valid = unsafe {
+        is_valid_in_place::<Src, Dst, _, BecauseExclusive>(Ptr::from_mut(&mut *src), meta)
+    };
+    if let Err(mismatched_constant) = is_valid {
+        return Err(ValidityError::new(src).with_mismatched_constant(mismatched_constant).into());
+    }
+
+    let dst = Dst::raw_from_ptr_len(NonNull::from(Box::leak(src)).cast::<u8>(), meta);
+    // SAFETY: `dst` has the same address and provenance as a pointer returned
+    // by `Box::leak`, and so it is valid to convert it back into a `Box`
+    // "given that the `Layout` used with the allocator is correct for the
+    // type" [1]. `Src` and `Dst` have the same alignment (asserted above), and
+    // by postcondition on `same_size_metadata`, `*dst` has the same size as
+    // the `Src`. Thus, `*dst` has the layout with which the allocation was
+    // made. We just validated that the referent of `dst` is a valid `Dst`.
+    //
+    // [1] https://doc.rust-lang.org/1.82.0/std/boxed/index.html#memory-layout
+    Ok(unsafe { Box::from_raw(dst.as_ptr()) })
+}
+
+/// Transmutes an `Rc<Src>` into an `Rc<Dst>`.
+///
+/// A helper for `transmute_rc!`.
+///
+/// # Panics
+///
+/// `transmute_rc` produces a post-monomorphization error unless every `Src`
+/// can be reinterpreted in place as a `Dst` of the same size and alignment.
+#[cfg(feature = "alloc")]
+#[must_use]
+#[inline(always)]
+pub fn transmute_rc<Src, Dst>(src: Rc<Src>) -> Rc<Dst>
+where
+    Src: ?Sized + KnownLayout + IntoBytes + Immutable,
+    Dst: ?Sized + KnownLayout + FromBytes + Immutable,
+{
+    assert_transmutable_in_place::<Src, Dst>();
+    let meta = transmuted_metadata::<Src, Dst>(&*src);
+    // SAFETY: `Rc::into_raw` never returns a null pointer.
+    let src = unsafe { NonNull::new_unchecked(Rc::into_raw(src).cast_mut()) };
+    let dst = Dst::raw_from_ptr_len(src.cast::<u8>(), meta);
+    // SAFETY: `dst` has the same address and provenance as a pointer returned
+    // by `Rc::<Src>::into_raw`. `Src` and `Dst` have the same alignment
+    // (asserted above), and by postcondition on `transmuted_metadata`, `*dst`
+    // has the same size as the `Src`, as required by `Rc::from_raw` [1]. Since
+    // `Src: IntoBytes` and `Dst: FromBytes`, the referent of `dst` is a valid
+    // `Dst`. Any other `Rc<Src>`s or `Weak<Src>`s which share this allocation
+    // may only observe it immutably while the returned `Rc<Dst>` exists, and
+    // vice versa, and `Src: Immutable` and `Dst: Immutable` ensure that
+    // neither type permits interior mutation. Thus, the referent remains a
+    // valid `Src` and a valid `Dst` for as long as it is shared.
+    //
+    // [1] https://doc.rust-lang.org/1.82.0/alloc/rc/struct.Rc.html#method.from_raw
+    unsafe { Rc::from_raw(dst.as_ptr()) }
+}
+
+/// Attempts to transmute an `Rc<Src>` into an `Rc<Dst>`.
+///
+/// A helper for `try_transmute_rc!`.
+///
+/// # Panics
+///
+/// `try_transmute_rc` produces a post-monomorphization error unless `Src` and
+/// `Dst` have the same alignment and, if both are sized, the same size.
+/// Otherwise, `try_transmute_rc` panics under the same circumstances as
+/// [`is_bit_valid`].
+///
+/// [`is_bit_valid`]: TryFromBytes::is_bit_valid
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn try_transmute_rc<Src, Dst>(src: Rc<Src>) -> Result<Rc<Dst>, TryReadError<Rc<Src>, Dst>>
+where
+    Src: ?Sized + KnownLayout + IntoBytes + Immutable,
+    Dst: ?Sized + KnownLayout + TryFromBytes + Immutable,
+{
+    assert_may_be_transmutable_in_place::<Src, Dst>();
+    let meta = match same_size_metadata::<Src, Dst>(&*src) {
+        Some(meta) => meta,
+        None => return Err(SizeError::new(src).into()),
+    };
+    // SAFETY: By postcondition on `same_size_metadata`, a `Dst` with pointer
+    // metadata `meta` has the same size as `*src`.
+    let is_valid =
+        unsafe { is_valid_in_place::<Src, Dst, _, BecauseImmutable>(Ptr::from_ref(&*src), meta) };
+    if let Err(mismatched_constant) = is_valid {
+        return Err(ValidityError::new(src).with_mismatched_constant(mismatched_constant).into());
+    }
+
+    // SAFETY: `Rc::into_raw` never returns a null pointer.
+    let src = unsafe { NonNull::new_unchecked(Rc::into_raw(src).cast_mut()) };
+    let dst = Dst::raw_from_ptr_len(src.cast::<u8>(), meta);
+    // SAFETY: `dst` has the same address and provenance as a pointer returned
+    // by `Rc::<Src>::into_raw`. `Src` and `Dst` have the same alignment
+    // (asserted above), and by postcondition on `same_size_metadata`, `*dst`
+    // has the same size as the `Src`, as required by `Rc::from_raw` [1]. We
+    // just validated that the referent of `dst` is a valid `Dst`. Any other
+    // `Rc<Src>`s or `Weak<Src>`s which share this allocation may only observe
+    // it immutably while the returned `Rc<Dst>` exists, and vice versa, and
+    // `Src: Immutable` and `Dst: Immutable` ensure that neither type permits
+    // interior mutation. Thus, the referent remains a valid `Src` and a valid
+    // `Dst` for as long as it is shared.
+    //
+    // [1] https://doc.rust-lang.org/1.82.0/alloc/rc/struct.Rc.html#method.from_raw
+    Ok(unsafe { Rc::from_raw(dst.as_ptr()) })
+}
+
+/// Transmutes an `Arc<Src>` into an `Arc<Dst>`.
+///
+/// A helper for `transmute_arc!`.
+///
+/// # Panics
+///
+/// `transmute_arc` produces a post-monomorphization error unless every `Src`
+/// can be reinterpreted in place as a `Dst` of the same size and alignment.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[must_use]
+#[inline(always)]
+pub fn transmute_arc<Src, Dst>(src: Arc<Src>) -> Arc<Dst>
+where
+    Src: ?Sized + KnownLayout + IntoBytes + Immutable,
+    Dst: ?Sized + KnownLayout + FromBytes + Immutable,
+{
+    assert_transmutable_in_place::<Src, Dst>();
+    let meta = transmuted_metadata::<Src, Dst>(&*src);
+    // SAFETY: `Arc::into_raw` never returns a null pointer.
+    let src = unsafe { NonNull::new_unchecked(Arc::into_raw(src).cast_mut()) };
+    let dst = Dst::raw_from_ptr_len(src.cast::<u8>(), meta);
+    // SAFETY: `dst` has the same address and provenance as a pointer returned
+    // by `Arc::<Src>::into_raw`. `Src` and `Dst` have the same alignment
+    // (asserted above), and by postcondition on `transmuted_metadata`, `*dst`
+    // has the same size as the `Src`, as required by `Arc::from_raw` [1].
+    // Since `Src: IntoBytes` and `Dst: FromBytes`, the referent of `dst` is a
+    // valid `Dst`. Any other `Arc<Src>`s or `Weak<Src>`s which share this
+    // allocation may only observe it immutably while the returned `Arc<Dst>`
+    // exists, and vice versa, and `Src: Immutable` and `Dst: Immutable`
+    // ensure that neither type permits interior mutation. Thus, the referent
+    // remains a valid `Src` and a valid `Dst` for as long as it is shared.
+    //
+    // [1] https://doc.rust-lang.org/1.82.0/alloc/sync/struct.Arc.html#method.from_raw
+    unsafe { Arc::from_raw(dst.as_ptr()) }
+}
+
+/// Attempts to transmute an `Arc<Src>` into an `Arc<Dst>`.
+///
+/// A helper for `try_transmute_arc!`.
+///
+/// # Panics
+///
+/// `try_transmute_arc` produces a post-monomorphization error unless `Src`
+/// and `Dst` have the same alignment and, if both are sized, the same size.
+/// Otherwise, `try_transmute_arc` panics under the same circumstances as
+/// [`is_bit_valid`].
+///
+/// [`is_bit_valid`]: TryFromBytes::is_bit_valid
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[inline(always)]
+pub fn try_transmute_arc<Src, Dst>(src: Arc<Src>) -> Result<Arc<Dst>, TryReadError<Arc<Src>, Dst>>
+where
+    Src: ?Sized + KnownLayout + IntoBytes + Immutable,
+    Dst: ?Sized + KnownLayout + TryFromBytes + Immutable,
+{
+    assert_may_be_transmutable_in_place::<Src, Dst>();
+    let meta = match same_size_metadata::<Src, Dst>(&*src) {
+        Some(meta) => meta,
+        None => return Err(SizeError::new(src).into()),
+    };
+    // SAFETY: By postcondition on `same_size_metadata`, a `Dst` with pointer
+    // metadata `meta` has the same size as `*src`.
+    let is_valid =
+        unsafe { is_valid_in_place::<Src, Dst, _, BecauseImmutable>(Ptr::from_ref(&*src), meta) };
+    if let Err(mismatched_constant) = is_valid {
+        return Err(ValidityError::new(src).with_mismatched_constant(mismatched_constant).into());
+    }
+
+    // SAFETY: `Arc::into_raw` never returns a null pointer.
+    let src = unsafe { NonNull::new_unchecked(Arc::into_raw(src).cast_mut()) };
+    let dst = Dst::raw_from_ptr_len(src.cast::<u8>(), meta);
+    // SAFETY: `dst` has the same address and provenance as a pointer returned
+    // by `Arc::<Src>::into_raw`. `Src` and `Dst` have the same alignment
+    // (asserted above), and by postcondition on `same_size_metadata`, `*dst`
+    // has the same size as the `Src`, as required by `Arc::from_raw` [1]. We
+    // just validated that the referent of `dst` is a valid `Dst`. Any other
+    // `Arc<Src>`s or `Weak<Src>`s which share this allocation may only observe
+    // it immutably while the returned `Arc<Dst>` exists, and vice versa, and
+    // `Src: Immutable` and `Dst: Immutable` ensure that neither type permits
+    // interior mutation. Thus, the referent remains a valid `Src` and a valid
+    // `Dst` for as long as it is shared.
+    //
+    // [1] https://doc.rust-lang.org/1.82.0/alloc/sync/struct.Arc.html#method.from_raw
+    Ok(unsafe { Arc::from_raw(dst.as_ptr()) })
+}
+
 /// A function which emits a warning if its return value is not used.
 #[must_use]
 #[inline(always)]
@@ -679,6 +1806,11 @@ pub mod core_reexport {
     }
 }
 
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+pub use alloc::{boxed::Box, rc::Rc};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,20 +499,20 @@ macro_rules! impl_or_verify {
 
 /// Implements `KnownLayout` for a sized type.
 macro_rules! impl_known_layout {
-    ($(const $constvar:ident : $constty:ty, $tyvar:ident $(: ?$optbound:ident)? => $ty:ty),* $(,)?) => {
-        $(impl_known_layout!(@inner const $constvar: $constty, $tyvar $(: ?$optbound)? => $ty);)*
+    ($(const $constvar:ident : $constty:ty, $tyvar:ident $(: $(?$optbound:ident)? $($bound:ident)?)? => $ty:ty),* $(,)?) => {
+        $(impl_known_layout!(@inner const $constvar: $constty, $tyvar $(: $(?$optbound)? $($bound)?)? => $ty);)*
     };
     ($($tyvar:ident $(: ?$optbound:ident)? => $ty:ty),* $(,)?) => {
         $(impl_known_layout!(@inner , $tyvar $(: ?$optbound)? => $ty);)*
     };
     ($($ty:ty),*) => { $(impl_known_layout!(@inner , => $ty);)* };
-    (@inner $(const $constvar:ident : $constty:ty)? , $($tyvar:ident $(: ?$optbound:ident)?)? => $ty:ty) => {
+    (@inner $(const $constvar:ident : $constty:ty)? , $($tyvar:ident $(: $(?$optbound:ident)? $($bound:ident)?)?)? => $ty:ty) => {
         const _: () = {
             use core::ptr::NonNull;
 
             #[allow(non_local_definitions)]
             // SAFETY: Delegates safety to `DstLayout::for_type`.
-            unsafe impl<$($tyvar $(: ?$optbound)?)? $(, const $constvar : $constty)?> KnownLayout for $ty {
+            unsafe impl<$($tyvar $(: $(?$optbound)? $($bound)?)?)? $(, const $constvar : $constty)?> KnownLayout for $ty {
                 #[allow(clippy::missing_inline_in_public_items)]
                 #[cfg_attr(coverage_nightly, coverage(off))]
                 fn only_derive_is_allowed_to_implement_this_trait() where Self: Sized {}
@@ -605,8 +605,9 @@ macro_rules! assert_unaligned {
 }
 
 /// Asserts at compile time that `$condition` is true for `Self` or the given
-/// `$tyvar`s. Unlike `assert!`, this is *strictly* a compile-time check; it
-/// cannot be evaluated in a runtime context. The condition is checked after
+/// `$tyvar`s. `Self` may be accompanied by a single `const` generic parameter
+/// of type `usize`. Unlike `assert!`, this is *strictly* a compile-time check;
+/// it cannot be evaluated in a runtime context. The condition is checked after
 /// monomorphization and, upon failure, emits a compile error.
 macro_rules! static_assert {
     (Self $(: $(? $optbound:ident $(+)?)* $($bound:ident $(+)?)* )? => $condition:expr $(, $args:tt)*) => {{
@@ -623,19 +624,33 @@ macro_rules! static_assert {
 
         assert!(<Self as StaticAssert>::ASSERT);
     }};
+    (Self $(: $(? $optbound:ident $(+)?)* $($bound:ident $(+)?)* )?, const $cvar:ident: usize => $condition:expr $(, $args:tt)*) => {{
+        trait StaticAssert<const $cvar: usize> {
+            const ASSERT: bool;
+        }
+
+        impl<T $(: $(? $optbound +)* $($bound +)*)?, const $cvar: usize> StaticAssert<$cvar> for T {
+            const ASSERT: bool = {
+                assert!($condition $(, $args)*);
+                $condition
+            };
+        }
+
+        assert!(<Self as StaticAssert<$cvar>>::ASSERT);
+    }};
     ($($tyvar:ident $(: $(? $optbound:ident $(+)?)* $($bound:ident $(+)?)* )?),* => $condition:expr $(, $args:tt)*) => {{
         trait StaticAssert {
             const ASSERT: bool;
         }
 
-        impl<$($tyvar $(: $(? $optbound +)* $($bound +)*)?,)*> StaticAssert for ($($tyvar,)*) {
+        impl<$($tyvar $(: $(? $optbound +)* $($bound +)*)?,)*> StaticAssert for ($(::core::marker::PhantomData<$tyvar>,)*) {
             const ASSERT: bool = {
                 assert!($condition $(, $args)*);
                 $condition
             };
         }
 
-        assert!(<($($tyvar,)*) as StaticAssert>::ASSERT);
+        assert!(<($(::core::marker::PhantomData<$tyvar>,)*) as StaticAssert>::ASSERT);
     }};
 }
 
@@ -656,6 +671,27 @@ macro_rules! static_assert_dst_is_not_zst {
     }}
 }
 
+/// Invokes `$m!` with a `$chunk:ident => $align:literal` pair for each valid
+/// alignment, where `$chunk` is the name of the corresponding
+/// [`ValidAlignment::Chunk`] type.
+///
+/// [`ValidAlignment::Chunk`]: crate::util::macro_util::ValidAlignment::Chunk
+macro_rules! for_each_alignment {
+    ($m:ident) => {
+        $m!(
+            Chunk1 => 0x1, Chunk2 => 0x2, Chunk4 => 0x4, Chunk8 => 0x8, Chunk16 => 0x10,
+            Chunk32 => 0x20, Chunk64 => 0x40, Chunk128 => 0x80, Chunk256 => 0x100,
+            Chunk512 => 0x200, Chunk1K => 0x400, Chunk2K => 0x800, Chunk4K => 0x1000,
+            Chunk8K => 0x2000, Chunk16K => 0x4000, Chunk32K => 0x8000, Chunk64K => 0x10000,
+            Chunk128K => 0x20000, Chunk256K => 0x40000, Chunk512K => 0x80000,
+            Chunk1M => 0x100000, Chunk2M => 0x200000, Chunk4M => 0x400000,
+            Chunk8M => 0x800000, Chunk16M => 0x1000000, Chunk32M => 0x2000000,
+            Chunk64M => 0x4000000, Chunk128M => 0x8000000, Chunk256M => 0x10000000,
+            Chunk512M => 0x20000000
+        );
+    };
+}
+
 macro_rules! define_because {
     ($(#[$attr:meta])* $vis:vis $name:ident) => {
         #[cfg(__ZEROCOPY_INTERNAL_USE_ONLY_NIGHTLY_FEATURES_IN_TESTS)]
@@ -459,6 +459,24 @@ impl<T: Unaligned + Display> Display for Unalign<T> {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<T: Copy + serde::Serialize> serde::Serialize for Unalign<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Unalign<T> {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Unalign<T>, D::Error> {
+        T::deserialize(deserializer).map(Unalign::new)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::panic::AssertUnwindSafe;
@@ -595,4 +613,16 @@ mod tests {
         assert_eq!(format!("{}", zero), format!("{}", 0u8));
         assert_eq!(format!("{}", one), format!("{}", 1u8));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let u = Unalign::new(0x01020304u32);
+        assert_eq!(serde_json::to_string(&u).unwrap(), "16909060");
+        assert_eq!(serde_json::from_str::<Unalign<u32>>("16909060").unwrap().get(), u.get());
+        assert_eq!(bincode::serialize(&u).unwrap(), bincode::serialize(&0x01020304u32).unwrap());
+
+        let u = Unalign::new(crate::byteorder::U16::<crate::byteorder::BE>::new(7));
+        assert_eq!(serde_json::to_string(&u).unwrap(), "7");
+    }
 }
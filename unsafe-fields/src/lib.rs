@@ -20,14 +20,19 @@
 //! are `unsafe`, which requires any use of an `Unsafe` field to be inside an
 //! `unsafe` block. One exception is [`Unsafe::as_ref`], which is available when
 //! the `zerocopy_0_8` feature is enabled. See its docs for more information.
+//! With that feature enabled, `Unsafe` also implements `Debug`, `PartialEq`,
+//! `Eq`, `PartialOrd`, `Ord`, and `Hash` when its field type is
+//! [immutable][immutable], so that types with unsafe fields can derive them.
 //!
 //! An unsafe field has the type `Unsafe<O, F, const NAME_HASH: u128>`. `O` is
 //! the enclosing type (struct, enum, or union), `F` is the type of the field,
-//! and `NAME_HASH` is the hash of the field's name. `O` prevents swapping
-//! unsafe fields of the same `F` type between different enclosing types, and
-//! `NAME_HASH` prevents swapping different fields of the same `F` type within
-//! the same enclosing type. Note that swapping the same field between instances
-//! of the same type [cannot be prevented](crate#limitations).
+//! and `NAME_HASH` is the hash of the field's name (see [`field_hash`]). `O`
+//! prevents swapping unsafe fields of the same `F` type between different
+//! enclosing types, and `NAME_HASH` prevents swapping different fields of the
+//! same `F` type within the same enclosing type; `unsafe_fields!` rejects types
+//! in which two unsafe fields' hashes collide. Note that swapping the same
+//! field between instances of the same type [cannot be
+//! prevented](crate#limitations).
 //!
 //! [immutable]: zerocopy_0_8::Immutable
 //!
@@ -180,6 +185,10 @@
 )]
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
 
+// zerocopy's derives refer to it as `::zerocopy`.
+#[cfg(feature = "zerocopy_0_8")]
+extern crate zerocopy_0_8 as zerocopy;
+
 use core::marker::PhantomData;
 
 /// A field with safety invariants.
@@ -188,11 +197,16 @@ use core::marker::PhantomData;
 /// declare a type with unsafe fields.
 ///
 /// See the [crate-level documentation](crate) for more information.
+#[cfg_attr(
+    feature = "zerocopy_0_8",
+    derive(zerocopy::KnownLayout, zerocopy::Immutable, zerocopy::IntoBytes, zerocopy::Unaligned)
+)]
 #[repr(transparent)]
 pub struct Unsafe<O: ?Sized, F: ?Sized, const NAME_HASH: u128> {
     _marker: PhantomData<O>,
     // INVARIANT: `field` is only modified via public `unsafe` methods. User code is never
-    // invoked implicitly except via public `unsafe` methods.
+    // invoked implicitly except via public `unsafe` methods, or with a `&F` where `F:
+    // Immutable`, which cannot be used to modify `field`.
     field: F,
 }
 
@@ -267,6 +281,65 @@ impl<O: ?Sized, F: ?Sized, const NAME_HASH: u128> Unsafe<O, F, { NAME_HASH }> {
     }
 }
 
+impl<O: ?Sized, F: ?Sized, const NAME_HASH: u128> Unsafe<O, F, { NAME_HASH }> {
+    /// The hash of the identifier of this field.
+    ///
+    /// See [`field_hash`] for how it is computed.
+    pub const FIELD_HASH: u128 = NAME_HASH;
+
+    /// Returns whether `identifier` identifies this field.
+    ///
+    /// See [`field_hash`] for the identifiers of fields.
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_field(identifier: &str) -> bool {
+        field_hash(identifier) == NAME_HASH
+    }
+
+    /// Returns the first of `identifiers` which identifies this field, if
+    /// any.
+    ///
+    /// Since field hashes cannot be reversed, this allows tooling which knows
+    /// the identifiers of a type's fields to recover which field an `Unsafe`
+    /// belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_fields::{unsafe_fields, Unsafe};
+    ///
+    /// unsafe_fields! {
+    ///     pub struct Range {
+    ///         #[unsafe]
+    ///         lo: usize,
+    ///         #[unsafe]
+    ///         hi: usize,
+    ///     }
+    /// }
+    ///
+    /// fn field_name<O, F, const NAME_HASH: u128>(_: &Unsafe<O, F, NAME_HASH>) -> Option<&str> {
+    ///     Unsafe::<O, F, NAME_HASH>::find_field(&["lo", "hi"])
+    /// }
+    ///
+    /// // SAFETY: `0 <= 1`.
+    /// let range = unsafe { Range { lo: Unsafe::new(0), hi: Unsafe::new(1) } };
+    /// assert_eq!(field_name(&range.hi), Some("hi"));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::indexing_slicing, clippy::arithmetic_side_effects)]
+    pub const fn find_field<'a>(identifiers: &[&'a str]) -> Option<&'a str> {
+        let mut i = 0;
+        while i < identifiers.len() {
+            if Self::is_field(identifiers[i]) {
+                return Some(identifiers[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
 impl<O: ?Sized, F, const NAME_HASH: u128> Unsafe<O, F, { NAME_HASH }> {
     /// Constructs a new `Unsafe`.
     ///
@@ -313,53 +386,540 @@ impl<O: ?Sized, F, const NAME_HASH: u128> Unsafe<O, F, { NAME_HASH }> {
     }
 }
 
+/// Promises that any bit-valid value of an unsafe field's type upholds that
+/// field's safety invariants.
+///
+/// [`Unsafe`] derives zerocopy's [`KnownLayout`], [`Immutable`], [`IntoBytes`],
+/// and [`Unaligned`], since none of these permit constructing or modifying the
+/// field. The latter three are implemented whenever the field type implements
+/// them, and `KnownLayout` whenever the field type is sized. [`TryFromBytes`],
+/// [`FromZeros`], and [`FromBytes`], on the other hand, permit constructing an
+/// `Unsafe` from bytes without calling the `unsafe` [`Unsafe::new`], and so
+/// bypass the field's safety invariants. `Unsafe<O, F, _>` only implements
+/// these traits if its field type does and if its enclosing type, `O`,
+/// implements `BytesUpholdInvariants`.
+///
+/// [`KnownLayout`]: zerocopy_0_8::KnownLayout
+/// [`Immutable`]: zerocopy_0_8::Immutable
+/// [`IntoBytes`]: zerocopy_0_8::IntoBytes
+/// [`Unaligned`]: zerocopy_0_8::Unaligned
+/// [`TryFromBytes`]: zerocopy_0_8::TryFromBytes
+/// [`FromZeros`]: zerocopy_0_8::FromZeros
+/// [`FromBytes`]: zerocopy_0_8::FromBytes
+///
+/// # Safety
+///
+/// By implementing `BytesUpholdInvariants` for a type, you promise that, for
+/// each of its unsafe fields, every bit-valid value of the field's type
+/// upholds the safety invariants associated with that field.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate zerocopy_0_8 as zerocopy;
+/// use unsafe_fields::{unsafe_fields, BytesUpholdInvariants, Unsafe};
+/// use zerocopy::FromBytes;
+/// # use zerocopy_derive::*;
+///
+/// static TABLE: [u32; 256] = [0; 256];
+///
+/// unsafe_fields! {
+///     /// An index into `TABLE`.
+///     #[derive(FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+///     #[repr(C)]
+///     pub struct Index {
+///         // INVARIANT: `i < TABLE.len()`.
+///         #[unsafe]
+///         i: u8,
+///     }
+/// }
+///
+/// // SAFETY: `TABLE` has 256 entries, so every `u8` is a valid index into it.
+/// unsafe impl BytesUpholdInvariants for Index {}
+///
+/// impl Index {
+///     pub fn get(&self) -> u32 {
+///         let i = usize::from(*self.i.as_ref());
+///         // SAFETY: By invariant on `self.i`, `i < TABLE.len()`.
+///         unsafe { *TABLE.get_unchecked(i) }
+///     }
+/// }
+///
+/// let index = Index::read_from_bytes(&[255][..]).unwrap();
+/// assert_eq!(index.get(), 0);
+/// ```
+#[cfg(feature = "zerocopy_0_8")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "zerocopy_0_8")))]
+pub unsafe trait BytesUpholdInvariants {}
+
+#[cfg(feature = "zerocopy_0_8")]
+mod zerocopy_0_8_impls {
+    use core::{
+        cmp::Ordering,
+        fmt::{self, Debug, Formatter},
+        hash::{Hash, Hasher},
+    };
+
+    use zerocopy_0_8::{project, unsafe_impl_items, FromBytes, FromZeros, Immutable, TryFromBytes};
+
+    use super::{BytesUpholdInvariants, Unsafe};
+
+    // SAFETY: `Unsafe<O, F, _>` has the same bit validity as `F`, so
+    // `is_bit_valid` soundly defers to `F`. `O: BytesUpholdInvariants` promises
+    // that every bit-valid `F` upholds the field's safety invariants, so
+    // constructing an `Unsafe` from any bit-valid `F` does not violate them.
+    unsafe impl<O, F, const NAME_HASH: u128> TryFromBytes for Unsafe<O, F, { NAME_HASH }>
+    where
+        O: ?Sized + BytesUpholdInvariants,
+        F: ?Sized + TryFromBytes,
+    {
+        unsafe_impl_items!(|candidate| project!(candidate, Unsafe, field).is_bit_valid());
+    }
+
+    // SAFETY: `Unsafe<O, F, _>` has the same bit validity as `F`, and the
+    // all-zeros bit pattern is a bit-valid `F` since `F: FromZeros`. `O:
+    // BytesUpholdInvariants` promises that it upholds the field's safety
+    // invariants.
+    unsafe impl<O, F, const NAME_HASH: u128> FromZeros for Unsafe<O, F, { NAME_HASH }>
+    where
+        O: ?Sized + BytesUpholdInvariants,
+        F: ?Sized + FromZeros,
+    {
+        unsafe_impl_items!();
+    }
+
+    // SAFETY: `Unsafe<O, F, _>` has the same bit validity as `F`, and every
+    // initialized bit pattern is a bit-valid `F` since `F: FromBytes`. `O:
+    // BytesUpholdInvariants` promises that each of these upholds the field's
+    // safety invariants.
+    unsafe impl<O, F, const NAME_HASH: u128> FromBytes for Unsafe<O, F, { NAME_HASH }>
+    where
+        O: ?Sized + BytesUpholdInvariants,
+        F: ?Sized + FromBytes,
+    {
+        unsafe_impl_items!();
+    }
+
+    // NOTE on design: The following impls invoke `F`'s implementations of
+    // standard library traits, which is user code. They are nonetheless safe,
+    // since they only pass that code a `&F` obtained via `Unsafe::as_ref`,
+    // which requires `F: Immutable`. `F` contains no `UnsafeCell`s, and so the
+    // user code cannot use the `&F` to modify the field and thereby violate its
+    // safety invariants.
+
+    /// Formats the field via [`Unsafe::as_ref`].
+    ///
+    /// Since `F: Immutable`, `F`'s `Debug` impl cannot modify the field.
+    impl<O: ?Sized, F: ?Sized + Immutable + Debug, const NAME_HASH: u128> Debug
+        for Unsafe<O, F, { NAME_HASH }>
+    {
+        #[inline]
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Unsafe").field(&self.as_ref()).finish()
+        }
+    }
+
+    /// Compares the fields via [`Unsafe::as_ref`].
+    ///
+    /// Since `F: Immutable`, `F`'s `PartialEq` impl cannot modify either field.
+    impl<O: ?Sized, F: ?Sized + Immutable + PartialEq, const NAME_HASH: u128> PartialEq
+        for Unsafe<O, F, { NAME_HASH }>
+    {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.as_ref() == other.as_ref()
+        }
+    }
+
+    /// `Eq` has no methods, and so cannot modify the field.
+    impl<O: ?Sized, F: ?Sized + Immutable + Eq, const NAME_HASH: u128> Eq
+        for Unsafe<O, F, { NAME_HASH }>
+    {
+    }
+
+    /// Compares the fields via [`Unsafe::as_ref`].
+    ///
+    /// Since `F: Immutable`, `F`'s `PartialOrd` impl cannot modify either
+    /// field.
+    impl<O: ?Sized, F: ?Sized + Immutable + PartialOrd, const NAME_HASH: u128> PartialOrd
+        for Unsafe<O, F, { NAME_HASH }>
+    {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.as_ref().partial_cmp(other.as_ref())
+        }
+    }
+
+    /// Compares the fields via [`Unsafe::as_ref`].
+    ///
+    /// Since `F: Immutable`, `F`'s `Ord` impl cannot modify either field.
+    impl<O: ?Sized, F: ?Sized + Immutable + Ord, const NAME_HASH: u128> Ord
+        for Unsafe<O, F, { NAME_HASH }>
+    {
+        #[inline]
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.as_ref().cmp(other.as_ref())
+        }
+    }
+
+    /// Hashes the field via [`Unsafe::as_ref`].
+    ///
+    /// Since `F: Immutable`, `F`'s `Hash` impl cannot modify the field. The
+    /// hash is the same as that of the field itself.
+    impl<O: ?Sized, F: ?Sized + Immutable + Hash, const NAME_HASH: u128> Hash
+        for Unsafe<O, F, { NAME_HASH }>
+    {
+        #[inline]
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.as_ref().hash(state);
+        }
+    }
+}
+
+/// The version of the algorithm used by [`field_hash`].
+///
+/// The algorithm is only changed in semver-breaking releases of this crate,
+/// which also increment this version.
+pub const FIELD_HASH_VERSION: u32 = 1;
+
+const FNV_OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// Hashes the identifier of an unsafe field.
+///
+/// This is the hash which [`unsafe_fields!`] uses for the `NAME_HASH`
+/// parameter of an [`Unsafe`]. A field's identifier is its name as written
+/// (including any `r#` prefix) or, for a tuple field, its index in decimal. In
+/// an enum, the identifier is additionally prefixed with the name of the
+/// variant and a `.`; e.g., `Variant.field` or `Variant.0`.
+///
+/// The hash is the 128-bit [FNV-1a] hash of the UTF-8 encoding of the
+/// identifier. It is stable for a given [`FIELD_HASH_VERSION`].
+///
+/// [FNV-1a]: https://datatracker.ietf.org/doc/html/draft-eastlake-fnv
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_fields::{field_hash, unsafe_fields, Unsafe};
+///
+/// unsafe_fields! {
+///     pub struct Foo {
+///         #[unsafe]
+///         a: usize,
+///     }
+/// }
+///
+/// fn hash_of<O, F, const NAME_HASH: u128>(_: &Unsafe<O, F, NAME_HASH>) -> u128 {
+///     NAME_HASH
+/// }
+///
+/// // SAFETY: `Foo::a` has no safety invariants.
+/// let foo = Foo { a: unsafe { Unsafe::new(0) } };
+/// assert_eq!(hash_of(&foo.a), field_hash("a"));
+/// ```
+#[inline(always)]
+#[must_use]
+pub const fn field_hash(identifier: &str) -> u128 {
+    fnv1a(FNV_OFFSET_BASIS, identifier.as_bytes())
+}
+
+#[allow(clippy::as_conversions, clippy::indexing_slicing, clippy::arithmetic_side_effects)]
+const fn fnv1a(mut hash: u128, bytes: &[u8]) -> u128 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ (bytes[i] as u128)).wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
+
 /// Defines a type with unsafe fields.
 ///
+/// `unsafe_fields!` accepts a struct, enum, or union definition, and wraps the
+/// type of each field annotated with `#[unsafe]` in [`Unsafe`]. The type may
+/// have lifetime, type, and const parameters and a `where` clause, and its
+/// fields may carry other attributes alongside `#[unsafe]`.
+///
+/// Each unsafe field is identified by its name or, in a tuple struct or tuple
+/// variant, its index. In an enum, the identifier is prefixed with the name of
+/// the variant, so that unsafe fields of different variants cannot be swapped.
+///
 /// See the [crate-level documentation](crate) for more information.
-// TODO: Allow specifying *which* fields are unsafe.
 #[macro_export]
 macro_rules! unsafe_fields {
-    ($(#[$attr:meta])* $vis:vis struct $name:ident {
-        $($(#[$field_attr:tt])? $field:ident: $field_ty:ty),* $(,)?
-    }) => {
-        $(#[$attr])*
-        $vis struct $name {
-            $(
-                $field: unsafe_fields!(@field $(#[$field_attr])? $field: $field_ty),
-            )*
-        }
+    ($(#[$attr:meta])* $vis:vis struct $name:ident $($rest:tt)*) => {
+        $crate::unsafe_fields!(@split [struct] [$(#[$attr])* $vis struct $name] [] $($rest)*);
+    };
+    ($(#[$attr:meta])* $vis:vis enum $name:ident $($rest:tt)*) => {
+        $crate::unsafe_fields!(@split [enum] [$(#[$attr])* $vis enum $name] [] $($rest)*);
+    };
+    ($(#[$attr:meta])* $vis:vis union $name:ident $($rest:tt)*) => {
+        $crate::unsafe_fields!(@split [union] [$(#[$attr])* $vis union $name] [] $($rest)*);
+    };
+
+    // Splits the generics and `where` clause of a type from its body. Each
+    // token which precedes the body is moved, one at a time, from the input
+    // into the second bracketed group.
+    (@split [struct] [$($head:tt)*] [$($generics:tt)*] { $($fields:tt)* }) => {
+        $crate::unsafe_fields!(
+            @named [@emit [$($head)* $($generics)*] []] [] [] [] [] [] $($fields)*
+        );
+    };
+    (@split [union] [$($head:tt)*] [$($generics:tt)*] { $($fields:tt)* }) => {
+        $crate::unsafe_fields!(
+            @named [@emit [$($head)* $($generics)*] []] [] [] [] [] [] $($fields)*
+        );
+    };
+    (@split [struct] [$($head:tt)*] [$($generics:tt)*] ( $($fields:tt)* ) ;) => {
+        $crate::unsafe_fields!(
+            @tuple [@emit [$($head)* $($generics)*] [;]] [] [] [] [] [] [] $($fields)*
+        );
+    };
+    (@split [struct] [$($head:tt)*] [$($generics:tt)*] ( $($fields:tt)* ) where $($where:tt)*) => {
+        $crate::unsafe_fields!(
+            @tuple [@emit [$($head)* $($generics)*] [where $($where)*]] [] [] [] [] [] []
+            $($fields)*
+        );
+    };
+    (@split [struct] [$($head:tt)*] [$($generics:tt)*] ;) => {
+        $($head)* $($generics)* ;
+    };
+    (@split [enum] [$($head:tt)*] [$($generics:tt)*] { $($variants:tt)* }) => {
+        $crate::unsafe_fields!(@variants [$($head)* $($generics)*] [] [] $($variants)*);
+    };
+    (@split [$kind:ident] [$($head:tt)*] [$($generics:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::unsafe_fields!(@split [$kind] [$($head)*] [$($generics)* $next] $($rest)*);
+    };
+
+    (@emit [$($head:tt)*] [$($tail:tt)*] [$($hashes:tt)*] $body:tt) => {
+        $($head)* $body $($tail)*
+        $crate::unsafe_fields!(@check [$($hashes)*]);
+    };
+
+    // Fails compilation if any two unsafe fields of a type share a hash.
+    (@check [$($hashes:tt)*]) => {
+        const _: () = $crate::macro_util::assert_distinct_field_hashes(&[$($hashes)*]);
+    };
+
+    // Rewrites a list of named fields. The arguments are:
+    // - the macro invocation to which the hashes of the fields and the
+    //   rewritten fields, as a brace-delimited group, are passed once all
+    //   fields have been rewritten
+    // - the prefix of the name of each field, used for hashing
+    // - the fields rewritten so far
+    // - the hashes of the fields rewritten so far, or `None` for fields which
+    //   are not unsafe
+    // - the attributes of the current field, other than `#[unsafe]`
+    // - `[unsafe]` if the current field is unsafe, and `[]` otherwise
+    (@named [$($k:tt)*] $prefix:tt [$($out:tt)*] $hashes:tt [] []) => {
+        $crate::unsafe_fields!($($k)* $hashes { $($out)* });
+    };
+    (@named $k:tt $prefix:tt $out:tt $hashes:tt $attrs:tt $flag:tt #[unsafe] $($rest:tt)*) => {
+        $crate::unsafe_fields!(@named $k $prefix $out $hashes $attrs [unsafe] $($rest)*);
+    };
+    (
+        @named $k:tt $prefix:tt $out:tt $hashes:tt [$($attrs:tt)*] $flag:tt
+        #[$($attr:tt)*] $($rest:tt)*
+    ) => {
+        $crate::unsafe_fields!(
+            @named $k $prefix $out $hashes [$($attrs)* #[$($attr)*]] $flag $($rest)*
+        );
+    };
+    (
+        @named $k:tt [$($prefix:tt)*] [$($out:tt)*] [$($hashes:tt)*] [$($attrs:tt)*] $flag:tt
+        $field_vis:vis $field:ident : $field_ty:ty $(, $($rest:tt)*)?
+    ) => {
+        $crate::unsafe_fields!(
+            @named $k [$($prefix)*]
+            [
+                $($out)*
+                $($attrs)*
+                $field_vis $field: $crate::unsafe_fields!(
+                    @ty $flag
+                    [$crate::field_hash(::core::concat!($($prefix)* ::core::stringify!($field)))]
+                    $field_ty
+                ),
+            ]
+            [
+                $($hashes)*
+                $crate::unsafe_fields!(
+                    @hash $flag
+                    [$crate::field_hash(::core::concat!($($prefix)* ::core::stringify!($field)))]
+                ),
+            ]
+            [] [] $($($rest)*)?
+        );
+    };
+
+    // Rewrites a list of tuple fields. The arguments are the same as for
+    // `@named`, except that the rewritten fields are passed as a
+    // parenthesis-delimited group, and that the third argument is a sequence
+    // of `+ 1`s which evaluates to the index of the current field.
+    (@tuple [$($k:tt)*] $prefix:tt $index:tt [$($out:tt)*] $hashes:tt [] []) => {
+        $crate::unsafe_fields!($($k)* $hashes ( $($out)* ));
+    };
+    (
+        @tuple $k:tt $prefix:tt $index:tt $out:tt $hashes:tt $attrs:tt $flag:tt
+        #[unsafe] $($rest:tt)*
+    ) => {
+        $crate::unsafe_fields!(@tuple $k $prefix $index $out $hashes $attrs [unsafe] $($rest)*);
+    };
+    (
+        @tuple $k:tt $prefix:tt $index:tt $out:tt $hashes:tt [$($attrs:tt)*] $flag:tt
+        #[$($attr:tt)*] $($rest:tt)*
+    ) => {
+        $crate::unsafe_fields!(
+            @tuple $k $prefix $index $out $hashes [$($attrs)* #[$($attr)*]] $flag $($rest)*
+        );
     };
-    (@field #[unsafe] $field:ident: $field_ty:ty) => {
-        $crate::Unsafe<Self, $field_ty, {$crate::macro_util::hash_field_name(stringify!($field))}>
+    (
+        @tuple $k:tt [$($prefix:tt)*] [$($index:tt)*] [$($out:tt)*] [$($hashes:tt)*]
+        [$($attrs:tt)*] $flag:tt
+        $field_vis:vis $field_ty:ty $(, $($rest:tt)*)?
+    ) => {
+        $crate::unsafe_fields!(
+            @tuple $k [$($prefix)*] [$($index)* + 1]
+            [
+                $($out)*
+                $($attrs)*
+                $field_vis $crate::unsafe_fields!(
+                    @ty $flag
+                    [$crate::macro_util::hash_field_index(
+                        ::core::concat!($($prefix)* ""),
+                        0 $($index)*,
+                    )]
+                    $field_ty
+                ),
+            ]
+            [
+                $($hashes)*
+                $crate::unsafe_fields!(
+                    @hash $flag
+                    [$crate::macro_util::hash_field_index(
+                        ::core::concat!($($prefix)* ""),
+                        0 $($index)*,
+                    )]
+                ),
+            ]
+            [] [] $($($rest)*)?
+        );
     };
-    (@field $_field:ident: $field_ty:ty) => {
+
+    // Rewrites the variants of an enum, passing the fields of each variant
+    // through `@named` or `@tuple` and then back to `@variant`. The arguments
+    // are the head of the enum, the variants rewritten so far, and the hashes
+    // of their fields.
+    (@variants [$($head:tt)*] [$($out:tt)*] $hashes:tt) => {
+        $($head)* { $($out)* }
+        $crate::unsafe_fields!(@check $hashes);
+    };
+    (
+        @variants $head:tt [$($out:tt)*] $hashes:tt
+        $(#[$($attr:tt)*])* $variant:ident { $($fields:tt)* } $($rest:tt)*
+    ) => {
+        $crate::unsafe_fields!(
+            @named [@variant $head [$($out)* $(#[$($attr)*])* $variant] $hashes [$($rest)*]]
+            [::core::stringify!($variant), ".",] [] [] [] [] $($fields)*
+        );
+    };
+    (
+        @variants $head:tt [$($out:tt)*] $hashes:tt
+        $(#[$($attr:tt)*])* $variant:ident ( $($fields:tt)* ) $($rest:tt)*
+    ) => {
+        $crate::unsafe_fields!(
+            @tuple [@variant $head [$($out)* $(#[$($attr)*])* $variant] $hashes [$($rest)*]]
+            [::core::stringify!($variant), ".",] [] [] [] [] [] $($fields)*
+        );
+    };
+    (
+        @variants $head:tt [$($out:tt)*] $hashes:tt
+        $(#[$($attr:tt)*])* $variant:ident $($rest:tt)*
+    ) => {
+        $crate::unsafe_fields!(
+            @variant $head [$($out)* $(#[$($attr)*])* $variant] $hashes [$($rest)*] []
+        );
+    };
+    (
+        @variant $head:tt [$($out:tt)*] [$($hashes:tt)*]
+        [$(= $discriminant:expr)? $(, $($rest:tt)*)?]
+        [$($field_hashes:tt)*] $($fields:tt)?
+    ) => {
+        $crate::unsafe_fields!(
+            @variants $head [$($out)* $($fields)? $(= $discriminant)?,]
+            [$($hashes)* $($field_hashes)*]
+            $($($rest)*)?
+        );
+    };
+
+    (@ty [unsafe] [$hash:expr] $field_ty:ty) => {
+        $crate::Unsafe<Self, $field_ty, { $hash }>
+    };
+    (@ty [] [$hash:expr] $field_ty:ty) => {
         $field_ty
-    }
+    };
+
+    (@hash [unsafe] [$hash:expr]) => {
+        ::core::option::Option::Some($hash)
+    };
+    (@hash [] [$hash:expr]) => {
+        ::core::option::Option::None
+    };
 }
 
 #[doc(hidden)]
 pub mod macro_util {
-    // TODO: Implement a stronger hash function so we can basically just ignore
-    // collisions. If users encounter collisions in practice, we can just deal
-    // with it then, publish a new version, and tell them to upgrade.
+    /// Hashes the identifier of the tuple field `prefix` followed by `index`.
+    ///
+    /// This produces the same hash as [`field_hash`](crate::field_hash) does
+    /// for the decimal representation of `index` appended to `prefix`.
     #[inline(always)]
     #[must_use]
     #[allow(clippy::as_conversions, clippy::indexing_slicing, clippy::arithmetic_side_effects)]
-    pub const fn hash_field_name(field_name: &str) -> u128 {
-        // An implementation of FxHasher, although returning a u128. Probably
-        // not as strong as it could be, but probably more collision resistant
-        // than normal 64-bit FxHasher.
-        let field_name = field_name.as_bytes();
-        let mut hash = 0u128;
+    pub const fn hash_field_index(prefix: &str, index: usize) -> u128 {
+        let mut hash = crate::fnv1a(crate::FNV_OFFSET_BASIS, prefix.as_bytes());
+
+        // `usize::MAX` has at most 20 decimal digits.
+        let mut digits = [0u8; 20];
+        let mut len = 0;
+        let mut n = index;
+        loop {
+            digits[len] = b'0' + (n % 10) as u8;
+            len += 1;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        while len > 0 {
+            len -= 1;
+            hash = crate::fnv1a(hash, &[digits[len]]);
+        }
+        hash
+    }
+
+    /// Panics if any two of `hashes` are equal.
+    ///
+    /// `hashes` holds the hash of each unsafe field of a type, and `None` for
+    /// each of its other fields. Since the identifiers of a type's fields are
+    /// distinct, equal hashes indicate a collision.
+    #[inline(always)]
+    #[allow(clippy::indexing_slicing, clippy::arithmetic_side_effects)]
+    pub const fn assert_distinct_field_hashes(hashes: &[Option<u128>]) {
         let mut i = 0;
-        while i < field_name.len() {
-            // This is just FxHasher's `0x517cc1b727220a95` constant
-            // concatenated back-to-back.
-            const K: u128 = 0x517cc1b727220a95517cc1b727220a95;
-            hash = (hash.rotate_left(5) ^ (field_name[i] as u128)).wrapping_mul(K);
+        while i < hashes.len() {
+            let mut j = i + 1;
+            while j < hashes.len() {
+                if let (Some(a), Some(b)) = (hashes[i], hashes[j]) {
+                    assert!(
+                        a != b,
+                        "two unsafe fields have the same field hash; rename one of them"
+                    );
+                }
+                j += 1;
+            }
             i += 1;
         }
-        hash
     }
 }
 
@@ -389,11 +949,158 @@ mod tests {
         }
     }
 
+    unsafe_fields! {
+        /// A `Bounded`.
+        #[allow(unused)]
+        struct Bounded<'a, T: Copy + 'a, const MAX: usize>
+        where
+            T: PartialOrd,
+        {
+            /// The bounded values.
+            #[unsafe]
+            #[allow(unused)]
+            pub(crate) values: &'a [T; MAX],
+            max: T,
+        }
+    }
+
+    unsafe_fields! {
+        /// A `Tuple`.
+        #[allow(unused)]
+        struct Tuple<T>(#[unsafe] pub T, u8, #[allow(unused)] #[unsafe] T)
+        where
+            T: Copy;
+    }
+
+    unsafe_fields! {
+        /// A `Unit`.
+        #[allow(unused)]
+        struct Unit where u8: Copy;
+    }
+
+    unsafe_fields! {
+        /// An `Enum`.
+        #[allow(unused)]
+        #[repr(u8)]
+        enum Enum<T: Copy> {
+            /// A struct variant.
+            Struct {
+                #[unsafe]
+                a: T,
+                b: u8,
+            } = 1,
+            Tuple(#[unsafe] T, u8),
+            Unit,
+        }
+    }
+
+    unsafe_fields! {
+        /// A `Union`.
+        #[allow(unused)]
+        union Union {
+            #[unsafe]
+            a: u8,
+            b: u8,
+        }
+    }
+
     #[test]
     #[allow(clippy::undocumented_unsafe_blocks)]
     fn test_unsafe_fieds() {
         let mut _foo = Foo { a: unsafe { Unsafe::new(0) }, b: 0 };
         let mut _bar = Bar { a: unsafe { Unsafe::new(0) }, b: unsafe { Unsafe::new(0) } };
+        let mut _bounded = Bounded { values: unsafe { Unsafe::new(&[0u8; 4]) }, max: 3 };
+        let mut _tuple = Tuple(unsafe { Unsafe::new(0u8) }, 0, unsafe { Unsafe::new(0) });
+        let mut _unit = Unit;
+        let mut _enum = Enum::Struct { a: unsafe { Unsafe::new(0u8) }, b: 0 };
+        _enum = Enum::Tuple(unsafe { Unsafe::new(0) }, 0);
+        _enum = Enum::Unit;
+        let mut _union = Union { a: unsafe { Unsafe::new(0) } };
+    }
+
+    #[cfg(feature = "zerocopy_0_8")]
+    unsafe_fields! {
+        /// A `Derived`.
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        struct Derived {
+            #[unsafe]
+            a: u8,
+            b: u8,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "zerocopy_0_8")]
+    #[allow(clippy::undocumented_unsafe_blocks, clippy::use_debug)]
+    fn test_read_only_traits() {
+        use core::hash::{Hash, Hasher};
+        use std::{
+            collections::{hash_map::DefaultHasher, BTreeSet, HashSet},
+            format,
+        };
+
+        let new = |a, b| Derived { a: unsafe { Unsafe::new(a) }, b };
+        let (x, y) = (new(1, 2), new(2, 1));
+
+        assert_eq!(format!("{:?}", x), "Derived { a: Unsafe(1), b: 2 }");
+        assert_eq!(x, new(1, 2));
+        assert_ne!(x, y);
+        assert!(x < y);
+
+        // `Unsafe` hashes the same as its field.
+        let hash = |h: &dyn Fn(&mut DefaultHasher)| {
+            let mut hasher = DefaultHasher::new();
+            h(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&|h| x.a.hash(h)), hash(&|h| 1u8.hash(h)));
+
+        let set: HashSet<_> = [new(1, 2), new(1, 2), new(2, 1)].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        let set: BTreeSet<_> = [new(2, 1), new(1, 2)].into_iter().collect();
+        assert_eq!(set.into_iter().next(), Some(x));
+    }
+
+    #[test]
+    fn test_field_hash() {
+        use macro_util::hash_field_index;
+
+        // Test vectors for 128-bit FNV-1a.
+        assert_eq!(field_hash(""), 0x6c62272e07bb014262b821756295c58d);
+        assert_eq!(field_hash("a"), 0xd228cb696f1a8caf78912b704e4a8964);
+
+        assert_eq!(hash_field_index("", 0), field_hash("0"));
+        assert_eq!(hash_field_index("", 42), field_hash("42"));
+        assert_eq!(hash_field_index("V.", 10), field_hash("V.10"));
+        assert_eq!(hash_field_index("", usize::MAX), field_hash(&usize::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_field_identity() {
+        type A = Unsafe<Foo, usize, { field_hash("a") }>;
+
+        assert_eq!(A::FIELD_HASH, field_hash("a"));
+        assert!(A::is_field("a"));
+        assert!(!A::is_field("b"));
+        assert_eq!(A::find_field(&["b", "a"]), Some("a"));
+        assert_eq!(A::find_field(&["b"]), None);
+
+        // Enum fields are identified by their variant and name or index.
+        fn hash_of<O, F, const NAME_HASH: u128>(_: &Unsafe<O, F, NAME_HASH>) -> u128 {
+            NAME_HASH
+        }
+        #[allow(clippy::undocumented_unsafe_blocks)]
+        if let Enum::Tuple(a, _) = Enum::Tuple(unsafe { Unsafe::new(0u8) }, 0) {
+            assert_eq!(hash_of(&a), field_hash("Tuple.0"));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "two unsafe fields have the same field hash")]
+    fn test_assert_distinct_field_hashes() {
+        macro_util::assert_distinct_field_hashes(&[Some(1), None, Some(2)]);
+        macro_util::assert_distinct_field_hashes(&[None, None]);
+        macro_util::assert_distinct_field_hashes(&[Some(1), None, Some(1)]);
     }
 }
 
@@ -463,5 +1170,120 @@ mod tests {
 ///     core::mem::swap(&mut foo.a, &mut bar.a);
 /// }
 /// ```
+///
+/// ```compile_fail,E0308
+/// use unsafe_fields::*;
+///
+/// unsafe_fields! {
+///     struct Bounded<'a, T, const MAX: usize>
+///     where
+///         T: PartialOrd,
+///     {
+///         #[unsafe]
+///         lo: &'a [T; MAX],
+///         #[unsafe]
+///         hi: &'a [T; MAX],
+///     }
+/// }
+///
+/// impl<'a, T: PartialOrd, const MAX: usize> Bounded<'a, T, MAX> {
+///     // Swapping unsafe fields of a generic type is a compile error.
+///     fn swap(&mut self) {
+///         core::mem::swap(&mut self.lo, &mut self.hi);
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail,E0308
+/// use unsafe_fields::*;
+///
+/// unsafe_fields! {
+///     struct Foo(#[unsafe] usize, #[unsafe] usize);
+/// }
+///
+/// impl Foo {
+///     // Swapping unsafe tuple fields is a compile error.
+///     fn swap(&mut self) {
+///         core::mem::swap(&mut self.0, &mut self.1);
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail,E0308
+/// use unsafe_fields::*;
+///
+/// unsafe_fields! {
+///     enum Foo {
+///         A {
+///             #[unsafe]
+///             a: usize,
+///         },
+///         B {
+///             #[unsafe]
+///             a: usize,
+///         },
+///     }
+/// }
+///
+/// // Swapping identically-named unsafe fields from different variants is a
+/// // compile error.
+/// fn swap(x: &mut Foo, y: &mut Foo) {
+///     if let (Foo::A { a: x }, Foo::B { a: y }) = (x, y) {
+///         core::mem::swap(x, y);
+///     }
+/// }
+/// ```
+///
+/// ```compile_fail,E0308
+/// use unsafe_fields::*;
+///
+/// unsafe_fields! {
+///     struct Foo {
+///         /// The field.
+///         #[allow(unused)]
+///         #[unsafe]
+///         a: usize,
+///     }
+/// }
+///
+/// // A field with multiple attributes is still unsafe.
+/// let _foo = Foo { a: 0 };
+/// ```
+///
+#[cfg_attr(
+    feature = "zerocopy_0_8",
+    doc = r#"
+```compile_fail,E0277
+use core::cell::Cell;
+use unsafe_fields::*;
+
+unsafe_fields! {
+    // `Cell<u8>` is not `Immutable`, so `Debug` could modify it.
+    #[derive(Debug)]
+    struct Foo {
+        #[unsafe]
+        a: Cell<u8>,
+    }
+}
+```
+
+```compile_fail,E0277
+# extern crate zerocopy_0_8 as zerocopy;
+use unsafe_fields::*;
+# use zerocopy_derive::*;
+
+unsafe_fields! {
+    // Without an `unsafe impl BytesUpholdInvariants for Foo`, `Foo`'s unsafe
+    // fields cannot be constructed from bytes.
+    #[derive(FromBytes)]
+    #[repr(C)]
+    struct Foo {
+        #[unsafe]
+        a: u8,
+    }
+}
+```
+"#
+)]
 #[doc(hidden)]
 pub mod compile_fail {}
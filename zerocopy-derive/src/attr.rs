@@ -0,0 +1,91 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+use syn::{Attribute, Error, Expr, Type};
+
+/// A single argument of a `#[zerocopy(...)]` helper attribute.
+pub(crate) enum ZerocopyAttr {
+    /// `#[zerocopy(discriminant = Type)]`: the byte-order-aware integer type
+    /// used to represent a field-less enum's discriminant on the wire.
+    Discriminant(Type),
+    /// `#[zerocopy(eq = expr)]`: the constant value which a struct field must
+    /// hold in order for the struct to be valid.
+    Eq(Expr),
+    /// `#[zerocopy(len = field.path)]`: the field of a struct which holds the
+    /// number of elements in its trailing slice field.
+    Len(Expr),
+}
+
+impl ZerocopyAttr {
+    /// Parses all `#[zerocopy(...)]` attributes in `attrs`.
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Result<Vec<ZerocopyAttr>, Error> {
+        let mut parsed = Vec::new();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("zerocopy")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("discriminant") {
+                    if discriminant(&parsed).is_some() {
+                        return Err(meta.error("duplicate `discriminant` attribute"));
+                    }
+                    parsed.push(ZerocopyAttr::Discriminant(meta.value()?.parse()?));
+                    Ok(())
+                } else if meta.path.is_ident("eq") {
+                    if eq(&parsed).is_some() {
+                        return Err(meta.error("duplicate `eq` attribute"));
+                    }
+                    parsed.push(ZerocopyAttr::Eq(meta.value()?.parse()?));
+                    Ok(())
+                } else if meta.path.is_ident("len") {
+                    if len(&parsed).is_some() {
+                        return Err(meta.error("duplicate `len` attribute"));
+                    }
+                    parsed.push(ZerocopyAttr::Len(meta.value()?.parse()?));
+                    Ok(())
+                } else {
+                    Err(meta.error("unrecognized zerocopy attribute"))
+                }
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Returns the type given by the `#[zerocopy(discriminant = ...)]` attribute
+/// in `attrs`, if any.
+pub(crate) fn discriminant(attrs: &[ZerocopyAttr]) -> Option<&Type> {
+    attrs
+        .iter()
+        .filter_map(|attr| match attr {
+            ZerocopyAttr::Discriminant(ty) => Some(ty),
+            ZerocopyAttr::Eq(_) | ZerocopyAttr::Len(_) => None,
+        })
+        .next()
+}
+
+/// Returns the expression given by the `#[zerocopy(eq = ...)]` attribute in
+/// `attrs`, if any.
+pub(crate) fn eq(attrs: &[ZerocopyAttr]) -> Option<&Expr> {
+    attrs
+        .iter()
+        .filter_map(|attr| match attr {
+            ZerocopyAttr::Eq(expr) => Some(expr),
+            ZerocopyAttr::Discriminant(_) | ZerocopyAttr::Len(_) => None,
+        })
+        .next()
+}
+
+/// Returns the field path given by the `#[zerocopy(len = ...)]` attribute in
+/// `attrs`, if any.
+pub(crate) fn len(attrs: &[ZerocopyAttr]) -> Option<&Expr> {
+    attrs
+        .iter()
+        .filter_map(|attr| match attr {
+            ZerocopyAttr::Len(expr) => Some(expr),
+            ZerocopyAttr::Discriminant(_) | ZerocopyAttr::Eq(_) => None,
+        })
+        .next()
+}
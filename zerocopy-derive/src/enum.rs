@@ -7,10 +7,12 @@
 // those terms.
 
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::{parse_quote, DataEnum, Error, Fields, Generics, Ident};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Data, DataEnum, DeriveInput, Error, Fields, Generics, Ident, Type};
 
-use crate::{derive_try_from_bytes_inner, repr::EnumRepr, Trait};
+use crate::{
+    derive_try_from_bytes_inner, open_enum::IntRepr, repr::EnumRepr, FieldBounds, SelfBounds, Trait,
+};
 
 /// Generates a tag enum for the given enum. This generates an enum with the
 /// same non-align `repr`s, variants, and corresponding discriminants, but none
@@ -160,7 +162,7 @@ fn generate_variants_union(generics: &Generics, data: &DataEnum) -> TokenStream
 
         // Field names are prefixed with `__field_` to prevent name collision with
         // the `__nonempty` field.
-        let field_name = Ident::new(&format!("__field_{}", &variant.ident), variant.ident.span());
+        let field_name = Ident::new(&format!("__field_{}", variant.ident), variant.ident.span());
         let variant_struct_ident = variant_struct_ident(&variant.ident);
 
         Some(quote! {
@@ -362,3 +364,199 @@ pub(crate) fn derive_is_bit_valid(
         }
     })
 }
+
+/// Generates the wire type for a field-less enum with a
+/// `#[zerocopy(discriminant = ...)]` attribute.
+///
+/// The wire type is a `repr(transparent)` wrapper around the byte-order-aware
+/// integer type named by the attribute. It implements `TryFromBytes` such that
+/// only the enum's discriminants are valid, and can be converted to and from
+/// the enum itself.
+pub(crate) fn derive_wire_type(
+    ast: &DeriveInput,
+    repr: &EnumRepr,
+    data: &DataEnum,
+    discriminant: &Type,
+) -> Result<TokenStream, Error> {
+    let int = IntRepr::from_type(discriminant)?;
+    if !int.byte_order {
+        return Err(Error::new_spanned(
+            discriminant,
+            "must be a byte-order-aware integer type (e.g., `U16<BigEndian>`); use `#[repr(...)]` for native discriminants",
+        ));
+    }
+    let native = &int.native;
+    let native_repr = format!("repr({})", native);
+    if repr.repr_type_name() != native_repr {
+        return Err(Error::new(
+            Span::call_site(),
+            format!("`#[zerocopy(discriminant = ...)]` requires `#[{}]`", native_repr),
+        ));
+    }
+    if !ast.generics.params.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`#[zerocopy(discriminant = ...)]` does not support generic enums",
+        ));
+    }
+    if let Some(v) = data.variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        return Err(Error::new_spanned(
+            v,
+            "`#[zerocopy(discriminant = ...)]` only supports field-less enums",
+        ));
+    }
+
+    let vis = &ast.vis;
+    let enum_ident = &ast.ident;
+    let wire_ident = Ident::new(&format!("{}Wire", enum_ident), enum_ident.span());
+    let core = quote!(::zerocopy::util::macro_util::core_reexport);
+    let field_ty =
+        quote!(::zerocopy::util::macro_util::WireDiscriminant<#discriminant, #enum_ident>);
+
+    let wire: DeriveInput = parse_quote! {
+        #[repr(transparent)]
+        #vis struct #wire_ident(#field_ty);
+    };
+    let wire_data = match &wire.data {
+        Data::Struct(strct) => strct,
+        Data::Enum(_) | Data::Union(_) => unreachable!(),
+    };
+
+    let tag_enum = generate_tag_enum(repr, data);
+    let tag_consts = generate_tag_consts(data);
+    let tag_idents: Vec<_> = data.variants.iter().map(|v| tag_ident(&v.ident)).collect();
+    let variant_idents = data.variants.iter().map(|v| &v.ident);
+
+    let is_bit_valid = quote! {
+        // SAFETY: We use `is_bit_valid` to validate that the discriminant
+        // corresponds to one of the enum's discriminants, which are the only
+        // valid values of the wire type.
+        fn is_bit_valid<___ZerocopyAliasing>(
+            candidate: ::zerocopy::Maybe<'_, Self, ___ZerocopyAliasing>,
+        ) -> #core::primitive::bool
+        where
+            ___ZerocopyAliasing: ::zerocopy::pointer::invariant::Reference,
+        {
+            #tag_enum
+
+            type ___ZerocopyTagPrimitive = #core::primitive::#native;
+
+            #tag_consts
+
+            // SAFETY:
+            // - `Self` is `repr(transparent)` and has a single field of type
+            //   `WireDiscriminant<#discriminant, #enum_ident>`, which is itself
+            //   `repr(transparent)` around `#discriminant`, so the cast
+            //   addresses exactly the bytes addressed by `candidate`.
+            // - Because the pointer is cast from `candidate`, it has the same
+            //   provenance as it.
+            // - There are no `UnsafeCell`s in either type because they are
+            //   byte-order-aware integers.
+            let discriminant = unsafe {
+                candidate.cast_unsized_unchecked(|p: *mut Self| p as *mut #discriminant)
+            };
+            let discriminant = discriminant
+                .bikeshed_recall_valid()
+                .read_unaligned::<::zerocopy::BecauseImmutable>();
+
+            #[allow(non_upper_case_globals)]
+            match discriminant.get() {
+                #(#tag_idents => true,)*
+                _ => false,
+            }
+        }
+    };
+
+    let impls = [
+        crate::derive_known_layout_inner(&wire, Trait::KnownLayout)?,
+        crate::derive_no_cell_inner(&wire, Trait::Immutable),
+        crate::impl_block(
+            &wire,
+            wire_data,
+            Trait::TryFromBytes,
+            // `WireDiscriminant` deliberately does not implement
+            // `TryFromBytes`; `is_bit_valid` validates the field itself.
+            FieldBounds::None,
+            SelfBounds::None,
+            None,
+            Some(is_bit_valid),
+        ),
+        crate::derive_into_bytes_inner(&wire, Trait::IntoBytes)?,
+        crate::derive_unaligned_inner(&wire, Trait::Unaligned)?,
+    ];
+
+    let doc = format!(
+        "The wire representation of [`{}`], whose discriminant is stored as a `{}`.",
+        enum_ident,
+        discriminant.to_token_stream().to_string().replace(' ', ""),
+    );
+
+    let new_doc = format!("Converts a [`{}`] to its wire representation.", enum_ident);
+    let get_doc = format!("Converts this value to the corresponding [`{}`].", enum_ident);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[repr(transparent)]
+        #[derive(
+            #core::clone::Clone,
+            #core::marker::Copy,
+            #core::fmt::Debug,
+            #core::cmp::PartialEq,
+            #core::cmp::Eq,
+            #core::hash::Hash,
+        )]
+        #vis struct #wire_ident(#field_ty);
+
+        #(#impls)*
+
+        impl #wire_ident {
+            #[doc = #new_doc]
+            #[must_use]
+            #[inline]
+            #vis const fn new(value: #enum_ident) -> #wire_ident {
+                let discriminant = <#discriminant>::new(value as #core::primitive::#native);
+                // SAFETY: `discriminant` is the discriminant of `value`.
+                #wire_ident(unsafe {
+                    ::zerocopy::util::macro_util::WireDiscriminant::new_unchecked(discriminant)
+                })
+            }
+
+            #[doc = #get_doc]
+            #[must_use]
+            #[inline]
+            #vis const fn get(self) -> #enum_ident {
+                #tag_enum
+
+                type ___ZerocopyTagPrimitive = #core::primitive::#native;
+
+                #tag_consts
+
+                #[allow(non_upper_case_globals)]
+                match self.0.get().get() {
+                    #(#tag_idents => #enum_ident::#variant_idents,)*
+                    // A `WireDiscriminant<_, #enum_ident>` can only be
+                    // obtained via `is_bit_valid`, which only accepts the
+                    // discriminants matched above, or via `unsafe` code which
+                    // must uphold the same guarantee. Since the enum is part
+                    // of its type, it cannot be taken from the wire type of
+                    // another enum. Thus, this arm is unreachable.
+                    _ => #core::unreachable!(),
+                }
+            }
+        }
+
+        impl #core::convert::From<#enum_ident> for #wire_ident {
+            #[inline]
+            fn from(value: #enum_ident) -> #wire_ident {
+                #wire_ident::new(value)
+            }
+        }
+
+        impl #core::convert::From<#wire_ident> for #enum_ident {
+            #[inline]
+            fn from(wire: #wire_ident) -> #enum_ident {
+                wire.get()
+            }
+        }
+    })
+}
@@ -0,0 +1,228 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Field, Ident, WherePredicate};
+
+use crate::repr::StructUnionRepr;
+
+/// A field of a `repr(C)` struct, along with expressions which compute its
+/// layout and offset.
+pub(crate) struct FieldOffset<'a> {
+    pub(crate) field: &'a Field,
+    /// The field's name, or its index if it is unnamed.
+    pub(crate) name: String,
+    /// An expression of type `DstLayout` which evaluates to the field's layout.
+    pub(crate) layout: TokenStream,
+    /// An expression of type `usize` which evaluates to the field's offset.
+    pub(crate) offset: TokenStream,
+}
+
+/// Computes the layout and offset of each field of the `repr(C)` struct `ast`.
+///
+/// Offsets are computed using the same sequence of `DstLayout::{new_zst,
+/// extend}` calls that `#[derive(KnownLayout)]` uses to compute the struct's
+/// layout. The generated expressions require the trailing field's type to
+/// implement `KnownLayout`; see [`trailing_bound`].
+pub(crate) fn field_offsets<'a>(
+    ast: &'a DeriveInput,
+    trait_name: &str,
+) -> Result<Vec<FieldOffset<'a>>, Error> {
+    let strct = match &ast.data {
+        Data::Struct(strct) => strct,
+        Data::Enum(_) | Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                format!("`{}` can only be derived for structs", trait_name),
+            ))
+        }
+    };
+
+    let repr = StructUnionRepr::from_attrs(&ast.attrs)?;
+    if !repr.is_c() {
+        return Err(Error::new(
+            Span::call_site(),
+            "must have #[repr(C)] attribute in order to guarantee this type's field offsets",
+        ));
+    }
+
+    let core_path = quote!(::zerocopy::util::macro_util::core_reexport);
+    let repr_align = repr
+        .get_align()
+        .map(|align| {
+            let align = align.t.get();
+            quote!(#core_path::num::NonZeroUsize::new(#align as usize))
+        })
+        .unwrap_or_else(|| quote!(#core_path::option::Option::None));
+    let repr_packed = repr
+        .get_packed()
+        .map(|packed| {
+            let packed = packed.get();
+            quote!(#core_path::num::NonZeroUsize::new(#packed as usize))
+        })
+        .unwrap_or_else(|| quote!(#core_path::option::Option::None));
+
+    let fields: Vec<_> = strct.fields.iter().collect();
+    let tys: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+
+    Ok(fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let name = match &field.ident {
+                Some(ident) => {
+                    let name = ident.to_string();
+                    name.strip_prefix("r#").unwrap_or(&name).to_string()
+                }
+                None => i.to_string(),
+            };
+
+            // As in `#[derive(KnownLayout)]`, only the trailing field may be
+            // unsized, and so only its layout is computed via `KnownLayout`.
+            let ty = tys[i];
+            let layout = if i + 1 == fields.len() {
+                quote!(<#ty as ::zerocopy::KnownLayout>::LAYOUT)
+            } else {
+                quote!(::zerocopy::DstLayout::for_type::<#ty>())
+            };
+            let preceding = &tys[..i];
+            let offset = quote!({
+                use ::zerocopy::DstLayout;
+                ::zerocopy::util::macro_util::repr_c_field_offset(
+                    DstLayout::new_zst(#repr_align)
+                        #(.extend(DstLayout::for_type::<#preceding>(), #repr_packed))*,
+                    #layout,
+                    #repr_packed,
+                )
+            });
+
+            FieldOffset { field, name, layout, offset }
+        })
+        .collect())
+}
+
+/// Returns the where predicates of `ast`, along with a bound requiring the
+/// type of its trailing field (if any) to implement `KnownLayout`.
+pub(crate) fn predicates_with_trailing_bound(ast: &DeriveInput) -> Vec<WherePredicate> {
+    let trailing = match &ast.data {
+        Data::Struct(strct) => strct.fields.iter().next_back(),
+        Data::Enum(_) | Data::Union(_) => None,
+    };
+    let trailing_bound: Option<WherePredicate> = trailing.map(|field| {
+        let ty = &field.ty;
+        parse_quote!(#ty: ::zerocopy::KnownLayout)
+    });
+    ast.generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter().cloned())
+        .chain(trailing_bound)
+        .collect()
+}
+
+/// Generates `<FIELD>_OFFSET` and `<FIELD>_SIZE` associated constants for each
+/// field of a `repr(C)` struct, and a `FIELD_OFFSETS` constant which lists the
+/// name, offset, and size of every field.
+pub(crate) fn derive_field_offsets_inner(ast: &DeriveInput) -> Result<TokenStream, Error> {
+    let fields = field_offsets(ast, "FieldOffsets")?;
+    let core_path = quote!(::zerocopy::util::macro_util::core_reexport);
+
+    let mut consts = Vec::new();
+    let mut entries = Vec::new();
+    for FieldOffset { field, name, layout, offset } in &fields {
+        let prefix = match &field.ident {
+            Some(_) => name.to_uppercase(),
+            None => format!("_{}", name),
+        };
+        let offset_ident = Ident::new(&format!("{}_OFFSET", prefix), Span::call_site());
+        let size_ident = Ident::new(&format!("{}_SIZE", prefix), Span::call_site());
+        let vis = &field.vis;
+        let offset_doc = format!("The offset of the `{}` field, in bytes.", name);
+        let size_doc = format!(
+            "The size of the `{}` field, in bytes; if it is dynamically sized, its size with a zero-length trailing slice.",
+            name
+        );
+
+        consts.push(quote! {
+            #[doc = #offset_doc]
+            #vis const #offset_ident: #core_path::primitive::usize = #offset;
+
+            #[doc = #size_doc]
+            #vis const #size_ident: #core_path::primitive::usize =
+                ::zerocopy::util::macro_util::layout_min_size(#layout);
+        });
+        entries.push(quote!((#name, Self::#offset_ident, Self::#size_ident)));
+    }
+
+    let ident = &ast.ident;
+    let vis = &ast.vis;
+    let len = fields.len();
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let predicates = predicates_with_trailing_bound(ast);
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics
+        where
+            #(#predicates,)*
+        {
+            #(#consts)*
+
+            /// The name, offset, and size (as defined by the `<FIELD>_OFFSET`
+            /// and `<FIELD>_SIZE` constants) of each field, in declaration
+            /// order.
+            #vis const FIELD_OFFSETS: [(
+                &'static #core_path::primitive::str,
+                #core_path::primitive::usize,
+                #core_path::primitive::usize,
+            ); #len] = [#(#entries),*];
+        }
+    })
+}
+
+/// Implements `DescribeLayout` for a `repr(C)` struct.
+///
+/// Each field's type must implement `DescribeLayout`; field offsets are
+/// computed as for `#[derive(FieldOffsets)]`.
+pub(crate) fn derive_describe_layout_inner(ast: &DeriveInput) -> Result<TokenStream, Error> {
+    let fields = field_offsets(ast, "DescribeLayout")?;
+
+    let entries = fields.iter().map(|FieldOffset { field, name, offset, .. }| {
+        let ty = &field.ty;
+        quote! {
+            ::zerocopy::util::macro_util::field_layout(
+                #name,
+                #offset,
+                &<#ty as ::zerocopy::DescribeLayout>::TYPE_LAYOUT,
+            )
+        }
+    });
+
+    let ident = &ast.ident;
+    let name = ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name).to_string();
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let field_bounds = fields.iter().map(|FieldOffset { field, .. }| -> WherePredicate {
+        let ty = &field.ty;
+        parse_quote!(#ty: ::zerocopy::DescribeLayout)
+    });
+    let predicates = predicates_with_trailing_bound(ast).into_iter().chain(field_bounds);
+
+    Ok(quote! {
+        impl #impl_generics ::zerocopy::DescribeLayout for #ident #ty_generics
+        where
+            #(#predicates,)*
+        {
+            const TYPE_LAYOUT: ::zerocopy::TypeLayout =
+                ::zerocopy::util::macro_util::struct_type_layout::<Self>(
+                    #name,
+                    &[#(#entries),*],
+                );
+        }
+    })
+}
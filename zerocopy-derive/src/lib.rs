@@ -28,10 +28,15 @@
 )]
 #![recursion_limit = "128"]
 
+mod attr;
 mod r#enum;
 mod ext;
+mod field_offsets;
+mod linked_len;
+mod open_enum;
 #[cfg(test)]
 mod output_tests;
+mod padded;
 mod repr;
 
 use proc_macro2::{TokenStream, TokenTree};
@@ -46,7 +51,7 @@ use {
     },
 };
 
-use {crate::ext::*, crate::repr::*};
+use {crate::attr::*, crate::ext::*, crate::repr::*};
 
 // TODO(https://github.com/rust-lang/rust/issues/54140): Some errors could be
 // made better if we could add multiple lines of error output like this:
@@ -73,14 +78,70 @@ use {crate::ext::*, crate::repr::*};
 /// specify the name in order to avoid name collisions.
 macro_rules! derive {
     ($trait:ident => $outer:ident => $inner:ident) => {
-        #[proc_macro_derive($trait)]
+        #[proc_macro_derive($trait, attributes(zerocopy))]
         pub fn $outer(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
             let ast = syn::parse_macro_input!(ts as DeriveInput);
-            $inner(&ast, Trait::$trait).into_ts().into()
+            let mut ts = $inner(&ast, Trait::$trait).into_ts();
+            ts.extend(require_traits_for_attrs(&ast, Trait::$trait));
+            ts.into()
         }
     };
 }
 
+/// Emits assertions that the type implements the traits whose derives process
+/// its `#[zerocopy(...)]` attributes.
+///
+/// `#[zerocopy(discriminant = ...)]` is only processed when deriving
+/// `TryFromBytes` (or a trait whose derive implies it), and `#[zerocopy(len =
+/// ...)]` is only processed when deriving `KnownLayout`. Without these
+/// assertions, the attributes would be silently ignored on a type which only
+/// derives other traits.
+fn require_traits_for_attrs(ast: &DeriveInput, top_level: Trait) -> TokenStream {
+    // Malformed attributes are reported by the derives which process them.
+    let mut assertions = TokenStream::new();
+    if !matches!(top_level, Trait::TryFromBytes | Trait::FromZeros | Trait::FromBytes) {
+        let attrs = ZerocopyAttr::from_attrs(&ast.attrs).unwrap_or_default();
+        if let Some(ty) = discriminant(&attrs) {
+            assertions.extend(assert_self_implements(ast, ty, Trait::TryFromBytes));
+        }
+    }
+    if top_level != Trait::KnownLayout {
+        let fields: Vec<&syn::Field> = match &ast.data {
+            Data::Struct(strct) => strct.fields.iter().collect(),
+            Data::Enum(enm) => enm.variants.iter().flat_map(|v| &v.fields).collect(),
+            Data::Union(unn) => unn.fields.named.iter().collect(),
+        };
+        for field in fields {
+            let attrs = ZerocopyAttr::from_attrs(&field.attrs).unwrap_or_default();
+            if let Some(expr) = len(&attrs) {
+                assertions.extend(assert_self_implements(ast, expr, Trait::KnownLayout));
+            }
+        }
+    }
+    assertions
+}
+
+/// Emits an assertion that the type implements `trt`, whose error points to
+/// `attr` rather than to the derive.
+fn assert_self_implements(ast: &DeriveInput, attr: &dyn ToTokens, trt: Trait) -> TokenStream {
+    let span = syn::spanned::Spanned::span(attr);
+    let name = Ident::new(&ast.ident.to_string(), span);
+    let trt = Ident::new(&trt.to_token_stream().to_string(), span);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    quote::quote_spanned! {span=>
+        #[allow(dead_code, non_snake_case)]
+        const _: () = {
+            fn ___zerocopy_attribute_requires_derive<
+                T: ?::zerocopy::util::macro_util::core_reexport::marker::Sized + ::zerocopy::#trt,
+            >() {
+            }
+            fn ___zerocopy_check #impl_generics () #where_clause {
+                ___zerocopy_attribute_requires_derive::<#name #ty_generics>();
+            }
+        };
+    }
+}
+
 trait IntoTokenStream {
     fn into_ts(self) -> TokenStream;
 }
@@ -108,6 +169,34 @@ derive!(FromBytes => derive_from_bytes => derive_from_bytes_inner);
 derive!(IntoBytes => derive_into_bytes => derive_into_bytes_inner);
 derive!(Unaligned => derive_unaligned => derive_unaligned_inner);
 
+#[proc_macro_derive(FieldOffsets, attributes(zerocopy))]
+pub fn derive_field_offsets(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(ts as DeriveInput);
+    field_offsets::derive_field_offsets_inner(&ast).into_ts().into()
+}
+
+#[proc_macro_derive(DescribeLayout, attributes(zerocopy))]
+pub fn derive_describe_layout(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(ts as DeriveInput);
+    field_offsets::derive_describe_layout_inner(&ast).into_ts().into()
+}
+
+#[proc_macro_attribute]
+pub fn open_enum(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    open_enum::open_enum(attr.into(), item.into()).into_ts().into()
+}
+
+#[proc_macro_attribute]
+pub fn padded(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    padded::padded(attr.into(), item.into()).into_ts().into()
+}
+
 /// Deprecated: prefer [`FromZeros`] instead.
 #[deprecated(since = "0.8.0", note = "`FromZeroes` was renamed to `FromZeros`")]
 #[doc(hidden)]
@@ -273,7 +362,8 @@ fn derive_known_layout_inner(ast: &DeriveInput, _top_level: Trait) -> Result<Tok
         )
     };
 
-    Ok(match &ast.data {
+    let linked_len = linked_len::derive_linked_len(ast)?;
+    let known_layout = match &ast.data {
         Data::Struct(strct) => {
             let require_trait_bound_on_field_types = if self_bounds == SelfBounds::SIZED {
                 FieldBounds::None
@@ -321,7 +411,8 @@ fn derive_known_layout_inner(ast: &DeriveInput, _top_level: Trait) -> Result<Tok
                 Some(extras),
             )
         }
-    })
+    };
+    Ok(quote!(#known_layout #linked_len))
 }
 
 fn derive_no_cell_inner(ast: &DeriveInput, _top_level: Trait) -> TokenStream {
@@ -357,11 +448,66 @@ fn derive_no_cell_inner(ast: &DeriveInput, _top_level: Trait) -> TokenStream {
 }
 
 fn derive_try_from_bytes_inner(ast: &DeriveInput, top_level: Trait) -> Result<TokenStream, Error> {
+    let attrs = ZerocopyAttr::from_attrs(&ast.attrs)?;
+    if let Some(expr) = eq(&attrs) {
+        return Err(Error::new_spanned(
+            expr,
+            "`#[zerocopy(eq = ...)]` is only supported on struct fields",
+        ));
+    }
+    let constants = constant_fields(ast, top_level)?;
+    match (&ast.data, discriminant(&attrs)) {
+        (Data::Enum(enm), _) => derive_try_from_bytes_enum(ast, enm, top_level, &attrs),
+        (Data::Struct(_) | Data::Union(_), Some(ty)) => Err(Error::new_spanned(
+            ty,
+            "`#[zerocopy(discriminant = ...)]` is only supported on enums",
+        )),
+        (Data::Struct(strct), None) => {
+            derive_try_from_bytes_struct(ast, strct, top_level, &constants)
+        }
+        (Data::Union(unn), None) => Ok(derive_try_from_bytes_union(ast, unn, top_level)),
+    }
+}
+
+/// Returns the constant which each field of a struct must hold, as given by
+/// its `#[zerocopy(eq = ...)]` attribute.
+///
+/// The returned `Vec` is empty for enums and unions, whose fields may not have
+/// constants.
+fn constant_fields(ast: &DeriveInput, top_level: Trait) -> Result<Vec<Option<Expr>>, Error> {
+    let all_fields: Vec<&syn::Field> = match &ast.data {
+        Data::Struct(strct) => strct.fields.iter().collect(),
+        Data::Enum(enm) => enm.variants.iter().flat_map(|v| &v.fields).collect(),
+        Data::Union(unn) => unn.fields.named.iter().collect(),
+    };
+    let constants = all_fields
+        .iter()
+        .map(|field| Ok(eq(&ZerocopyAttr::from_attrs(&field.attrs)?).cloned()))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let first = match constants.iter().flatten().next() {
+        Some(first) => first,
+        None => return Ok(constants),
+    };
     match &ast.data {
-        Data::Struct(strct) => derive_try_from_bytes_struct(ast, strct, top_level),
-        Data::Enum(enm) => derive_try_from_bytes_enum(ast, enm, top_level),
-        Data::Union(unn) => Ok(derive_try_from_bytes_union(ast, unn, top_level)),
+        Data::Struct(_) => {}
+        Data::Enum(_) | Data::Union(_) => {
+            return Err(Error::new_spanned(
+                first,
+                "`#[zerocopy(eq = ...)]` is only supported on struct fields",
+            ))
+        }
     }
+    if top_level == Trait::FromZeros || top_level == Trait::FromBytes {
+        return Err(Error::new_spanned(
+            first,
+            format!(
+                "`#[zerocopy(eq = ...)]` is not supported when deriving `{}`",
+                top_level.to_token_stream()
+            ),
+        ));
+    }
+    Ok(constants)
 }
 
 fn derive_from_zeros_inner(ast: &DeriveInput, top_level: Trait) -> Result<TokenStream, Error> {
@@ -407,7 +553,12 @@ fn derive_try_from_bytes_struct(
     ast: &DeriveInput,
     strct: &DataStruct,
     top_level: Trait,
+    constants: &[Option<Expr>],
 ) -> Result<TokenStream, Error> {
+    if constants.iter().any(Option::is_some) {
+        return Ok(derive_try_from_bytes_struct_with_constants(ast, strct, constants));
+    }
+
     let extras = try_gen_trivial_is_bit_valid(ast, top_level).unwrap_or_else(|| {
         let fields = strct.fields();
         let field_names = fields.iter().map(|(name, _ty)| name);
@@ -455,6 +606,166 @@ fn derive_try_from_bytes_struct(
     ))
 }
 
+/// Derives `TryFromBytes` for a struct with one or more fields annotated with
+/// `#[zerocopy(eq = ...)]`.
+///
+/// Besides validating each field, the generated `is_bit_valid` checks that
+/// each such field holds its constant, and `mismatched_constant` reports the
+/// first field which does not. An inherent `new_zeroed_with_constants`
+/// constructor is also generated.
+fn derive_try_from_bytes_struct_with_constants(
+    ast: &DeriveInput,
+    strct: &DataStruct,
+    constants: &[Option<Expr>],
+) -> TokenStream {
+    let core_path = quote!(::zerocopy::util::macro_util::core_reexport);
+    let fields = strct.fields();
+
+    // Each field's check evaluates to `true` if the field is bit-valid and, if
+    // it has a constant, holds that constant.
+    let checks: Vec<_> = fields
+        .iter()
+        .zip(constants)
+        .map(|((name, ty), constant)| {
+            let constant_check = constant.as_ref().map(|constant| {
+                quote!(&& {
+                    // SAFETY: `<#ty as TryFromBytes>::is_bit_valid` has just
+                    // returned `true`, and so `field_candidate` references a
+                    // bit-valid `#ty`.
+                    let field = unsafe { field_candidate.assume_valid() };
+                    let expected: #ty =
+                        ::zerocopy::util::macro_util::IntoConstField::into_const_field(#constant);
+                    field.read_unaligned::<::zerocopy::BecauseImmutable>() == expected
+                })
+            });
+            quote!({
+                // SAFETY:
+                // - `project` is a field projection, and so it addresses a
+                //   subset of the bytes addressed by `slf`
+                // - ..., and so it preserves provenance
+                // - ..., and `*slf` is a struct, so `UnsafeCell`s exist at
+                //   the same byte ranges in the returned pointer's referent
+                //   as they do in `*slf`
+                #[allow(unused_mut)]
+                let mut field_candidate = unsafe {
+                    let project = |slf: *mut Self|
+                        #core_path::ptr::addr_of_mut!((*slf).#name);
+
+                    candidate.reborrow().project(project)
+                };
+
+                <#ty as ::zerocopy::TryFromBytes>::is_bit_valid(field_candidate.reborrow())
+                    #constant_check
+            })
+        })
+        .collect();
+
+    let constant_names =
+        strct.fields.iter().enumerate().zip(constants).filter_map(|((i, field), constant)| {
+            constant.as_ref().map(|_| match &field.ident {
+                Some(ident) => {
+                    let name = ident.to_string();
+                    name.strip_prefix("r#").unwrap_or(&name).to_string()
+                }
+                None => i.to_string(),
+            })
+        });
+    let constant_checks = checks
+        .iter()
+        .zip(constants)
+        .filter_map(|(check, constant)| constant.as_ref().map(|_| check));
+
+    let extras = quote!(
+        // SAFETY: We use `is_bit_valid` to validate that each field is
+        // bit-valid, and only return `true` if all of them are. The bit
+        // validity of a struct is just the composition of the bit validities
+        // of its fields, so this is a sound implementation of `is_bit_valid`.
+        // Checking that some fields hold particular values only makes this
+        // implementation stricter.
+        fn is_bit_valid<___ZerocopyAliasing>(
+            mut candidate: ::zerocopy::Maybe<Self, ___ZerocopyAliasing>,
+        ) -> #core_path::primitive::bool
+        where
+            ___ZerocopyAliasing: ::zerocopy::pointer::invariant::Reference,
+        {
+            true #(&& #checks)*
+        }
+
+        fn mismatched_constant<___ZerocopyAliasing>(
+            mut candidate: ::zerocopy::Maybe<Self, ___ZerocopyAliasing>,
+        ) -> #core_path::option::Option<&'static #core_path::primitive::str>
+        where
+            ___ZerocopyAliasing: ::zerocopy::pointer::invariant::Reference,
+        {
+            #(
+                if !#constant_checks {
+                    return #core_path::option::Option::Some(#constant_names);
+                }
+            )*
+            #core_path::option::Option::None
+        }
+    );
+
+    let try_from_bytes = impl_block(
+        ast,
+        strct,
+        Trait::TryFromBytes,
+        FieldBounds::ALL_SELF,
+        SelfBounds::None,
+        None,
+        Some(extras),
+    );
+
+    let names = fields.iter().map(|(name, _)| name);
+    let values = fields.iter().zip(constants).map(|((_, ty), constant)| match constant {
+        Some(constant) => quote!(
+            ::zerocopy::util::macro_util::IntoConstField::<#ty>::into_const_field(#constant)
+        ),
+        None => quote!(<#ty as ::zerocopy::FromZeros>::new_zeroed()),
+    });
+    // The bounds are higher-ranked so that they are only checked where the
+    // constructor is used, rather than rejected outright when a field is not
+    // `FromZeros`. For the same reason, the constructor is generated even if
+    // the struct is unsized: requiring the trailing field to be `Sized` (which
+    // may depend on the struct's type parameters) makes it uncallable then.
+    let from_zeros_bounds =
+        fields.iter().zip(constants).filter(|(_, constant)| constant.is_none()).map(
+            |((_, ty), _)| -> WherePredicate {
+                parse_quote!(for<'___zerocopy> #ty: ::zerocopy::FromZeros)
+            },
+        );
+    let sized_bound = fields.last().map(|(_, ty)| -> WherePredicate {
+        parse_quote!(for<'___zerocopy> #ty: #core_path::marker::Sized)
+    });
+    let bounds = sized_bound.into_iter().chain(from_zeros_bounds);
+    let ident = &ast.ident;
+    // The constructor sets every field, so it must not be more visible than
+    // any of them.
+    let vis = padded::most_restrictive_visibility(&ast.vis, &strct.fields);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let doc = format!(
+        "Constructs a `{}` whose fields annotated with `#[zerocopy(eq = ...)]` hold their \
+         constants, and whose other fields are zero.",
+        ident
+    );
+
+    quote! {
+        #try_from_bytes
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc = #doc]
+            #[must_use]
+            #[inline]
+            #vis fn new_zeroed_with_constants() -> Self
+            where
+                #(#bounds,)*
+            {
+                Self { #(#names: #values,)* }
+            }
+        }
+    }
+}
+
 /// A union is `TryFromBytes` if:
 /// - all of its fields are `TryFromBytes` and `Immutable`
 fn derive_try_from_bytes_union(
@@ -516,6 +827,7 @@ fn derive_try_from_bytes_enum(
     ast: &DeriveInput,
     enm: &DataEnum,
     top_level: Trait,
+    attrs: &[ZerocopyAttr],
 ) -> Result<TokenStream, Error> {
     let repr = EnumRepr::from_attrs(&ast.attrs)?;
 
@@ -537,7 +849,12 @@ fn derive_try_from_bytes_enum(
         (None, false) => r#enum::derive_is_bit_valid(&ast.ident, &repr, &ast.generics, enm)?,
     };
 
-    Ok(impl_block(
+    let wire = match discriminant(attrs) {
+        Some(ty) => Some(r#enum::derive_wire_type(ast, &repr, enm, ty)?),
+        None => None,
+    };
+
+    let try_from_bytes = impl_block(
         ast,
         enm,
         Trait::TryFromBytes,
@@ -545,7 +862,8 @@ fn derive_try_from_bytes_enum(
         SelfBounds::None,
         None,
         Some(extra),
-    ))
+    );
+    Ok(IntoIterator::into_iter([Some(try_from_bytes), wire]).flatten().collect())
 }
 
 /// Attempts to generate a `TryFromBytes::is_bit_valid` instance that
@@ -0,0 +1,146 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Expr, Lit, Member, Type};
+
+use crate::attr::{len, ZerocopyAttr};
+
+/// Generates `ref_from_prefix_linked` and `try_ref_from_prefix_linked` methods
+/// for a struct whose trailing slice field is annotated with `#[zerocopy(len =
+/// ...)]`.
+///
+/// Returns `None` if no field has such an attribute.
+pub(crate) fn derive_linked_len(ast: &DeriveInput) -> Result<Option<TokenStream>, Error> {
+    let fields: Vec<_> = match &ast.data {
+        Data::Struct(strct) => strct.fields.iter().collect(),
+        Data::Enum(enm) => enm.variants.iter().flat_map(|v| &v.fields).collect(),
+        Data::Union(unn) => unn.fields.named.iter().collect(),
+    };
+
+    let mut linked = None;
+    for (i, field) in fields.iter().enumerate() {
+        let attrs = ZerocopyAttr::from_attrs(&field.attrs)?;
+        let path = match len(&attrs) {
+            Some(path) => path,
+            None => continue,
+        };
+        let is_trailing_slice = i + 1 == fields.len()
+            && matches!(field.ty, Type::Slice(_))
+            && matches!(ast.data, Data::Struct(_));
+        if !is_trailing_slice {
+            return Err(Error::new_spanned(
+                path,
+                "`#[zerocopy(len = ...)]` is only supported on the trailing slice field of a struct",
+            ));
+        }
+        linked = Some(field_path(path)?);
+    }
+    let path = match linked {
+        Some(path) => path,
+        None => return Ok(None),
+    };
+
+    let ident = &ast.ident;
+    let vis = &ast.vis;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let core_path = quote!(::zerocopy::util::macro_util::core_reexport);
+    let path_str = path.iter().map(member_to_string).collect::<Vec<_>>().join(".");
+
+    let ref_doc = format!(
+        "Interprets the prefix of `source` as a `&{0}` whose trailing slice has as many \
+         elements as `{1}` specifies.\n\n\
+         This reads `{1}` from the prefix of `source`, and then behaves like \
+         [`FromBytes::ref_from_prefix_with_elems`](::zerocopy::FromBytes::ref_from_prefix_with_elems) \
+         with that element count. If `{1}` cannot be represented as a `usize`, this returns a \
+         size error.",
+        ident, path_str,
+    );
+    let try_ref_doc = format!(
+        "Attempts to interpret the prefix of `source` as a `&{0}` whose trailing slice has as \
+         many elements as `{1}` specifies.\n\n\
+         This reads `{1}` from the prefix of `source`, and then behaves like \
+         [`TryFromBytes::try_ref_from_prefix_with_elems`](::zerocopy::TryFromBytes::try_ref_from_prefix_with_elems) \
+         with that element count. If `{1}` cannot be represented as a `usize`, this returns a \
+         size error.",
+        ident, path_str,
+    );
+
+    // The bounds are higher-ranked so that they are only checked where the
+    // methods are used, rather than rejected outright when `Self` does not
+    // implement them.
+    Ok(Some(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc = #ref_doc]
+            #[inline]
+            #vis fn ref_from_prefix_linked(
+                source: &[#core_path::primitive::u8],
+            ) -> #core_path::result::Result<
+                (&Self, &[#core_path::primitive::u8]),
+                ::zerocopy::CastError<&[#core_path::primitive::u8], Self>,
+            >
+            where
+                for<'___zerocopy> Self: ::zerocopy::FromBytes + ::zerocopy::Immutable,
+            {
+                ::zerocopy::util::macro_util::ref_from_prefix_linked(source, |slf: &Self| {
+                    ::zerocopy::util::macro_util::LinkedLen::into_len(slf.#(#path).*)
+                })
+            }
+
+            #[doc = #try_ref_doc]
+            #[inline]
+            #vis fn try_ref_from_prefix_linked(
+                source: &[#core_path::primitive::u8],
+            ) -> #core_path::result::Result<
+                (&Self, &[#core_path::primitive::u8]),
+                ::zerocopy::TryCastError<&[#core_path::primitive::u8], Self>,
+            >
+            where
+                for<'___zerocopy> Self: ::zerocopy::TryFromBytes + ::zerocopy::Immutable,
+            {
+                ::zerocopy::util::macro_util::try_ref_from_prefix_linked(source, |slf: &Self| {
+                    ::zerocopy::util::macro_util::LinkedLen::into_len(slf.#(#path).*)
+                })
+            }
+        }
+    }))
+}
+
+/// Parses a path of field accesses, such as `header.count` or `0.len`.
+fn field_path(expr: &Expr) -> Result<Vec<Member>, Error> {
+    match expr {
+        Expr::Path(path) if path.qself.is_none() => match path.path.get_ident() {
+            Some(ident) => Ok(vec![Member::Named(ident.clone())]),
+            None => Err(Error::new_spanned(expr, "expected a field name")),
+        },
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => Ok(vec![Member::Unnamed(syn::Index {
+                index: int.base10_parse()?,
+                span: int.span(),
+            })]),
+            _ => Err(Error::new_spanned(expr, "expected a field name")),
+        },
+        Expr::Field(field) => {
+            let mut path = field_path(&field.base)?;
+            path.push(field.member.clone());
+            Ok(path)
+        }
+        _ => Err(Error::new_spanned(expr, "expected a path of fields, such as `header.count`")),
+    }
+}
+
+fn member_to_string(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => {
+            let name = ident.to_string();
+            name.strip_prefix("r#").unwrap_or(&name).to_string()
+        }
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    Attribute, DeriveInput, Error, Fields, Ident, ItemEnum, Path, Token, Type,
+};
+
+use crate::{
+    derive_from_bytes_inner, derive_into_bytes_inner, derive_known_layout_inner,
+    derive_no_cell_inner, derive_unaligned_inner, IntoTokenStream, Trait,
+};
+
+/// The arguments to `#[open_enum(...)]`: the type of the newtype's field,
+/// optionally followed by `known = Ident` to name the closed enum.
+struct OpenEnumArgs {
+    repr: Type,
+    known: Option<Ident>,
+}
+
+impl Parse for OpenEnumArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<OpenEnumArgs> {
+        let repr = input.parse()?;
+        let mut known = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "known" {
+                return Err(Error::new(key.span(), "expected `known = ...`"));
+            }
+            input.parse::<Token![=]>()?;
+            known = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(OpenEnumArgs { repr, known })
+    }
+}
+
+/// An integer type: either a native integer or a byte-order-aware integer.
+pub(crate) struct IntRepr {
+    /// The corresponding native integer type.
+    pub(crate) native: Ident,
+    /// Whether the type is a byte-order-aware integer (e.g., `U16<BE>`) rather
+    /// than a native integer.
+    pub(crate) byte_order: bool,
+}
+
+impl IntRepr {
+    /// Recognizes `ty` as an integer type.
+    ///
+    /// Procedural macros cannot resolve types, so this only inspects the name
+    /// of the last segment of `ty`'s path. Aliases are not recognized, and a
+    /// user-defined type which shadows one of these names is accepted as the
+    /// type it shadows (and will fail to type-check in the generated code if
+    /// it is not compatible).
+    pub(crate) fn from_type(ty: &Type) -> Result<IntRepr, Error> {
+        const NATIVE: &[&str] = &[
+            "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize",
+        ];
+        const BYTE_ORDER: &[&str] =
+            &["U16", "I16", "U32", "I32", "U64", "I64", "U128", "I128", "Usize", "Isize"];
+
+        if let Type::Path(path) = ty {
+            if let (None, Some(last)) = (&path.qself, path.path.segments.last()) {
+                let name = last.ident.to_string();
+                if last.arguments.is_empty() && NATIVE.contains(&name.as_str()) {
+                    return Ok(IntRepr { native: last.ident.clone(), byte_order: false });
+                }
+                if BYTE_ORDER.contains(&name.as_str()) {
+                    let native = Ident::new(&name.to_lowercase(), last.ident.span());
+                    return Ok(IntRepr { native, byte_order: true });
+                }
+            }
+        }
+
+        Err(Error::new_spanned(
+            ty,
+            "must be a primitive integer type (e.g., `u8`) or a byte-order-aware integer type (e.g., `U16<BigEndian>`)",
+        ))
+    }
+
+    /// Is the field type guaranteed to have alignment 1?
+    fn is_unaligned(&self) -> bool {
+        self.byte_order || self.native == "u8" || self.native == "i8"
+    }
+}
+
+fn is_repr(attr: &Attribute) -> bool {
+    attr.path().is_ident("repr")
+}
+
+fn is_cfg(attr: &Attribute) -> bool {
+    attr.path().is_ident("cfg") || attr.path().is_ident("cfg_attr")
+}
+
+/// The traits which `open_enum` implements for the newtype, and which the user
+/// therefore must not derive.
+const GENERATED_TRAITS: &[&str] = &["Clone", "Copy", "Debug", "PartialEq", "Eq", "Hash"];
+
+/// Returns an error if `attrs` derive any of [`GENERATED_TRAITS`].
+fn check_derives(attrs: &[Attribute]) -> Result<(), Error> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+        let paths = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+        for path in paths {
+            if let Some(last) = path.segments.last() {
+                if GENERATED_TRAITS.iter().any(|t| last.ident == t) {
+                    return Err(Error::new_spanned(
+                        &path,
+                        format!(
+                            "`open_enum` generates an implementation of `{}`; remove this derive",
+                            last.ident
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn open_enum(attr: TokenStream, item: TokenStream) -> Result<TokenStream, Error> {
+    if attr.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`open_enum` requires an integer type argument (e.g., `#[open_enum(u8)]`)",
+        ));
+    }
+    let args: OpenEnumArgs = syn::parse2(attr)?;
+    let enm: ItemEnum = syn::parse2(item)?;
+
+    if let Some(repr) = enm.attrs.iter().find(|attr| is_repr(attr)) {
+        return Err(Error::new_spanned(
+            repr,
+            "`open_enum` determines the representation of its output; remove this `repr` attribute",
+        ));
+    }
+    check_derives(&enm.attrs)?;
+    if enm.generics.lt_token.is_some() || enm.generics.where_clause.is_some() {
+        return Err(Error::new_spanned(&enm.generics, "`open_enum` does not support generics"));
+    }
+    if let Some(v) = enm.variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        return Err(Error::new_spanned(v, "`open_enum` only supports field-less variants"));
+    }
+
+    let int = IntRepr::from_type(&args.repr)?;
+    let repr = &args.repr;
+    let native = &int.native;
+    let vis = &enm.vis;
+    let name = &enm.ident;
+    let known =
+        args.known.unwrap_or_else(|| Ident::new(&format!("{}Known", enm.ident), enm.ident.span()));
+    let attrs = &enm.attrs;
+    let core = quote!(::zerocopy::util::macro_util::core_reexport);
+
+    let variant_idents: Vec<_> = enm.variants.iter().map(|v| &v.ident).collect();
+    let variant_attrs: Vec<_> = enm.variants.iter().map(|v| &v.attrs).collect();
+    let variant_cfgs: Vec<Vec<_>> =
+        enm.variants.iter().map(|v| v.attrs.iter().filter(|a| is_cfg(a)).collect()).collect();
+    let variant_discriminants = enm.variants.iter().map(|v| match &v.discriminant {
+        Some((eq, discriminant)) => quote!(#eq #discriminant),
+        None => quote!(),
+    });
+    let tag_idents: Vec<_> = variant_idents
+        .iter()
+        .map(|ident| Ident::new(&format!("___ZEROCOPY_TAG_{}", ident), ident.span()))
+        .collect();
+
+    let wrap = |native_value: TokenStream| {
+        if int.byte_order {
+            quote!(<#repr>::new(#native_value))
+        } else {
+            native_value
+        }
+    };
+    let get = if int.byte_order { quote!(self.0.get()) } else { quote!(self.0) };
+    let consts = variant_idents.iter().map(|ident| wrap(quote!(#known::#ident as #native)));
+    let from_known = wrap(quote!(known as #native));
+
+    let strct: DeriveInput = parse_quote! {
+        #[repr(transparent)]
+        #vis struct #name(#vis #repr);
+    };
+    let mut impls = vec![
+        derive_known_layout_inner(&strct, Trait::KnownLayout).into_ts(),
+        derive_no_cell_inner(&strct, Trait::Immutable),
+        derive_from_bytes_inner(&strct, Trait::FromBytes).into_ts(),
+        derive_into_bytes_inner(&strct, Trait::IntoBytes).into_ts(),
+    ];
+    if int.is_unaligned() {
+        impls.push(derive_unaligned_inner(&strct, Trait::Unaligned).into_ts());
+    }
+
+    let known_doc = format!("The known variants of [`{}`].", name);
+
+    Ok(quote! {
+        #(#attrs)*
+        #[repr(transparent)]
+        #[derive(
+            #core::clone::Clone,
+            #core::marker::Copy,
+            #core::cmp::PartialEq,
+            #core::cmp::Eq,
+            #core::hash::Hash,
+        )]
+        #vis struct #name(#vis #repr);
+
+        #(#impls)*
+
+        #[doc = #known_doc]
+        #[repr(#native)]
+        #[derive(
+            #core::clone::Clone,
+            #core::marker::Copy,
+            #core::fmt::Debug,
+            #core::cmp::PartialEq,
+            #core::cmp::Eq,
+            #core::hash::Hash,
+        )]
+        #vis enum #known {
+            #(
+                #(#variant_attrs)*
+                #variant_idents #variant_discriminants,
+            )*
+        }
+
+        #[allow(non_upper_case_globals)]
+        impl #name {
+            #(
+                #(#variant_attrs)*
+                #vis const #variant_idents: #name = #name(#consts);
+            )*
+
+            /// Converts this value to the corresponding known variant, or
+            /// returns `None` if it does not correspond to any known variant.
+            #[must_use]
+            #[inline]
+            #vis const fn known(self) -> #core::option::Option<#known> {
+                #(
+                    #(#variant_cfgs)*
+                    const #tag_idents: #native = #known::#variant_idents as #native;
+                )*
+
+                match #get {
+                    #(
+                        #(#variant_cfgs)*
+                        #tag_idents => #core::option::Option::Some(#known::#variant_idents),
+                    )*
+                    _ => #core::option::Option::None,
+                }
+            }
+        }
+
+        impl #core::convert::From<#known> for #name {
+            #[inline]
+            fn from(known: #known) -> #name {
+                #name(#from_known)
+            }
+        }
+
+        impl #core::fmt::Debug for #name {
+            fn fmt(&self, f: &mut #core::fmt::Formatter<'_>) -> #core::fmt::Result {
+                match #name::known(*self) {
+                    #core::option::Option::Some(known) => #core::fmt::Debug::fmt(&known, f),
+                    #core::option::Option::None => #core::fmt::Debug::fmt(&#get, f),
+                }
+            }
+        }
+    })
+}
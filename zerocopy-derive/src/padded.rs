@@ -0,0 +1,190 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote, Error, Field, FieldMutability, Fields, Ident, ItemStruct, Token, Visibility,
+};
+
+use crate::repr::StructUnionRepr;
+
+/// The arguments to `#[padded(...)]`: optionally, `constructor = Ident` to
+/// name the generated constructor.
+struct PaddedArgs {
+    constructor: Option<Ident>,
+}
+
+impl Parse for PaddedArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<PaddedArgs> {
+        let mut constructor = None;
+        if !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "constructor" {
+                return Err(Error::new(key.span(), "expected `constructor = ...`"));
+            }
+            input.parse::<Token![=]>()?;
+            constructor = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(PaddedArgs { constructor })
+    }
+}
+
+fn padding_ident(index: usize) -> Ident {
+    Ident::new(&format!("___zerocopy_padding_{}", index), Span::call_site())
+}
+
+/// Returns a visibility which is no more visible than `vis` or than the
+/// visibility of any of `fields`.
+///
+/// This is the visibility given to generated constructors which take the value
+/// of every field, so that they do not allow code which could not otherwise
+/// construct the type to do so. Visibilities can only be compared
+/// syntactically, so if two different `pub(in ...)` or `pub(super)`
+/// visibilities are present, this conservatively returns the private
+/// visibility.
+pub(crate) fn most_restrictive_visibility<'a>(
+    vis: &Visibility,
+    fields: impl IntoIterator<Item = &'a Field>,
+) -> Visibility {
+    // Ranks `vis` from least (0) to most (3) visible. Visibilities of rank 1
+    // cannot be compared with each other.
+    fn rank(vis: &Visibility) -> u8 {
+        match vis {
+            Visibility::Inherited => 0,
+            Visibility::Restricted(r) if r.in_token.is_none() && r.path.is_ident("self") => 0,
+            Visibility::Restricted(r) if r.in_token.is_none() && r.path.is_ident("crate") => 2,
+            Visibility::Restricted(_) => 1,
+            Visibility::Public(_) => 3,
+        }
+    }
+
+    let vises: Vec<&Visibility> =
+        core::iter::once(vis).chain(fields.into_iter().map(|field| &field.vis)).collect();
+    let min = vises.iter().map(|vis| rank(vis)).min().unwrap_or(0);
+    let mut candidates = vises.into_iter().filter(|vis| rank(vis) == min);
+    let first = match candidates.next() {
+        Some(first) => first,
+        None => return Visibility::Inherited,
+    };
+    let first_tokens = first.to_token_stream().to_string();
+    if min == 1 && candidates.any(|vis| vis.to_token_stream().to_string() != first_tokens) {
+        return Visibility::Inherited;
+    }
+    first.clone()
+}
+
+pub(crate) fn padded(attr: TokenStream, item: TokenStream) -> Result<TokenStream, Error> {
+    let args: PaddedArgs = syn::parse2(attr)?;
+    let mut strct: ItemStruct = syn::parse2(item)?;
+
+    let repr = StructUnionRepr::from_attrs(&strct.attrs)?;
+    if !repr.is_c() {
+        return Err(Error::new(
+            Span::call_site(),
+            "must have #[repr(C)] attribute in order to compute this type's padding",
+        ));
+    }
+    if repr.get_packed().is_some() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`padded` does not support #[repr(packed)], which never has padding",
+        ));
+    }
+    if !strct.generics.params.is_empty() {
+        return Err(Error::new_spanned(&strct.generics, "`padded` does not support generic types"));
+    }
+    let fields = match &strct.fields {
+        Fields::Named(fields) => fields.named.iter().cloned().collect::<Vec<_>>(),
+        Fields::Unnamed(_) | Fields::Unit => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`padded` only supports structs with named fields",
+            ))
+        }
+    };
+
+    let core_path = quote!(::zerocopy::util::macro_util::core_reexport);
+    let repr_align = repr
+        .get_align()
+        .map(|align| {
+            let align = align.t.get();
+            quote!(#core_path::num::NonZeroUsize::new(#align as usize))
+        })
+        .unwrap_or_else(|| quote!(#core_path::option::Option::None));
+
+    // After each field, insert a padding field which covers the gap between
+    // that field and the next one (or, after the last field, the end of the
+    // struct). Since the padding fields have alignment 1 and exactly fill
+    // those gaps, the original fields keep the offsets they would have had
+    // without them.
+    let tys: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let mut padded_fields = Vec::new();
+    let mut paddings = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let preceding = &tys[..=i];
+        let next = match tys.get(i + 1) {
+            Some(next) => {
+                quote!(#core_path::option::Option::Some(DstLayout::for_type::<#next>()))
+            }
+            None => quote!(#core_path::option::Option::None),
+        };
+        let len = quote!({
+            use ::zerocopy::DstLayout;
+            ::zerocopy::util::macro_util::repr_c_padding(
+                DstLayout::new_zst(#repr_align)
+                    #(.extend(DstLayout::for_type::<#preceding>(), #core_path::option::Option::None))*,
+                #next,
+            )
+        });
+
+        let ident = padding_ident(i);
+        padded_fields.push(field.clone());
+        padded_fields.push(Field {
+            attrs: Vec::new(),
+            vis: Visibility::Inherited,
+            mutability: FieldMutability::None,
+            ident: Some(ident.clone()),
+            colon_token: Some(Default::default()),
+            ty: parse_quote!([u8; #len]),
+        });
+        paddings.push(quote!(#ident: [0u8; #len]));
+    }
+
+    if let Fields::Named(named) = &mut strct.fields {
+        named.named = padded_fields.into_iter().collect();
+    }
+
+    let ident = &strct.ident;
+    let vis = most_restrictive_visibility(&strct.vis, &fields);
+    let constructor = args.constructor.unwrap_or_else(|| Ident::new("new", Span::call_site()));
+    let field_idents: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let doc = format!(
+        "Constructs a new `{}` from the values of its fields, with all padding bytes set to zero.",
+        ident
+    );
+
+    Ok(quote! {
+        #strct
+
+        impl #ident {
+            #[doc = #doc]
+            #[allow(clippy::too_many_arguments)]
+            #[must_use]
+            #[inline]
+            #vis const fn #constructor(#(#field_idents: #tys),*) -> #ident {
+                #ident {
+                    #(#field_idents,)*
+                    #(#paddings,)*
+                }
+            }
+        }
+    })
+}
@@ -626,3 +626,49 @@ fn test_trivial_is_bit_valid() {
     util_assert_not_impl_any!(FooU8: imp::FromBytes);
     util::test_trivial_is_bit_valid::<FooU8>();
 }
+
+#[derive(
+    Eq, PartialEq, Debug, Copy, Clone, imp::KnownLayout, imp::Immutable, imp::TryFromBytes,
+)]
+#[zerocopy(discriminant = imp::U16<imp::byteorder::BigEndian>)]
+#[repr(u16)]
+enum MessageType {
+    Hello = 0x0102,
+    Goodbye = 0x0304,
+}
+
+util_assert_impl_all!(MessageTypeWire: imp::KnownLayout, imp::Immutable, imp::TryFromBytes, imp::IntoBytes, imp::Unaligned);
+util_assert_not_impl_any!(MessageTypeWire: imp::FromZeros);
+
+#[test]
+fn test_discriminant_wire_type() {
+    let wire = <MessageTypeWire as imp::TryFromBytes>::try_read_from_bytes(&[0x01, 0x02]).unwrap();
+    imp::assert_eq!(wire.get(), MessageType::Hello);
+    imp::assert_eq!(<MessageType as imp::From<MessageTypeWire>>::from(wire), MessageType::Hello);
+
+    let wire = <MessageTypeWire as imp::TryFromBytes>::try_ref_from_bytes(&[0x03, 0x04]).unwrap();
+    imp::assert_eq!(wire.get(), MessageType::Goodbye);
+
+    imp::assert!(
+        <MessageTypeWire as imp::TryFromBytes>::try_read_from_bytes(&[0x02, 0x01]).is_err()
+    );
+    imp::assert!(
+        <MessageTypeWire as imp::TryFromBytes>::try_read_from_bytes(&[0x00, 0x00]).is_err()
+    );
+    imp::assert!(<MessageTypeWire as imp::TryFromBytes>::try_read_from_bytes(&[0x01]).is_err());
+
+    let wire = MessageTypeWire::new(MessageType::Goodbye);
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&wire), [0x03, 0x04]);
+    imp::assert_eq!(
+        <MessageTypeWire as imp::From<MessageType>>::from(MessageType::Hello).get(),
+        MessageType::Hello
+    );
+
+    // The enum itself keeps its native representation.
+    imp::assert_eq!(
+        <MessageType as imp::TryFromBytes>::try_read_from_bytes(imp::IntoBytes::as_bytes(
+            &0x0304u16
+        )),
+        imp::Ok(MessageType::Goodbye)
+    );
+}
@@ -0,0 +1,93 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+#[imp::open_enum(u8)]
+enum Foo {
+    A,
+    B = 5,
+    C,
+}
+
+util_assert_impl_all!(Foo: imp::KnownLayout, imp::Immutable, imp::FromBytes, imp::IntoBytes, imp::Unaligned);
+util_assert_impl_all!(FooKnown: imp::core::fmt::Debug, imp::Copy, imp::Eq, imp::core::hash::Hash);
+
+#[test]
+fn test_open_enum_u8() {
+    imp::assert_eq!(::core::mem::size_of::<Foo>(), 1);
+    imp::assert_eq!(Foo::A.0, 0);
+    imp::assert_eq!(Foo::B.0, 5);
+    imp::assert_eq!(Foo::C.0, 6);
+
+    imp::assert_eq!(Foo::A.known(), imp::Some(FooKnown::A));
+    imp::assert_eq!(Foo(6).known(), imp::Some(FooKnown::C));
+    imp::assert_eq!(Foo(1).known(), imp::None);
+    imp::assert_eq!(<Foo as imp::From<FooKnown>>::from(FooKnown::B), Foo::B);
+
+    imp::assert_eq!(::std::format!("{:?}", Foo::B), "B");
+    imp::assert_eq!(::std::format!("{:?}", Foo(7)), "7");
+
+    // Open enums can be matched on like ordinary enums.
+    let describe = |foo: Foo| match foo {
+        Foo::A => "A",
+        Foo::B | Foo::C => "B or C",
+        _ => "unknown",
+    };
+    imp::assert_eq!(describe(Foo(0)), "A");
+    imp::assert_eq!(describe(Foo(6)), "B or C");
+    imp::assert_eq!(describe(Foo(255)), "unknown");
+}
+
+#[imp::open_enum(imp::U16<imp::byteorder::BigEndian>, known = KnownEtherType)]
+#[derive(imp::PartialOrd, imp::Ord)]
+pub enum EtherType {
+    /// Internet Protocol version 4.
+    Ipv4 = 0x0800,
+    Arp = 0x0806,
+    Ipv6 = 0x86DD,
+}
+
+util_assert_impl_all!(EtherType: imp::KnownLayout, imp::Immutable, imp::FromBytes, imp::IntoBytes, imp::Unaligned, imp::Ord);
+
+#[test]
+fn test_open_enum_byte_order() {
+    imp::assert_eq!(::core::mem::size_of::<EtherType>(), 2);
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&EtherType::Ipv6), [0x86, 0xDD]);
+
+    let ether_type = <EtherType as imp::FromBytes>::ref_from_bytes(&[0x08, 0x06][..]).unwrap();
+    imp::assert_eq!(*ether_type, EtherType::Arp);
+    imp::assert_eq!(ether_type.known(), imp::Some(KnownEtherType::Arp));
+
+    let ether_type = <EtherType as imp::FromBytes>::read_from_bytes(&[0x12, 0x34][..]).unwrap();
+    imp::assert_eq!(ether_type.known(), imp::None);
+    imp::assert_eq!(ether_type.0.get(), 0x1234);
+    imp::assert_eq!(::std::format!("{:?}", ether_type), "4660");
+    imp::assert_eq!(::std::format!("{:#x?}", ether_type), "0x1234");
+    imp::assert_eq!(::std::format!("{:?}", EtherType::Ipv4), "Ipv4");
+}
+
+#[imp::open_enum(i16)]
+enum Bar {
+    Neg = -1,
+    Pos = 1,
+}
+
+util_assert_impl_all!(Bar: imp::KnownLayout, imp::Immutable, imp::FromBytes, imp::IntoBytes);
+util_assert_not_impl_any!(Bar: imp::Unaligned);
+
+#[test]
+fn test_open_enum_signed() {
+    imp::assert_eq!(Bar::Neg.0, -1);
+    imp::assert_eq!(Bar(-1).known(), imp::Some(BarKnown::Neg));
+    imp::assert_eq!(Bar(0).known(), imp::None);
+}
@@ -0,0 +1,96 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+// Without `#[padded]`, `Foo` would have one byte of padding after `a`, and one
+// byte after `c`.
+#[imp::padded]
+#[derive(imp::KnownLayout, imp::Immutable, imp::FromBytes, imp::IntoBytes)]
+#[repr(C)]
+struct Foo {
+    a: u8,
+    b: util::AU16,
+    c: u8,
+}
+
+util_assert_impl_all!(Foo: imp::FromBytes, imp::IntoBytes);
+
+#[test]
+fn test_foo() {
+    imp::assert_eq!(::core::mem::size_of::<Foo>(), 6);
+    imp::assert_eq!(::core::mem::align_of::<Foo>(), 2);
+
+    let foo = Foo::new(1, util::AU16(0x0202), 3);
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&foo), [1, 0, 2, 2, 3, 0]);
+
+    let foo = <Foo as imp::FromBytes>::read_from_bytes(&[4, 0xFF, 5, 5, 6, 0xFF][..]).unwrap();
+    imp::assert_eq!((foo.a, foo.b.0, foo.c), (4, 0x0505, 6));
+}
+
+// `repr(align)` adds trailing padding.
+#[imp::padded]
+#[derive(imp::KnownLayout, imp::Immutable, imp::FromBytes, imp::IntoBytes)]
+#[repr(C, align(8))]
+pub struct Bar {
+    pub a: u8,
+    pub b: [u8; 2],
+}
+
+util_assert_impl_all!(Bar: imp::FromBytes, imp::IntoBytes);
+
+#[test]
+fn test_bar() {
+    imp::assert_eq!(::core::mem::size_of::<Bar>(), 8);
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&Bar::new(1, [2, 3])), [1, 2, 3, 0, 0, 0, 0, 0]);
+}
+
+// Structs without padding are unchanged.
+#[imp::padded]
+#[derive(imp::KnownLayout, imp::Immutable, imp::FromBytes, imp::IntoBytes)]
+#[repr(C)]
+struct Baz {
+    a: util::AU16,
+    b: u8,
+    c: u8,
+}
+
+const BAZ: Baz = Baz::new(util::AU16(1), 2, 3);
+
+#[test]
+fn test_baz() {
+    imp::assert_eq!(::core::mem::size_of::<Baz>(), 4);
+    let [b0, b1] = 1u16.to_ne_bytes();
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&BAZ), [b0, b1, 2, 3]);
+}
+
+// The constructor can be renamed so that it does not collide with a
+// user-defined `new`.
+#[imp::padded(constructor = from_fields)]
+#[derive(imp::KnownLayout, imp::Immutable, imp::FromBytes, imp::IntoBytes)]
+#[repr(C)]
+struct Qux {
+    a: u8,
+    b: util::AU16,
+}
+
+impl Qux {
+    fn new(b: u16) -> Qux {
+        Qux::from_fields(0, util::AU16(b))
+    }
+}
+
+#[test]
+fn test_qux() {
+    let [b0, b1] = 0x0102u16.to_ne_bytes();
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&Qux::new(0x0102)), [0, 0, b0, b1]);
+}
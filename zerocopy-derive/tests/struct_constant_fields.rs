@@ -0,0 +1,146 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable, imp::IntoBytes, imp::Unaligned)]
+#[repr(C)]
+struct Header {
+    #[zerocopy(eq = b"\x7fELF")]
+    magic: [u8; 4],
+    #[zerocopy(eq = 2)]
+    version: imp::byteorder::U16<imp::byteorder::BigEndian>,
+    flags: u8,
+    valid: bool,
+}
+
+util_assert_impl_all!(Header: imp::TryFromBytes);
+util_assert_not_impl_any!(Header: imp::FromZeros);
+
+fn mismatched_constant<A, S, Src, Dst: ?imp::Sized + imp::TryFromBytes>(
+    err: imp::ConvertError<A, S, imp::ValidityError<Src, Dst>>,
+) -> imp::Option<&'static str> {
+    match err {
+        imp::ConvertError::Validity(err) => err.mismatched_constant(),
+        _ => imp::panic!("expected a validity error"),
+    }
+}
+
+#[test]
+fn test_header() {
+    let header = Header::new_zeroed_with_constants();
+    imp::assert_eq!(imp::IntoBytes::as_bytes(&header), b"\x7fELF\x00\x02\x00\x00");
+
+    let bytes = *b"\x7fELF\x00\x02\x05\x01";
+    let header = <Header as imp::TryFromBytes>::try_ref_from_bytes(&bytes[..]).unwrap();
+    imp::assert_eq!((header.flags, header.valid), (5, true));
+
+    let err = <Header as imp::TryFromBytes>::try_ref_from_bytes(&b"\x7fELG\x00\x02\x05\x01"[..])
+        .map(|_| ())
+        .unwrap_err();
+    imp::assert_eq!(mismatched_constant(err), imp::Some("magic"));
+
+    let err = <Header as imp::TryFromBytes>::try_read_from_bytes(&b"\x7fELF\x02\x00\x05\x01"[..])
+        .map(|_| ())
+        .unwrap_err();
+    imp::assert_eq!(mismatched_constant(err), imp::Some("version"));
+
+    // Fields without constants are still validated.
+    let err = <Header as imp::TryFromBytes>::try_ref_from_bytes(&b"\x7fELF\x00\x02\x05\x02"[..])
+        .map(|_| ())
+        .unwrap_err();
+    imp::assert_eq!(mismatched_constant(err), imp::None);
+}
+
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct Tuple<T>(#[zerocopy(eq = -1)] i32, T, #[zerocopy(eq = 1.5)] f32);
+
+#[test]
+fn test_tuple() {
+    let tuple = Tuple::<u64>::new_zeroed_with_constants();
+    imp::assert_eq!((tuple.0, tuple.1, tuple.2), (-1, 0, 1.5));
+
+    let mut tuple = Tuple::<u64>::new_zeroed_with_constants();
+    tuple.2 = 2.0;
+    // SAFETY: `Tuple<u64>` has no padding.
+    let bytes = unsafe {
+        ::core::slice::from_raw_parts(
+            (&tuple as *const Tuple<u64>).cast::<u8>(),
+            ::core::mem::size_of::<Tuple<u64>>(),
+        )
+    };
+    let err =
+        <Tuple<u64> as imp::TryFromBytes>::try_read_from_bytes(bytes).map(|_| ()).unwrap_err();
+    imp::assert_eq!(mismatched_constant(err), imp::Some("2"));
+}
+
+// The fields of a struct with constants need not be `FromZeros`, although its
+// constructor can then not be used.
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct NonZero {
+    #[zerocopy(eq = 7)]
+    kind: u8,
+    nonzero: ::core::num::NonZeroU8,
+}
+
+// The constructor cannot be called on DSTs.
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct Dst {
+    #[zerocopy(eq = 7)]
+    kind: u8,
+    body: [u8],
+}
+
+#[test]
+fn test_dst() {
+    let dst = <Dst as imp::TryFromBytes>::try_ref_from_bytes(&[7, 1, 2, 3][..]).unwrap();
+    imp::assert_eq!(&dst.body, &[1, 2, 3][..]);
+
+    let err =
+        <Dst as imp::TryFromBytes>::try_ref_from_bytes(&[8, 1, 2, 3][..]).map(|_| ()).unwrap_err();
+    imp::assert_eq!(mismatched_constant(err), imp::Some("kind"));
+}
+
+type Bytes = [u8];
+
+// Whether the struct is a DST is determined from the type of its trailing
+// field, not from its syntax.
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct AliasDst {
+    #[zerocopy(eq = 7)]
+    kind: u8,
+    body: Bytes,
+}
+
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct MaybeDst<T: ?imp::Sized> {
+    #[zerocopy(eq = 7)]
+    kind: u8,
+    body: T,
+}
+
+util_assert_impl_all!(AliasDst: imp::TryFromBytes);
+util_assert_impl_all!(MaybeDst<[u8]>: imp::TryFromBytes);
+
+#[test]
+fn test_maybe_dst() {
+    let sized = MaybeDst::<[u8; 2]>::new_zeroed_with_constants();
+    imp::assert_eq!((sized.kind, sized.body), (7, [0, 0]));
+
+    let dst = <MaybeDst<[u8]> as imp::TryFromBytes>::try_ref_from_bytes(&[7, 1, 2][..]).unwrap();
+    imp::assert_eq!(&dst.body, &[1, 2][..]);
+}
@@ -0,0 +1,109 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+// These fixtures also derive `FieldOffsets` so that the tests can check that
+// the two derives agree.
+
+/// A `u16` with alignment 2.
+///
+/// Unlike `util::AU16`, this implements `DescribeLayout`.
+#[derive(imp::KnownLayout, imp::DescribeLayout)]
+#[repr(C, align(2))]
+struct AU16(u16);
+
+/// Asserts that a type's `FieldOffsets` and `DescribeLayout` agree.
+fn assert_consistent<const N: usize>(
+    offsets: [(&'static str, usize, usize); N],
+    layout: &imp::TypeLayout,
+) {
+    let fields = layout.fields();
+    imp::assert_eq!(fields.len(), N);
+    for ((name, offset, _size), field) in imp::Iterator::zip(offsets.iter(), fields.iter()) {
+        imp::assert_eq!((*name, *offset), (field.name(), field.offset()));
+    }
+}
+
+#[derive(imp::KnownLayout, imp::FieldOffsets, imp::DescribeLayout)]
+#[repr(C)]
+struct Foo {
+    a: u8,
+    b: AU16,
+    r#type: [u8; 3],
+}
+
+#[test]
+fn test_foo() {
+    let layout = <Foo as imp::DescribeLayout>::TYPE_LAYOUT;
+    assert_consistent(Foo::FIELD_OFFSETS, &layout);
+    imp::assert_eq!((layout.size(), layout.align()), (8, 2));
+    imp::assert_eq!(::std::format!("{}", layout), "Foo");
+    let fields = layout.fields();
+    imp::assert_eq!(::std::format!("{}", fields[2].layout()), "[u8; 3]");
+    imp::assert_eq!(fields[1].layout(), &<AU16 as imp::DescribeLayout>::TYPE_LAYOUT);
+
+    let mut padding = ::std::vec::Vec::new();
+    layout.for_each_padding(|range| padding.push(range));
+    imp::assert_eq!(padding, [1..2, 7..8]);
+}
+
+#[derive(imp::KnownLayout, imp::FieldOffsets, imp::DescribeLayout)]
+#[repr(C, packed)]
+struct Packed(u8, u16, u8);
+
+#[test]
+fn test_packed() {
+    let layout = <Packed as imp::DescribeLayout>::TYPE_LAYOUT;
+    assert_consistent(Packed::FIELD_OFFSETS, &layout);
+    imp::assert_eq!((layout.size(), layout.align()), (4, 1));
+}
+
+// Layouts are available for DSTs, including the trailing field.
+#[derive(imp::KnownLayout, imp::FieldOffsets, imp::DescribeLayout)]
+#[repr(C)]
+struct Dst {
+    a: u8,
+    b: AU16,
+    c: [u8],
+}
+
+#[test]
+fn test_dst() {
+    let layout = <Dst as imp::DescribeLayout>::TYPE_LAYOUT;
+    assert_consistent(Dst::FIELD_OFFSETS, &layout);
+    imp::assert_eq!(layout.size(), 4);
+    imp::assert_eq!(
+        layout.trailing_slice_elem(),
+        imp::Some(&<u8 as imp::DescribeLayout>::TYPE_LAYOUT)
+    );
+}
+
+// Layouts are available in generic types.
+#[derive(imp::KnownLayout, imp::FieldOffsets, imp::DescribeLayout)]
+#[repr(C)]
+struct Generic<T> {
+    a: u8,
+    b: T,
+    c: u8,
+}
+
+fn generic_offsets<T>() -> [(&'static str, usize, usize); 3] {
+    Generic::<T>::FIELD_OFFSETS
+}
+
+#[test]
+fn test_generic() {
+    let layout = <Generic<AU16> as imp::DescribeLayout>::TYPE_LAYOUT;
+    assert_consistent(generic_offsets::<AU16>(), &layout);
+    imp::assert_eq!((layout.size(), layout.align()), (6, 2));
+}
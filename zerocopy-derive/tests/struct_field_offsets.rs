@@ -0,0 +1,73 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+#[derive(imp::FieldOffsets)]
+#[repr(C)]
+struct Foo {
+    a: u8,
+    b: util::AU16,
+    r#type: [u8; 3],
+}
+
+#[test]
+fn test_foo() {
+    imp::assert_eq!((Foo::A_OFFSET, Foo::A_SIZE), (0, 1));
+    imp::assert_eq!((Foo::B_OFFSET, Foo::B_SIZE), (2, 2));
+    imp::assert_eq!((Foo::TYPE_OFFSET, Foo::TYPE_SIZE), (4, 3));
+    imp::assert_eq!(Foo::FIELD_OFFSETS, [("a", 0, 1), ("b", 2, 2), ("type", 4, 3)]);
+}
+
+#[derive(imp::FieldOffsets)]
+#[repr(C, packed)]
+struct Packed(u8, u16, u8);
+
+#[test]
+fn test_packed() {
+    imp::assert_eq!(Packed::FIELD_OFFSETS, [("0", 0, 1), ("1", 1, 2), ("2", 3, 1)]);
+    imp::assert_eq!(Packed::_1_OFFSET, 1);
+}
+
+// Offsets are available for fields of DSTs, including the trailing field.
+#[derive(imp::KnownLayout, imp::FieldOffsets)]
+#[repr(C)]
+struct Dst {
+    a: u8,
+    b: util::AU16,
+    c: [u8],
+}
+
+#[test]
+fn test_dst() {
+    imp::assert_eq!(Dst::FIELD_OFFSETS, [("a", 0, 1), ("b", 2, 2), ("c", 4, 0)]);
+}
+
+// Offsets are available in generic types.
+#[derive(imp::FieldOffsets)]
+#[repr(C)]
+struct Generic<T> {
+    a: u8,
+    b: T,
+    c: u8,
+}
+
+fn generic_offsets<T>() -> [(&'static str, usize, usize); 3] {
+    Generic::<T>::FIELD_OFFSETS
+}
+
+#[test]
+fn test_generic() {
+    imp::assert_eq!(generic_offsets::<u8>(), [("a", 0, 1), ("b", 1, 1), ("c", 2, 1)]);
+    imp::assert_eq!(generic_offsets::<util::AU16>(), [("a", 0, 1), ("b", 2, 2), ("c", 4, 1)]);
+    imp::assert_eq!(Generic::<[u8; 5]>::C_OFFSET, 6);
+}
@@ -0,0 +1,83 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+#[derive(imp::FromBytes, imp::KnownLayout, imp::Immutable, imp::Unaligned)]
+#[repr(C)]
+struct Header {
+    kind: u8,
+    count: imp::byteorder::U16<imp::byteorder::BigEndian>,
+}
+
+#[derive(imp::FromBytes, imp::KnownLayout, imp::Immutable, imp::Unaligned)]
+#[repr(C)]
+struct Record {
+    header: Header,
+    #[zerocopy(len = header.count)]
+    elems: [[u8; 2]],
+}
+
+#[test]
+fn test_ref_from_prefix_linked() {
+    let bytes = [1, 0, 2, 10, 11, 12, 13, 14];
+    let (record, rest) = Record::ref_from_prefix_linked(&bytes[..]).unwrap();
+    imp::assert_eq!(record.header.kind, 1);
+    imp::assert_eq!(&record.elems, &[[10, 11], [12, 13]][..]);
+    imp::assert_eq!(rest, &[14][..]);
+
+    // The header is too short.
+    imp::assert!(Record::ref_from_prefix_linked(&bytes[..2]).is_err());
+    // The trailing slice is too short.
+    imp::assert!(Record::ref_from_prefix_linked(&bytes[..6]).is_err());
+}
+
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct Tuple(bool, u64, #[zerocopy(len = 1)] [u16]);
+
+/// A buffer aligned to at least `align_of::<u64>()`, so that it can hold a
+/// `Tuple`.
+#[repr(C, align(8))]
+struct Aligned([u8; 32]);
+
+#[test]
+fn test_try_ref_from_prefix_linked() {
+    // `Tuple.1` is at offset `align`.
+    let align = ::core::mem::align_of::<u64>();
+
+    let mut buf = Aligned([0u8; 32]);
+    let bytes = &mut buf.0;
+    bytes[0] = 1;
+    bytes[align..align + 8].copy_from_slice(&3u64.to_ne_bytes());
+    let (tuple, rest) = Tuple::try_ref_from_prefix_linked(&bytes[..]).unwrap();
+    imp::assert!(tuple.0);
+    imp::assert_eq!(tuple.2.len(), 3);
+    // The struct is padded to a multiple of its alignment.
+    let size = (align + 8 + 3 * 2 + align - 1) / align * align;
+    imp::assert_eq!(rest.len(), 32 - size);
+
+    // The prefix is validated before the count is read.
+    bytes[0] = 2;
+    imp::assert!(::core::matches!(
+        Tuple::try_ref_from_prefix_linked(&bytes[..]),
+        imp::Err(imp::TryCastError::Validity(_))
+    ));
+
+    // Counts which overflow are reported as size errors.
+    bytes[0] = 1;
+    bytes[align..align + 8].copy_from_slice(&u64::MAX.to_ne_bytes());
+    imp::assert!(::core::matches!(
+        Tuple::try_ref_from_prefix_linked(&bytes[..]),
+        imp::Err(imp::TryCastError::Size(_))
+    ));
+}
@@ -0,0 +1,91 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+// See comment in `include.rs` for why we disable the prelude.
+#![no_implicit_prelude]
+#![allow(warnings)]
+
+include!("include.rs");
+
+// Exercises `zerocopy::testing` against derived impls, as a downstream crate
+// would.
+
+#[derive(imp::TryFromBytes, imp::IntoBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(u8)]
+enum Small {
+    A = 0,
+    B = 7,
+    C = 255,
+}
+
+#[derive(imp::TryFromBytes, imp::IntoBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(u16)]
+enum Wide {
+    A = 0x0100,
+    B = 0x0001,
+}
+
+#[derive(imp::TryFromBytes, imp::IntoBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct Pair {
+    small: Small,
+    flag: bool,
+}
+
+#[derive(imp::TryFromBytes, imp::IntoBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct Large {
+    flag: bool,
+    pad: [u8; 3],
+    value: u32,
+    wide: [u8; 8],
+}
+
+#[derive(imp::TryFromBytes, imp::KnownLayout, imp::Immutable)]
+#[repr(C)]
+struct Dst {
+    len: u8,
+    flags: [bool],
+}
+
+#[test]
+fn test_exhaustive() {
+    imp::testing::check_exhaustive::<Small>(|bytes| imp::matches!(bytes, [0] | [7] | [255]));
+    imp::testing::check_exhaustive::<Wide>(|bytes| {
+        let discriminant = imp::u16::from_ne_bytes([bytes[0], bytes[1]]);
+        discriminant == 0x0100 || discriminant == 0x0001
+    });
+    imp::testing::check_exhaustive::<Pair>(|bytes| {
+        imp::matches!(bytes[0], 0 | 7 | 255) && bytes[1] < 2
+    });
+}
+
+#[test]
+fn test_encodings() {
+    imp::testing::check_encodings(&[Small::A, Small::B, Small::C]);
+    imp::testing::check_encodings(&[Wide::A, Wide::B]);
+    imp::testing::check_encodings(&[
+        Pair { small: Small::A, flag: false },
+        Pair { small: Small::C, flag: true },
+    ]);
+}
+
+#[test]
+fn test_random() {
+    imp::testing::check_roundtrip::<Large>();
+    imp::testing::check_random::<Large>(42, 2048, |bytes| bytes[0] < 2);
+}
+
+#[test]
+fn test_known_bytes() {
+    imp::testing::assert_valid::<Small>(&[7]);
+    imp::testing::assert_invalid::<Small>(&[8]);
+    imp::testing::assert_valid::<Dst>(&[2, 0, 1]);
+    imp::testing::assert_valid::<Dst>(&[0]);
+    imp::testing::assert_invalid::<Dst>(&[2, 0, 2]);
+}
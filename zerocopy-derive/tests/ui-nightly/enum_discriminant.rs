@@ -0,0 +1,50 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+extern crate zerocopy;
+
+use zerocopy::{
+    byteorder::{BigEndian, U16},
+    Immutable, KnownLayout, TryFromBytes,
+};
+
+fn main() {}
+
+// `#[zerocopy(discriminant = ...)]` requires deriving `TryFromBytes`.
+#[derive(KnownLayout, Immutable)]
+#[zerocopy(discriminant = U16<BigEndian>)]
+#[repr(u16)]
+enum NotTryFromBytes {
+    A = 1,
+}
+
+#[derive(TryFromBytes)]
+#[zerocopy(discriminant = U16<BigEndian>)]
+#[repr(u16)]
+enum Foo {
+    A = 1,
+}
+
+// The wire type's field cannot be constructed from an arbitrary discriminant,
+// even in the enum's module.
+fn construct_invalid_wire() -> FooWire {
+    FooWire(U16::new(2))
+}
+
+#[derive(TryFromBytes)]
+#[zerocopy(discriminant = U16<BigEndian>)]
+#[repr(u16)]
+enum Bar {
+    A = 2,
+}
+
+// Nor can it be constructed from the wire type of another enum with the same
+// discriminant type.
+fn construct_invalid_wire_from_other_enum(bar: BarWire) -> FooWire {
+    FooWire(bar.0)
+}
@@ -0,0 +1,74 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+extern crate zerocopy;
+
+use zerocopy::open_enum;
+
+fn main() {}
+
+//
+// Invalid integer types
+//
+
+#[open_enum(f32)]
+enum Float {
+    A,
+}
+
+#[open_enum([u8; 2])]
+enum Array {
+    A,
+}
+
+#[open_enum]
+enum Missing {
+    A,
+}
+
+#[open_enum(u8, known)]
+enum MissingKnownName {
+    A,
+}
+
+//
+// Invalid enums
+//
+
+#[open_enum(u8)]
+enum Tuple {
+    A(u8),
+}
+
+#[open_enum(u8)]
+enum Struct {
+    A { a: u8 },
+}
+
+#[open_enum(u8)]
+#[repr(u8)]
+enum Repr {
+    A,
+}
+
+#[open_enum(u8)]
+enum Generic<T> {
+    A,
+}
+
+#[open_enum(u8)]
+#[derive(Debug)]
+enum DeriveDebug {
+    A,
+}
+
+#[open_enum(u8)]
+#[derive(core::clone::Clone)]
+enum DeriveClone {
+    A,
+}
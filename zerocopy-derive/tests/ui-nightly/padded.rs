@@ -0,0 +1,49 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+extern crate zerocopy;
+
+use zerocopy::padded;
+
+fn main() {}
+
+mod private {
+    use zerocopy::padded;
+
+    // The constructor is no more visible than the least visible field.
+    #[padded]
+    #[repr(C)]
+    pub struct Foo {
+        pub a: u8,
+        b: u16,
+    }
+
+    #[padded]
+    #[repr(C)]
+    pub struct Bar {
+        pub a: u8,
+        pub(crate) b: u16,
+    }
+}
+
+// `Foo::new` is private, since `Foo::b` is.
+fn construct_foo() -> private::Foo {
+    private::Foo::new(0, 1)
+}
+
+// `Bar::new` is `pub(crate)`, since `Bar::b` is.
+fn construct_bar() -> private::Bar {
+    private::Bar::new(0, 1)
+}
+
+// `padded` only accepts `constructor = ...`.
+#[padded(new)]
+#[repr(C)]
+struct Baz {
+    a: u8,
+}
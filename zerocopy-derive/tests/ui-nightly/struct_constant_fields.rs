@@ -0,0 +1,41 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+extern crate zerocopy;
+
+fn main() {}
+
+mod private {
+    use zerocopy::{Immutable, KnownLayout, TryFromBytes};
+
+    // The constructor is no more visible than the least visible field.
+    #[derive(TryFromBytes, KnownLayout, Immutable)]
+    #[repr(C)]
+    pub struct Foo {
+        #[zerocopy(eq = 7)]
+        pub kind: u8,
+        secret: u8,
+    }
+}
+
+fn construct_foo() -> private::Foo {
+    private::Foo::new_zeroed_with_constants()
+}
+
+#[derive(zerocopy::TryFromBytes, zerocopy::KnownLayout, zerocopy::Immutable)]
+#[repr(C)]
+struct Dst {
+    #[zerocopy(eq = 7)]
+    kind: u8,
+    body: [u8],
+}
+
+// The constructor cannot be called on DSTs.
+fn construct_dst() {
+    let _ = Dst::new_zeroed_with_constants;
+}
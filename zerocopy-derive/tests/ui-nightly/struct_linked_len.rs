@@ -0,0 +1,39 @@
+// Copyright 2024 The Fuchsia Authors
+//
+// Licensed under a BSD-style license <LICENSE-BSD>, Apache License, Version 2.0
+// <LICENSE-APACHE or https://www.apache.org/licenses/LICENSE-2.0>, or the MIT
+// license <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your option.
+// This file may not be copied, modified, or distributed except according to
+// those terms.
+
+extern crate zerocopy;
+
+use zerocopy::{FromBytes, Immutable, KnownLayout};
+
+fn main() {}
+
+// `#[zerocopy(len = ...)]` requires deriving `KnownLayout`.
+#[derive(FromBytes, Immutable)]
+#[repr(C)]
+struct NotKnownLayout {
+    len: u8,
+    #[zerocopy(len = len)]
+    elems: [u8],
+}
+
+// `#[zerocopy(len = ...)]` is only supported on the trailing slice field.
+#[derive(KnownLayout)]
+#[repr(C)]
+struct NotTrailing {
+    #[zerocopy(len = 0)]
+    len: u8,
+    elems: [u8],
+}
+
+#[derive(KnownLayout)]
+#[repr(C)]
+struct NotAFieldPath {
+    len: u8,
+    #[zerocopy(len = len + 1)]
+    elems: [u8],
+}
@@ -0,0 +1 @@
+../ui-nightly/enum_discriminant.rs
\ No newline at end of file
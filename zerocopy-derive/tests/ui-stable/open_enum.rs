@@ -0,0 +1 @@
+../ui-nightly/open_enum.rs
\ No newline at end of file
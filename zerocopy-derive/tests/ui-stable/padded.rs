@@ -0,0 +1 @@
+../ui-nightly/padded.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/struct_constant_fields.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../ui-nightly/struct_linked_len.rs
\ No newline at end of file